@@ -1,5 +1,7 @@
 use crate::errors::QuickexError;
 use crate::events::{publish_admin_changed, publish_contract_paused};
+use crate::types::ContractConfig;
+use crate::{commitment, id_escrow, storage};
 use soroban_sdk::{symbol_short, Address, Env, Symbol};
 
 #[allow(dead_code)]
@@ -33,16 +35,50 @@ pub fn get_admin(env: &Env) -> Option<Address> {
 }
 
 /// Require that the caller is the admin
-#[allow(dead_code)]
+///
+/// Checks against the admin stored by [`crate::QuickexContract::initialize`].
 pub fn require_admin(env: &Env, caller: &Address) -> Result<(), QuickexError> {
     caller.require_auth();
 
-    match get_admin(env) {
+    match crate::storage::get_admin(env) {
         Some(admin) if admin == *caller => Ok(()),
         _ => Err(QuickexError::Unauthorized),
     }
 }
 
+/// Require authorization from `account` itself or, if `operator` is given, from the
+/// operator `account` registered with [`set_operator`].
+///
+/// # Errors
+/// - [`QuickexError::Unauthorized`] – `operator` is not `account`'s registered operator.
+pub fn authorize(
+    env: &Env,
+    account: &Address,
+    operator: Option<Address>,
+) -> Result<(), QuickexError> {
+    let Some(operator) = operator else {
+        account.require_auth();
+        return Ok(());
+    };
+    if storage::get_operator(env, account) != Some(operator.clone()) {
+        return Err(QuickexError::Unauthorized);
+    }
+    operator.require_auth();
+    Ok(())
+}
+
+/// Register `operator` as allowed to act for `account`, or remove it with `None`.
+/// The account must authorize.
+pub fn set_operator(env: &Env, account: &Address, operator: Option<Address>) {
+    account.require_auth();
+    storage::set_operator(env, account, operator.as_ref());
+}
+
+/// Return the operator registered for `account`, if any.
+pub fn operator_of(env: &Env, account: &Address) -> Option<Address> {
+    storage::get_operator(env, account)
+}
+
 /// Set a new admin address (Admin only)
 #[allow(dead_code)]
 pub fn set_admin(env: &Env, caller: Address, new_admin: Address) -> Result<(), QuickexError> {
@@ -75,6 +111,19 @@ pub fn is_paused(env: &Env) -> bool {
     env.storage().instance().get(&PAUSED_KEY).unwrap_or(false)
 }
 
+/// Collect the current admin-configurable settings, with defaults for unset ones.
+pub fn get_config(env: &Env) -> ContractConfig {
+    let (_, max_salt_len) = commitment::salt_policy(env);
+    ContractConfig {
+        fee_bps: storage::get_escrow_fee_bps(env),
+        max_escrow_amount: storage::get_max_escrow_amount(env),
+        max_salt_len,
+        paused: storage::is_paused(env),
+        default_privacy_level: storage::get_default_privacy_level(env),
+        dispute_deadline_ledgers: id_escrow::dispute_deadline_ledgers(env),
+    }
+}
+
 /// Require that the contract is not paused
 /// This helper function should be called at the start of deposit/withdraw functions
 #[allow(dead_code)]
@@ -1,5 +1,157 @@
 use crate::errors::QuickexError;
-use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
+use crate::storage;
+use crate::types::{CommitmentParams, OpeningRecord};
+use soroban_sdk::{symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, Map, Symbol, Vec};
+
+/// Version of the commitment preimage layout.
+pub const COMMITMENT_VERSION: u32 = 1;
+
+/// Version byte embedded in v2 (length-prefixed salt) commitment preimages.
+pub const COMMITMENT_VERSION_V2: u8 = 2;
+
+/// Hash algorithm applied to commitment preimages.
+pub const HASH_ALGORITHM: Symbol = symbol_short!("sha256");
+
+/// Size in bits of the commitment digest. Commitments store the full SHA-256 output.
+pub const COMMITMENT_OUTPUT_BITS: u32 = 256;
+
+/// Built-in domain tag prefixed to every commitment preimage when the admin has not
+/// configured one via [`set_domain_tag`].
+pub const DEFAULT_DOMAIN_TAG: &[u8] = b"QUICKEX_COMMITMENT";
+
+/// Maximum length in bytes of a configured domain tag.
+pub const MAX_DOMAIN_TAG_LEN: u32 = 64;
+
+/// Hard cap on salt length in bytes. The configurable salt policy can only tighten it.
+pub const MAX_SALT_LEN: u32 = 1024;
+
+/// Length in bytes of salts generated by [`create_commitment_auto_salt`].
+pub const AUTO_SALT_LEN: u32 = 32;
+
+/// Maximum tracked commitments an owner may create within a single ledger.
+pub const MAX_TRACKED_PER_LEDGER: u32 = 5;
+
+/// Maximum tracked commitments all owners together may create within a single ledger.
+pub const MAX_TRACKED_LEDGER_LEN: u32 = 50;
+
+/// Maximum number of ledgers a single [`commitments_in_range`] call reads.
+pub const MAX_TRACKED_SCAN_LEDGERS: u32 = 128;
+
+/// Label separating payload commitments from amount commitments.
+const PAYLOAD_LABEL: &[u8] = b"payload";
+
+/// Label separating salt-free public commitments from amount commitments.
+const PUBLIC_LABEL: &[u8] = b"public";
+
+/// Label separating ledger-anchored commitments from plain amount commitments.
+const ANCHOR_LABEL: &[u8] = b"anchor";
+
+/// Label separating viewing-key commitments from plain amount commitments.
+const VIEWKEY_LABEL: &[u8] = b"viewkey";
+
+/// Label separating aggregate roots from individual commitments.
+const AGGREGATE_LABEL: &[u8] = b"aggregate";
+
+/// Label separating named-amount map commitments from single-amount commitments.
+const MAP_LABEL: &[u8] = b"map";
+
+/// Return the domain tag currently prefixed to commitment preimages.
+pub fn domain_tag(env: &Env) -> Bytes {
+    storage::get_domain_tag(env).unwrap_or(Bytes::from_slice(env, DEFAULT_DOMAIN_TAG))
+}
+
+/// Return the `(min, max)` salt length policy currently enforced.
+///
+/// Defaults to `(0, MAX_SALT_LEN)` when the admin has not configured one.
+pub fn salt_policy(env: &Env) -> (u32, u32) {
+    storage::get_salt_policy(env).unwrap_or((0, MAX_SALT_LEN))
+}
+
+/// Returns `true` when `salt` satisfies the current salt length policy.
+pub fn is_valid_salt(env: &Env, salt: &Bytes) -> bool {
+    let (min_len, max_len) = salt_policy(env);
+    salt.len() >= min_len && salt.len() <= max_len
+}
+
+/// Replace the salt length policy.
+///
+/// # Errors
+/// - [`QuickexError::InvalidSaltPolicy`] – `min_len > max_len` or `max_len > MAX_SALT_LEN`.
+pub fn set_salt_policy(env: &Env, min_len: u32, max_len: u32) -> Result<(), QuickexError> {
+    if min_len > max_len || max_len > MAX_SALT_LEN {
+        return Err(QuickexError::InvalidSaltPolicy);
+    }
+    storage::set_salt_policy(env, min_len, max_len);
+    Ok(())
+}
+
+/// Return the parameters of the commitment scheme currently in use.
+pub fn commitment_params(env: &Env) -> CommitmentParams {
+    let (min_salt, max_salt) = salt_policy(env);
+    CommitmentParams {
+        version: COMMITMENT_VERSION,
+        hash_alg: hash_algorithm(),
+        min_salt,
+        max_salt,
+        domain_tag: domain_tag(env),
+    }
+}
+
+/// Return the hash algorithm applied to commitment preimages.
+///
+/// Always [`HASH_ALGORITHM`] until the algorithm becomes selectable.
+pub fn hash_algorithm() -> Symbol {
+    HASH_ALGORITHM
+}
+
+/// Return the collision-resistance bits of the commitment digest.
+///
+/// 128 while commitments keep the full 256-bit SHA-256 output.
+pub fn commitment_bit_security() -> u32 {
+    collision_resistance_bits(COMMITMENT_OUTPUT_BITS)
+}
+
+/// Collision resistance of an `output_bits`-bit digest.
+///
+/// A birthday attack finds a collision in about `2^(output_bits / 2)` hashes, so
+/// truncating the digest halves the security of the bits removed.
+pub fn collision_resistance_bits(output_bits: u32) -> u32 {
+    output_bits / 2
+}
+
+/// Set the domain tag prefixed to commitment preimages.
+///
+/// The tag can be set once, and only before the first deposit: deposits are keyed by
+/// commitments computed under the tag, so changing it would strand them.
+///
+/// # Errors
+/// - [`QuickexError::InvalidDomainTag`] – tag is empty or longer than [`MAX_DOMAIN_TAG_LEN`].
+/// - [`QuickexError::DomainTagLocked`] – the tag was already set or a deposit exists.
+pub fn set_domain_tag(env: &Env, tag: Bytes) -> Result<(), QuickexError> {
+    if tag.is_empty() || tag.len() > MAX_DOMAIN_TAG_LEN {
+        return Err(QuickexError::InvalidDomainTag);
+    }
+    if storage::is_domain_tag_locked(env) {
+        return Err(QuickexError::DomainTagLocked);
+    }
+    storage::set_domain_tag(env, &tag);
+    storage::lock_domain_tag(env);
+    Ok(())
+}
+
+/// Commitment of (`owner`, `amount`, `salt`) as computed before domain tags existed.
+///
+/// Commitment = SHA256(XDR(owner) || BE(amount) || salt)
+///
+/// Only used to look up deposits made before the upgrade that introduced domain tags.
+pub fn legacy_amount_commitment(
+    env: &Env,
+    owner: Address,
+    amount: i128,
+    salt: Bytes,
+) -> BytesN<32> {
+    hash_amount_preimage(env, Bytes::new(env), owner, amount.to_be_bytes(), salt)
+}
 
 /// # Commitment Scheme Invariants
 ///
@@ -30,7 +182,10 @@ use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
 /// ## Security Constraints
 ///
 /// - Amount must be non-negative (amount >= 0)
-/// - Salt length capped at 1024 bytes to prevent DoS via excessive hashing
+/// - Salt length must satisfy the salt policy (see [`is_valid_salt`]), which never
+///   exceeds 1024 bytes to prevent DoS via excessive hashing
+/// - Every preimage starts with the deployment's domain tag, so commitments cannot be
+///   replayed across QuickEx instances configured with different tags
 /// - Uses XDR serialization for Address to ensure canonical representation
 /// - Big-endian encoding for amount ensures consistent byte ordering
 ///
@@ -43,8 +198,9 @@ use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
 ///
 /// ## Implementation Details
 ///
-/// Commitment = SHA256(XDR(owner) || BE(amount) || salt)
+/// Commitment = SHA256(tag || XDR(owner) || BE(amount) || salt)
 /// where:
+/// - tag = configured domain tag, or [`DEFAULT_DOMAIN_TAG`] when unset
 /// - XDR(owner) = Stellar XDR encoding of Address
 /// - BE(amount) = 16-byte big-endian representation of i128
 /// - || = byte concatenation
@@ -59,31 +215,205 @@ pub fn create_amount_commitment(
         return Err(QuickexError::InvalidAmount);
     }
 
-    // Cap salt length as a safeguard
-    if salt.len() > 1024 {
+    // Serialize amount (i128) to big-endian bytes
+    commit_amount_bytes(env, owner, amount.to_be_bytes(), salt)
+}
+
+/// Hash `tag || XDR(owner) || amount_bytes || salt` after enforcing the salt policy.
+///
+/// Shared by the signed and unsigned amount constructors.
+fn commit_amount_bytes(
+    env: &Env,
+    owner: Address,
+    amount_bytes: [u8; 16],
+    salt: Bytes,
+) -> Result<BytesN<32>, QuickexError> {
+    // Enforce the salt length policy as a safeguard
+    if !is_valid_salt(env, &salt) {
         return Err(QuickexError::InvalidSalt);
     }
 
-    let mut payload = Bytes::new(env);
+    Ok(hash_amount_preimage(
+        env,
+        domain_tag(env),
+        owner,
+        amount_bytes,
+        salt,
+    ))
+}
+
+/// Hash `tag || XDR(owner) || amount_bytes || salt` without any salt checks.
+fn hash_amount_preimage(
+    env: &Env,
+    tag: Bytes,
+    owner: Address,
+    amount_bytes: [u8; 16],
+    salt: Bytes,
+) -> BytesN<32> {
+    let mut payload = tag;
 
     // Append owner (Address) - using XDR serialization for consistency
     payload.append(&owner.to_xdr(env));
 
-    // Serialize amount (i128) to big-endian bytes
-    let amount_bytes: [u8; 16] = amount.to_be_bytes();
-
-    // Correct loop iteration over bytes
-    for b in &amount_bytes {
-        payload.push_back(*b);
-    }
+    // Append amount in one copy rather than byte by byte
+    payload.extend_from_array(&amount_bytes);
 
     // Append salt
     payload.append(&salt);
 
     // Return SHA256 hash
-    Ok(env.crypto().sha256(&payload).into())
+    env.crypto().sha256(&payload).into()
+}
+
+/// Create a v2 amount commitment, whose preimage length-prefixes the salt.
+///
+/// Commitment = SHA256(tag || 0x02 || XDR(owner) || BE16(amount) || BE32(len(salt)) || salt)
+///
+/// The explicit salt length keeps the preimage unambiguous once further
+/// variable-length fields follow the salt. The version byte separates v2 hashes
+/// from [`create_amount_commitment`] hashes.
+///
+/// # Errors
+/// - [`QuickexError::InvalidAmount`] – amount is negative.
+/// - [`QuickexError::InvalidSalt`] – salt violates the salt length policy.
+pub fn create_amount_commitment_v2(
+    env: &Env,
+    owner: Address,
+    amount: i128,
+    salt: Bytes,
+) -> Result<BytesN<32>, QuickexError> {
+    if amount < 0 {
+        return Err(QuickexError::InvalidAmount);
+    }
+    if !is_valid_salt(env, &salt) {
+        return Err(QuickexError::InvalidSalt);
+    }
+
+    let mut preimage = domain_tag(env);
+    preimage.push_back(COMMITMENT_VERSION_V2);
+    preimage.append(&owner.to_xdr(env));
+    preimage.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+    preimage.append(&Bytes::from_array(env, &salt.len().to_be_bytes()));
+    preimage.append(&salt);
+    Ok(env.crypto().sha256(&preimage).into())
 }
 
+/// Verify a v2 commitment. Returns `false` for invalid or mismatching openings.
+pub fn verify_amount_commitment_v2(
+    env: &Env,
+    commitment: BytesN<32>,
+    owner: Address,
+    amount: i128,
+    salt: Bytes,
+) -> bool {
+    match create_amount_commitment_v2(env, owner, amount, salt) {
+        Ok(hash) => hash == commitment,
+        Err(_) => false,
+    }
+}
+
+/// Create an amount commitment from an ASCII-hex encoded salt.
+///
+/// The salt is decoded to raw bytes first, so the result equals
+/// [`create_amount_commitment`] over the decoded salt. Both upper- and lower-case
+/// hex digits are accepted.
+///
+/// # Errors
+/// - [`QuickexError::InvalidSalt`] – `hex_salt` decodes to more than [`MAX_SALT_LEN`]
+///   bytes.
+/// - [`QuickexError::InvalidHex`] – `hex_salt` has odd length or a non-hex character.
+/// - Any error of [`create_amount_commitment`] for the decoded salt.
+pub fn create_amount_commitment_hex(
+    env: &Env,
+    owner: Address,
+    amount: i128,
+    hex_salt: Bytes,
+) -> Result<BytesN<32>, QuickexError> {
+    let salt = decode_hex(env, &hex_salt)?;
+    create_amount_commitment(env, owner, amount, salt)
+}
+
+/// Decode ASCII hex into raw bytes, rejecting input longer than a maximal salt.
+///
+/// Works on stack buffers so the host is crossed once to read and once to build.
+fn decode_hex(env: &Env, hex: &Bytes) -> Result<Bytes, QuickexError> {
+    let len = hex.len() as usize;
+    if len > 2 * MAX_SALT_LEN as usize {
+        return Err(QuickexError::InvalidSalt);
+    }
+    if !len.is_multiple_of(2) {
+        return Err(QuickexError::InvalidHex);
+    }
+
+    let mut digits = [0u8; 2 * MAX_SALT_LEN as usize];
+    hex.copy_into_slice(&mut digits[..len]);
+    let mut decoded = [0u8; MAX_SALT_LEN as usize];
+    for (byte, pair) in decoded.iter_mut().zip(digits[..len].chunks_exact(2)) {
+        *byte = (hex_nibble(pair[0])? << 4) | hex_nibble(pair[1])?;
+    }
+    Ok(Bytes::from_slice(env, &decoded[..len / 2]))
+}
+
+fn hex_nibble(digit: u8) -> Result<u8, QuickexError> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(QuickexError::InvalidHex),
+    }
+}
+
+/// Create an amount commitment with a fresh [`AUTO_SALT_LEN`]-byte salt from the
+/// contract PRNG, returning the commitment and the salt.
+///
+/// **For simulation only.** The salt is drawn from the host PRNG and returned, so in a
+/// submitted transaction it is public and the commitment hides nothing. Real
+/// commitments must use a salt generated off-chain; see [`create_amount_commitment`].
+/// The PRNG is deterministic within a test environment.
+///
+/// # Errors
+/// - [`QuickexError::InvalidAmount`] – amount is negative.
+/// - [`QuickexError::InvalidSalt`] – the salt policy does not admit 32-byte salts.
+pub fn create_commitment_auto_salt(
+    env: &Env,
+    owner: Address,
+    amount: i128,
+) -> Result<(BytesN<32>, Bytes), QuickexError> {
+    let salt: Bytes = env.prng().gen_len(AUTO_SALT_LEN);
+    let commitment = create_amount_commitment(env, owner, amount, salt.clone())?;
+    Ok((commitment, salt))
+}
+
+/// Create a salt-free commitment to a public amount.
+///
+/// Commitment = SHA256(domain_tag || "public" || XDR(owner) || BE16(amount)).
+///
+/// **This hides nothing.** Anyone can recompute it from the owner and a guessed
+/// amount. Use it only as a stable identifier for amounts that are public anyway.
+///
+/// # Errors
+/// - [`QuickexError::InvalidAmount`] – amount is negative.
+pub fn create_public_commitment(
+    env: &Env,
+    owner: Address,
+    amount: i128,
+) -> Result<BytesN<32>, QuickexError> {
+    if amount < 0 {
+        return Err(QuickexError::InvalidAmount);
+    }
+
+    let mut preimage = domain_tag(env);
+    preimage.append(&Bytes::from_slice(env, PUBLIC_LABEL));
+    preimage.append(&owner.to_xdr(env));
+    preimage.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+    Ok(env.crypto().sha256(&preimage).into())
+}
+
+/// Verify an amount commitment opening.
+///
+/// The salt is only checked against the hard [`MAX_SALT_LEN`] cap, not the current
+/// salt policy, so commitments created before the policy was tightened still verify.
+/// Use [`verify_commitment_strict`] to also require policy compliance.
 pub fn verify_amount_commitment(
     env: &Env,
     commitment: BytesN<32>,
@@ -91,8 +421,504 @@ pub fn verify_amount_commitment(
     amount: i128,
     salt: Bytes,
 ) -> bool {
-    match create_amount_commitment(env, owner, amount, salt) {
+    if amount < 0 || salt.len() > MAX_SALT_LEN {
+        return false;
+    }
+    hash_amount_preimage(env, domain_tag(env), owner, amount.to_be_bytes(), salt) == commitment
+}
+
+/// Returns `true` when `preimage` is laid out as an amount commitment preimage owned
+/// by `expected_owner`: `tag || XDR(expected_owner)`, a 16-byte amount, then a salt
+/// satisfying the current salt length policy. Says nothing about the amount's value.
+pub fn validate_preimage_structure(env: &Env, preimage: Bytes, expected_owner: Address) -> bool {
+    let mut prefix = domain_tag(env);
+    prefix.append(&expected_owner.to_xdr(env));
+
+    let header_len = prefix.len() + 16;
+    if preimage.len() < header_len || preimage.slice(..prefix.len()) != prefix {
+        return false;
+    }
+    is_valid_salt(env, &preimage.slice(header_len..))
+}
+
+/// Like [`verify_amount_commitment`], but also `false` when the salt violates the
+/// current salt length policy.
+pub fn verify_commitment_strict(
+    env: &Env,
+    commitment: BytesN<32>,
+    owner: Address,
+    amount: i128,
+    salt: Bytes,
+) -> bool {
+    is_valid_salt(env, &salt) && verify_amount_commitment(env, commitment, owner, amount, salt)
+}
+
+/// Returns `true` when `commitment` opens to amount `0` for `owner` and `salt`.
+///
+/// Proves an empty or nullified position without a separate amount argument.
+pub fn is_zero_commitment(env: &Env, commitment: BytesN<32>, owner: Address, salt: Bytes) -> bool {
+    verify_amount_commitment(env, commitment, owner, 0, salt)
+}
+
+/// Verify the 32-byte commitment found at `offset` inside a larger `blob`.
+///
+/// Returns `false` when `offset + 32` runs past the end of `blob`.
+pub fn verify_commitment_at(
+    env: &Env,
+    blob: Bytes,
+    offset: u32,
+    owner: Address,
+    amount: i128,
+    salt: Bytes,
+) -> bool {
+    let end = match offset.checked_add(32) {
+        Some(end) if end <= blob.len() => end,
+        _ => return false,
+    };
+    let mut commitment = [0u8; 32];
+    blob.slice(offset..end).copy_into_slice(&mut commitment);
+    verify_amount_commitment(
+        env,
+        BytesN::from_array(env, &commitment),
+        owner,
+        amount,
+        salt,
+    )
+}
+
+/// Returns `true` when both commitments open to `owner` with their given amounts and salts.
+///
+/// Supports linking analysis where the openings of both commitments are known.
+#[allow(clippy::too_many_arguments)]
+pub fn shared_owner(
+    env: &Env,
+    commitment_a: BytesN<32>,
+    amount_a: i128,
+    salt_a: Bytes,
+    commitment_b: BytesN<32>,
+    amount_b: i128,
+    salt_b: Bytes,
+    owner: Address,
+) -> bool {
+    verify_amount_commitment(env, commitment_a, owner.clone(), amount_a, salt_a)
+        && verify_amount_commitment(env, commitment_b, owner, amount_b, salt_b)
+}
+
+/// Merge two of `owner`'s amount commitments into one commitment to their summed amount.
+///
+/// Both openings must verify; the result is `create_amount_commitment(owner,
+/// amount_a + amount_b, merged_salt)`.
+///
+/// # Errors
+/// - [`QuickexError::CommitmentMismatch`] – either opening does not match its commitment.
+/// - [`QuickexError::InvalidAmount`] – the sum overflows `i128`.
+/// - [`QuickexError::InvalidSalt`] – `merged_salt` violates the salt length policy.
+#[allow(clippy::too_many_arguments)]
+pub fn merge_commitments(
+    env: &Env,
+    commitment_a: BytesN<32>,
+    amount_a: i128,
+    salt_a: Bytes,
+    commitment_b: BytesN<32>,
+    amount_b: i128,
+    salt_b: Bytes,
+    merged_salt: Bytes,
+    owner: Address,
+) -> Result<BytesN<32>, QuickexError> {
+    if !shared_owner(
+        env,
+        commitment_a,
+        amount_a,
+        salt_a,
+        commitment_b,
+        amount_b,
+        salt_b,
+        owner.clone(),
+    ) {
+        return Err(QuickexError::CommitmentMismatch);
+    }
+    let merged = amount_a
+        .checked_add(amount_b)
+        .ok_or(QuickexError::InvalidAmount)?;
+    create_amount_commitment(env, owner, merged, merged_salt)
+}
+
+/// Create an amount commitment and record the ledger sequence it was created at.
+///
+/// Each owner may track at most [`MAX_TRACKED_PER_LEDGER`] commitments per
+/// ledger; the counter resets when the ledger sequence advances. A ledger holds at
+/// most [`MAX_TRACKED_LEDGER_LEN`] tracked commitments across all owners.
+///
+/// # Errors
+/// - [`QuickexError::InvalidAmount`] – amount is negative.
+/// - [`QuickexError::InvalidSalt`] – salt violates the salt length policy.
+/// - [`QuickexError::CommitmentAlreadyExists`] – the commitment is already tracked.
+/// - [`QuickexError::RateLimited`] – the owner or the ledger reached its limit.
+pub fn create_tracked_commitment(
+    env: &Env,
+    owner: Address,
+    amount: i128,
+    salt: Bytes,
+) -> Result<BytesN<32>, QuickexError> {
+    owner.require_auth();
+
+    let commitment = create_amount_commitment(env, owner.clone(), amount, salt)?;
+    if storage::get_tracked_commitment(env, &commitment).is_some() {
+        return Err(QuickexError::CommitmentAlreadyExists);
+    }
+
+    let sequence = env.ledger().sequence();
+    let (ledger, count) = storage::get_tracked_rate(env, &owner);
+    let count = if ledger == sequence { count } else { 0 };
+    if count >= MAX_TRACKED_PER_LEDGER
+        || storage::get_tracked_at_ledger(env, sequence).len() >= MAX_TRACKED_LEDGER_LEN
+    {
+        return Err(QuickexError::RateLimited);
+    }
+    storage::set_tracked_rate(env, &owner, sequence, count + 1);
+
+    storage::set_tracked_commitment(env, &commitment, sequence);
+    storage::push_tracked_at_ledger(env, sequence, &commitment);
+    Ok(commitment)
+}
+
+/// Return the ledger sequence a commitment was tracked at, or `None` if untracked.
+pub fn commitment_created_at(env: &Env, commitment: &BytesN<32>) -> Option<u32> {
+    storage::get_tracked_commitment(env, commitment)
+}
+
+/// Return the tracked commitments created in ledgers `start_ledger..=end_ledger`, oldest
+/// first, and the ledger to resume from.
+///
+/// Reads at most [`MAX_TRACKED_SCAN_LEDGERS`] ledgers and stops after the first ledger
+/// that brings the result to `limit` commitments; a ledger's commitments are always
+/// returned together, so the result can exceed `limit` by less than
+/// [`MAX_TRACKED_LEDGER_LEN`]. Pass the returned ledger as `start_ledger` to continue;
+/// it is `end_ledger + 1` once the range is exhausted.
+///
+/// # Errors
+/// - [`QuickexError::InvalidRange`] – `start_ledger` is after `end_ledger`.
+pub fn commitments_in_range(
+    env: &Env,
+    start_ledger: u32,
+    end_ledger: u32,
+    limit: u32,
+) -> Result<(Vec<BytesN<32>>, u32), QuickexError> {
+    if start_ledger > end_ledger {
+        return Err(QuickexError::InvalidRange);
+    }
+
+    let mut found = Vec::new(env);
+    let end = end_ledger.min(start_ledger.saturating_add(MAX_TRACKED_SCAN_LEDGERS - 1));
+    let mut ledger = start_ledger;
+    while ledger <= end && found.len() < limit {
+        found.append(&storage::get_tracked_at_ledger(env, ledger));
+        if ledger == u32::MAX {
+            break;
+        }
+        ledger += 1;
+    }
+    Ok((found, ledger))
+}
+
+/// Ledger timestamp after which a timed commitment made at `timestamp` is stale.
+///
+/// A commitment is fresh while the ledger timestamp is `<=` the returned value.
+/// Saturates at `u64::MAX` instead of overflowing.
+pub fn commitment_expiry(timestamp: u64, max_age: u64) -> u64 {
+    timestamp.saturating_add(max_age)
+}
+
+/// Verify an opening of `commitment` and record `revealer` and the current ledger.
+///
+/// An opening is recorded once; the first revealer stays on record.
+///
+/// # Errors
+/// - [`QuickexError::CommitmentMismatch`] – the opening does not match `commitment`.
+/// - [`QuickexError::CommitmentAlreadyExists`] – an opening is already recorded.
+pub fn record_opening_by(
+    env: &Env,
+    revealer: Address,
+    commitment: BytesN<32>,
+    owner: Address,
+    amount: i128,
+    salt: Bytes,
+) -> Result<(), QuickexError> {
+    revealer.require_auth();
+
+    if !verify_amount_commitment(env, commitment.clone(), owner, amount, salt) {
+        return Err(QuickexError::CommitmentMismatch);
+    }
+    if storage::get_commitment_opening(env, &commitment).is_some() {
+        return Err(QuickexError::CommitmentAlreadyExists);
+    }
+    let record = OpeningRecord {
+        revealer,
+        ledger: env.ledger().sequence(),
+    };
+    storage::set_commitment_opening(env, &commitment, &record);
+    Ok(())
+}
+
+/// Return the address that recorded an opening of `commitment`, if any.
+pub fn opening_revealer(env: &Env, commitment: &BytesN<32>) -> Option<Address> {
+    storage::get_commitment_opening(env, commitment).map(|record| record.revealer)
+}
+
+/// Return the commitments in `expected` that are not tracked, in input order.
+pub fn commitments_missing(env: &Env, expected: Vec<BytesN<32>>) -> Vec<BytesN<32>> {
+    let mut missing = Vec::new(env);
+    for commitment in expected.iter() {
+        if storage::get_tracked_commitment(env, &commitment).is_none() {
+            missing.push_back(commitment);
+        }
+    }
+    missing
+}
+
+/// Create a commitment to an unsigned `u128` amount.
+///
+/// Uses the same preimage layout as [`create_amount_commitment`] with the amount as
+/// 16 big-endian bytes. No sign check is needed, so every `u128` is accepted. For
+/// amounts up to `i128::MAX` the result equals the signed commitment to the same value.
+///
+/// # Errors
+/// - [`QuickexError::InvalidSalt`] – salt violates the salt length policy.
+pub fn create_u128_commitment(
+    env: &Env,
+    owner: Address,
+    amount: u128,
+    salt: Bytes,
+) -> Result<BytesN<32>, QuickexError> {
+    commit_amount_bytes(env, owner, amount.to_be_bytes(), salt)
+}
+
+pub fn verify_u128_commitment(
+    env: &Env,
+    commitment: BytesN<32>,
+    owner: Address,
+    amount: u128,
+    salt: Bytes,
+) -> bool {
+    match create_u128_commitment(env, owner, amount, salt) {
+        Ok(hash) => hash == commitment,
+        Err(_) => false,
+    }
+}
+
+/// Create an amount commitment anchored to the current ledger sequence.
+///
+/// Commitment = SHA256(tag || "anchor" || BE32(sequence) || XDR(owner) || BE(amount) || salt)
+///
+/// Returns the hash together with the sequence folded into it; verification needs both.
+///
+/// # Errors
+/// - [`QuickexError::InvalidAmount`] – amount is negative.
+/// - [`QuickexError::InvalidSalt`] – salt violates the salt length policy.
+pub fn create_anchored_commitment(
+    env: &Env,
+    owner: Address,
+    amount: i128,
+    salt: Bytes,
+) -> Result<(BytesN<32>, u32), QuickexError> {
+    let sequence = env.ledger().sequence();
+    let commitment = anchored_commitment_at(env, owner, amount, salt, sequence)?;
+    Ok((commitment, sequence))
+}
+
+pub fn verify_anchored_commitment(
+    env: &Env,
+    commitment: BytesN<32>,
+    owner: Address,
+    amount: i128,
+    salt: Bytes,
+    sequence: u32,
+) -> bool {
+    match anchored_commitment_at(env, owner, amount, salt, sequence) {
         Ok(hash) => hash == commitment,
         Err(_) => false,
     }
 }
+
+fn anchored_commitment_at(
+    env: &Env,
+    owner: Address,
+    amount: i128,
+    salt: Bytes,
+    sequence: u32,
+) -> Result<BytesN<32>, QuickexError> {
+    if amount < 0 {
+        return Err(QuickexError::InvalidAmount);
+    }
+    if !is_valid_salt(env, &salt) {
+        return Err(QuickexError::InvalidSalt);
+    }
+
+    let mut preimage = domain_tag(env);
+    preimage.append(&Bytes::from_slice(env, ANCHOR_LABEL));
+    preimage.append(&Bytes::from_array(env, &sequence.to_be_bytes()));
+    preimage.append(&owner.to_xdr(env));
+    preimage.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+    preimage.append(&salt);
+
+    Ok(env.crypto().sha256(&preimage).into())
+}
+
+/// Create an amount commitment that can only be reconstructed with a viewing key.
+///
+/// Commitment = SHA256(tag || "viewkey" || viewkey || XDR(owner) || BE16(amount) || salt)
+///
+/// Sharing `viewkey` with a recipient discloses the opening to them alone; without
+/// it the commitment cannot be recomputed even from the owner, amount, and salt.
+///
+/// # Errors
+/// - [`QuickexError::InvalidAmount`] – amount is negative.
+/// - [`QuickexError::InvalidSalt`] – salt violates the salt length policy.
+pub fn create_viewkey_commitment(
+    env: &Env,
+    owner: Address,
+    amount: i128,
+    salt: Bytes,
+    viewkey: BytesN<32>,
+) -> Result<BytesN<32>, QuickexError> {
+    if amount < 0 {
+        return Err(QuickexError::InvalidAmount);
+    }
+    if !is_valid_salt(env, &salt) {
+        return Err(QuickexError::InvalidSalt);
+    }
+
+    let mut preimage = domain_tag(env);
+    preimage.append(&Bytes::from_slice(env, VIEWKEY_LABEL));
+    preimage.append(&Bytes::from_array(env, &viewkey.to_array()));
+    preimage.append(&owner.to_xdr(env));
+    preimage.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+    preimage.append(&salt);
+    Ok(env.crypto().sha256(&preimage).into())
+}
+
+/// Verify a viewing-key commitment. Returns `false` for invalid or mismatching openings.
+pub fn verify_viewkey_commitment(
+    env: &Env,
+    commitment: BytesN<32>,
+    owner: Address,
+    amount: i128,
+    salt: Bytes,
+    viewkey: BytesN<32>,
+) -> bool {
+    match create_viewkey_commitment(env, owner, amount, salt, viewkey) {
+        Ok(hash) => hash == commitment,
+        Err(_) => false,
+    }
+}
+
+/// Create a commitment to an arbitrary 32-byte payload instead of an amount.
+///
+/// Commitment = SHA256(tag || "payload" || XDR(owner) || payload || salt)
+///
+/// The `"payload"` label keeps these commitments disjoint from amount commitments,
+/// whose fixed 16-byte amount field would otherwise let a payload commitment be
+/// reinterpreted as an amount commitment with a shifted salt.
+///
+/// # Errors
+/// - [`QuickexError::InvalidSalt`] – salt violates the salt length policy.
+pub fn create_payload_commitment(
+    env: &Env,
+    owner: Address,
+    payload: BytesN<32>,
+    salt: Bytes,
+) -> Result<BytesN<32>, QuickexError> {
+    if !is_valid_salt(env, &salt) {
+        return Err(QuickexError::InvalidSalt);
+    }
+
+    let mut preimage = domain_tag(env);
+    preimage.append(&Bytes::from_slice(env, PAYLOAD_LABEL));
+    preimage.append(&owner.to_xdr(env));
+    preimage.append(&payload.into());
+    preimage.append(&salt);
+
+    Ok(env.crypto().sha256(&preimage).into())
+}
+
+pub fn verify_payload_commitment(
+    env: &Env,
+    commitment: BytesN<32>,
+    owner: Address,
+    payload: BytesN<32>,
+    salt: Bytes,
+) -> bool {
+    match create_payload_commitment(env, owner, payload, salt) {
+        Ok(hash) => hash == commitment,
+        Err(_) => false,
+    }
+}
+
+/// Create a commitment to a map of named amounts, e.g. a fee breakdown.
+///
+/// Commitment = SHA256(tag || "map" || XDR(owner) || BE32(len) || (XDR(key) || BE16(amount))* || salt)
+///
+/// Entries are hashed in ascending key order. Soroban maps keep their keys sorted, so
+/// the same logical map yields the same hash however it was built.
+///
+/// # Errors
+/// - [`QuickexError::InvalidAmount`] – any amount is negative.
+/// - [`QuickexError::InvalidSalt`] – salt violates the salt length policy.
+pub fn create_map_commitment(
+    env: &Env,
+    owner: Address,
+    entries: Map<Symbol, i128>,
+    salt: Bytes,
+) -> Result<BytesN<32>, QuickexError> {
+    if !is_valid_salt(env, &salt) {
+        return Err(QuickexError::InvalidSalt);
+    }
+
+    let mut preimage = domain_tag(env);
+    preimage.append(&Bytes::from_slice(env, MAP_LABEL));
+    preimage.append(&owner.to_xdr(env));
+    preimage.append(&Bytes::from_array(env, &entries.len().to_be_bytes()));
+    for (key, amount) in entries.iter() {
+        if amount < 0 {
+            return Err(QuickexError::InvalidAmount);
+        }
+        preimage.append(&key.to_xdr(env));
+        preimage.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+    }
+    preimage.append(&salt);
+    Ok(env.crypto().sha256(&preimage).into())
+}
+
+/// Verify a map commitment. Returns `false` for invalid or mismatching openings.
+pub fn verify_map_commitment(
+    env: &Env,
+    commitment: BytesN<32>,
+    owner: Address,
+    entries: Map<Symbol, i128>,
+    salt: Bytes,
+) -> bool {
+    match create_map_commitment(env, owner, entries, salt) {
+        Ok(hash) => hash == commitment,
+        Err(_) => false,
+    }
+}
+
+/// Combine an ordered batch of commitments into a single aggregate root.
+///
+/// Root = SHA256(tag || "aggregate" || BE32(len) || c_1 || ... || c_n)
+///
+/// Reordering, adding, or altering any part changes the root.
+pub fn aggregate_commitments(env: &Env, parts: Vec<BytesN<32>>) -> BytesN<32> {
+    let mut preimage = domain_tag(env);
+    preimage.append(&Bytes::from_slice(env, AGGREGATE_LABEL));
+    preimage.append(&Bytes::from_array(env, &parts.len().to_be_bytes()));
+    for part in parts.iter() {
+        preimage.append(&part.into());
+    }
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Returns `true` when `parts`, in order, aggregate to `root`.
+pub fn verify_aggregate(env: &Env, root: BytesN<32>, parts: Vec<BytesN<32>>) -> bool {
+    aggregate_commitments(env, parts) == root
+}
@@ -3,8 +3,19 @@
 //! This module provides comprehensive property-based testing for the commitment scheme.
 //! Tests validate the core invariants documented in commitment.rs.
 
-use crate::{errors::QuickexError, QuickexContract, QuickexContractClient};
-use soroban_sdk::{testutils::Address as _, Address, Bytes, Env};
+use crate::{
+    commitment::{
+        COMMITMENT_VERSION_V2, DEFAULT_DOMAIN_TAG, MAX_TRACKED_LEDGER_LEN, MAX_TRACKED_SCAN_LEDGERS,
+    },
+    errors::QuickexError,
+    QuickexContract, QuickexContractClient,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec,
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, Map, Symbol,
+};
 
 extern crate std;
 
@@ -218,6 +229,20 @@ fn test_verify_commitment_with_different_owner() {
     assert!(!is_valid);
 }
 
+#[test]
+fn test_is_zero_commitment() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"empty_position");
+
+    let zero = client.create_amount_commitment(&owner, &0i128, &salt);
+    assert!(client.is_zero_commitment(&zero, &owner, &salt));
+    assert!(!client.is_zero_commitment(&zero, &owner, &Bytes::from_slice(&env, b"other")));
+
+    let nonzero = client.create_amount_commitment(&owner, &1i128, &salt);
+    assert!(!client.is_zero_commitment(&nonzero, &owner, &salt));
+}
+
 // ============================================================================
 // Security Constraints
 // ============================================================================
@@ -270,6 +295,45 @@ fn test_commitment_oversized_salt_rejected() {
     assert_eq!(result, Err(Ok(QuickexError::InvalidSalt)));
 }
 
+#[test]
+fn test_commitment_max_salt_length_accepted() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt = Bytes::from_array(&env, &[0xAB; 1024]);
+
+    let result = client.try_create_amount_commitment(&owner, &1_000i128, &salt);
+    assert!(matches!(result, Ok(Ok(_))));
+}
+
+#[test]
+fn test_commitment_256_byte_salt_matches_bytewise_preimage() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let raw_salt: std::vec::Vec<u8> = (0..=255u8).collect();
+    let salt = Bytes::from_slice(&env, &raw_salt);
+
+    // Reference preimage assembled one byte at a time, as the original loop did.
+    let mut preimage = Bytes::new(&env);
+    for b in DEFAULT_DOMAIN_TAG {
+        preimage.push_back(*b);
+    }
+    for b in owner.clone().to_xdr(&env).iter() {
+        preimage.push_back(b);
+    }
+    for b in 123_456_789i128.to_be_bytes() {
+        preimage.push_back(b);
+    }
+    for b in &raw_salt {
+        preimage.push_back(*b);
+    }
+    let expected: BytesN<32> = env.crypto().sha256(&preimage).into();
+
+    assert_eq!(
+        client.create_amount_commitment(&owner, &123_456_789i128, &salt),
+        expected
+    );
+}
+
 // ============================================================================
 // Property-Based Tests (Pseudo-Random Generation)
 // ============================================================================
@@ -352,3 +416,1015 @@ fn test_commitment_property_verification_consistency() {
         assert!(!client.verify_amount_commitment(&commitment, &wrong_owner, amount, &salt));
     }
 }
+
+// ============================================================================
+// Domain Separation
+// ============================================================================
+
+#[test]
+fn test_commitment_domain_tag_defaults_to_builtin() {
+    let (env, client) = setup();
+    assert_eq!(
+        client.get_domain_tag(),
+        Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG)
+    );
+}
+
+#[test]
+fn test_hash_algorithm_defaults_to_sha256() {
+    let (env, client) = setup();
+
+    assert_eq!(client.hash_algorithm(), Symbol::new(&env, "sha256"));
+    assert_eq!(client.hash_algorithm(), client.commitment_params().hash_alg);
+}
+
+#[test]
+fn test_commitment_bit_security_full_sha256() {
+    let (_env, client) = setup();
+
+    assert_eq!(client.commitment_bit_security(), 128);
+}
+
+#[test]
+fn test_collision_resistance_bits_truncated_digest() {
+    assert_eq!(crate::commitment::collision_resistance_bits(256), 128);
+    assert_eq!(crate::commitment::collision_resistance_bits(160), 80);
+    assert_eq!(crate::commitment::collision_resistance_bits(128), 64);
+}
+
+#[test]
+fn test_commitment_params_defaults() {
+    let (env, client) = setup();
+
+    let params = client.commitment_params();
+
+    assert_eq!(params.version, 1);
+    assert_eq!(params.hash_alg, Symbol::new(&env, "sha256"));
+    assert_eq!(params.min_salt, 0);
+    assert_eq!(params.max_salt, 1024);
+    assert_eq!(
+        params.domain_tag,
+        Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG)
+    );
+}
+
+#[test]
+fn test_commitment_differs_across_domain_tags() {
+    let (env, client_a) = setup();
+    let client_b = QuickexContractClient::new(&env, &env.register(QuickexContract, ()));
+    let admin = Address::generate(&env);
+    client_a.initialize(&admin);
+    client_b.initialize(&admin);
+
+    let owner = Address::generate(&env);
+    let amount = 2_500i128;
+    let salt = Bytes::from_slice(&env, b"domain_salt");
+
+    client_a.set_domain_tag(&admin, &Bytes::from_slice(&env, b"deployment-a"));
+    let commitment_a = client_a.create_amount_commitment(&owner, &amount, &salt);
+    assert!(client_a.verify_amount_commitment(&commitment_a, &owner, &amount, &salt));
+
+    client_b.set_domain_tag(&admin, &Bytes::from_slice(&env, b"deployment-b"));
+    let commitment_b = client_b.create_amount_commitment(&owner, &amount, &salt);
+    assert!(client_b.verify_amount_commitment(&commitment_b, &owner, &amount, &salt));
+
+    assert_ne!(commitment_a, commitment_b);
+    // A commitment from deployment A does not verify under deployment B's tag.
+    assert!(!client_b.verify_amount_commitment(&commitment_a, &owner, &amount, &salt));
+}
+
+#[test]
+fn test_set_domain_tag_only_once() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let tag = Bytes::from_slice(&env, b"deployment-a");
+    client.set_domain_tag(&admin, &tag);
+    let result = client.try_set_domain_tag(&admin, &Bytes::from_slice(&env, b"deployment-b"));
+    assert_eq!(result, Err(Ok(QuickexError::DomainTagLocked)));
+    assert_eq!(client.get_domain_tag(), tag);
+}
+
+#[test]
+fn test_set_domain_tag_rejects_invalid_length() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let empty = client.try_set_domain_tag(&admin, &Bytes::new(&env));
+    assert_eq!(empty, Err(Ok(QuickexError::InvalidDomainTag)));
+
+    let oversized = Bytes::from_array(&env, &[b'x'; 65]);
+    let result = client.try_set_domain_tag(&admin, &oversized);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidDomainTag)));
+}
+
+#[test]
+fn test_set_domain_tag_by_non_admin_fails() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.initialize(&admin);
+
+    let result = client.try_set_domain_tag(&stranger, &Bytes::from_slice(&env, b"hijack"));
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+}
+
+// ============================================================================
+// Ledger Anchoring
+// ============================================================================
+
+#[test]
+fn test_anchored_commitment_differs_across_sequences() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"anchor_salt");
+
+    env.ledger().set_sequence_number(100);
+    let (first, first_seq) = client.create_anchored_commitment(&owner, &500i128, &salt);
+    env.ledger().set_sequence_number(101);
+    let (second, second_seq) = client.create_anchored_commitment(&owner, &500i128, &salt);
+
+    assert_eq!((first_seq, second_seq), (100, 101));
+    assert_ne!(first, second);
+    assert_ne!(
+        first,
+        client.create_amount_commitment(&owner, &500i128, &salt)
+    );
+}
+
+#[test]
+fn test_anchored_commitment_requires_correct_sequence() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"anchor_salt");
+    env.ledger().set_sequence_number(42);
+
+    let (commitment, sequence) = client.create_anchored_commitment(&owner, &500i128, &salt);
+
+    assert!(client.verify_anchored_commitment(&commitment, &owner, &500i128, &salt, &sequence));
+    assert!(!client.verify_anchored_commitment(
+        &commitment,
+        &owner,
+        &500i128,
+        &salt,
+        &(sequence + 1)
+    ));
+}
+
+// ============================================================================
+// Tracked Commitments
+// ============================================================================
+
+#[test]
+fn test_tracked_commitment_records_creation_ledger() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"tracked");
+    env.ledger().set_sequence_number(1_234);
+
+    let commitment = client.create_tracked_commitment(&owner, &900i128, &salt);
+
+    assert_eq!(client.commitment_created_at(&commitment), Some(1_234));
+    assert_eq!(
+        commitment,
+        client.create_amount_commitment(&owner, &900i128, &salt)
+    );
+
+    let again = client.try_create_tracked_commitment(&owner, &900i128, &salt);
+    assert_eq!(again, Err(Ok(QuickexError::CommitmentAlreadyExists)));
+}
+
+#[test]
+fn test_commitment_created_at_unknown_is_none() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let untracked =
+        client.create_amount_commitment(&owner, &900i128, &Bytes::from_slice(&env, b"plain"));
+
+    assert_eq!(client.commitment_created_at(&untracked), None);
+    assert_eq!(
+        client.commitment_created_at(&BytesN::from_array(&env, &[0u8; 32])),
+        None
+    );
+}
+
+#[test]
+fn test_commitments_missing_returns_only_untracked() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let tracked_a =
+        client.create_tracked_commitment(&owner, &1i128, &Bytes::from_slice(&env, b"a"));
+    let tracked_b =
+        client.create_tracked_commitment(&owner, &2i128, &Bytes::from_slice(&env, b"b"));
+    let untracked = client.create_amount_commitment(&owner, &3i128, &Bytes::from_slice(&env, b"c"));
+    let unknown = BytesN::from_array(&env, &[9u8; 32]);
+
+    let expected = vec![
+        &env,
+        tracked_a,
+        untracked.clone(),
+        tracked_b,
+        unknown.clone(),
+    ];
+    assert_eq!(
+        client.commitments_missing(&expected),
+        vec![&env, untracked, unknown]
+    );
+    assert_eq!(client.commitments_missing(&vec![&env]), vec![&env]);
+}
+
+#[test]
+fn test_tracked_commitment_rate_limited_per_ledger() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+    env.ledger().set_sequence_number(50);
+
+    for i in 0..5i128 {
+        client.create_tracked_commitment(&owner, &i, &Bytes::from_slice(&env, b"rate"));
+    }
+    let limited =
+        client.try_create_tracked_commitment(&owner, &5i128, &Bytes::from_slice(&env, b"rate"));
+    assert_eq!(limited, Err(Ok(QuickexError::RateLimited)));
+    // The limit is per owner.
+    client.create_tracked_commitment(&other, &5i128, &Bytes::from_slice(&env, b"rate"));
+
+    env.ledger().set_sequence_number(51);
+    let commitment =
+        client.create_tracked_commitment(&owner, &5i128, &Bytes::from_slice(&env, b"rate"));
+    assert_eq!(client.commitment_created_at(&commitment), Some(51));
+}
+
+#[test]
+fn test_commitments_in_range_filters_by_ledger_and_limit() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let mut created = std::vec::Vec::new();
+    for (i, ledger) in [10u32, 20, 20, 30, 40].iter().enumerate() {
+        env.ledger().set_sequence_number(*ledger);
+        let salt = Bytes::from_slice(&env, &[i as u8; 4]);
+        created.push(client.create_tracked_commitment(&owner, &(i as i128), &salt));
+    }
+    // Untracked commitments are never listed.
+    client.create_amount_commitment(&owner, &7i128, &Bytes::from_slice(&env, b"plain"));
+
+    assert_eq!(
+        client.commitments_in_range(&20, &30, &10),
+        (
+            vec![
+                &env,
+                created[1].clone(),
+                created[2].clone(),
+                created[3].clone()
+            ],
+            31
+        )
+    );
+    // A ledger's commitments are returned together, so the limit can be overshot.
+    assert_eq!(
+        client.commitments_in_range(&0, &100, &2),
+        (
+            vec![
+                &env,
+                created[0].clone(),
+                created[1].clone(),
+                created[2].clone()
+            ],
+            21
+        )
+    );
+    assert_eq!(
+        client.commitments_in_range(&21, &100, &1),
+        (vec![&env, created[3].clone()], 31)
+    );
+    assert_eq!(
+        client.commitments_in_range(&41, &100, &10),
+        (vec![&env], 101)
+    );
+    assert_eq!(
+        client.try_commitments_in_range(&30, &20, &10),
+        Err(Ok(QuickexError::InvalidRange))
+    );
+}
+
+#[test]
+fn test_commitments_in_range_reads_a_bounded_window() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    env.ledger().set_sequence_number(500);
+    let late = client.create_tracked_commitment(&owner, &1i128, &Bytes::from_slice(&env, b"late"));
+
+    let (found, next) = client.commitments_in_range(&0, &u32::MAX, &10);
+    assert_eq!(found.len(), 0);
+    assert_eq!(next, MAX_TRACKED_SCAN_LEDGERS);
+    assert_eq!(
+        client.commitments_in_range(&400, &u32::MAX, &10),
+        (vec![&env, late], 400 + MAX_TRACKED_SCAN_LEDGERS)
+    );
+}
+
+#[test]
+fn test_create_tracked_commitment_caps_each_ledger() {
+    let (env, client) = setup();
+    env.ledger().set_sequence_number(7);
+    for i in 0..MAX_TRACKED_LEDGER_LEN {
+        let owner = Address::generate(&env);
+        client.create_tracked_commitment(&owner, &(i as i128), &Bytes::from_slice(&env, b"salt"));
+    }
+
+    let owner = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"salt");
+    let result = client.try_create_tracked_commitment(&owner, &1i128, &salt);
+    assert_eq!(result, Err(Ok(QuickexError::RateLimited)));
+
+    env.ledger().set_sequence_number(8);
+    client.create_tracked_commitment(&owner, &1i128, &salt);
+}
+
+#[test]
+fn test_commitment_expiry_adds_max_age() {
+    let (env, client) = setup();
+    env.ledger().set_timestamp(1_000);
+    let expiry = client.commitment_expiry(&env.ledger().timestamp(), &300);
+    assert_eq!(expiry, 1_300);
+
+    // Still fresh exactly at the expiry, stale one second later.
+    env.ledger().set_timestamp(expiry);
+    assert!(env.ledger().timestamp() <= expiry);
+    env.ledger().set_timestamp(expiry + 1);
+    assert!(env.ledger().timestamp() > expiry);
+
+    assert_eq!(client.commitment_expiry(&1_000, &0), 1_000);
+    assert_eq!(client.commitment_expiry(&(u64::MAX - 10), &300), u64::MAX);
+}
+
+// ============================================================================
+// Embedded Commitments
+// ============================================================================
+
+#[test]
+fn test_verify_commitment_at_reads_embedded_commitment() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"embedded");
+    let commitment = client.create_amount_commitment(&owner, &250i128, &salt);
+
+    let mut blob = Bytes::from_slice(&env, b"proof-header");
+    let offset = blob.len();
+    blob.append(&Bytes::from_array(&env, &commitment.to_array()));
+    blob.append(&Bytes::from_slice(&env, b"trailer"));
+
+    assert!(client.verify_commitment_at(&blob, &offset, &owner, &250i128, &salt));
+    assert!(!client.verify_commitment_at(&blob, &(offset + 1), &owner, &250i128, &salt));
+    assert!(!client.verify_commitment_at(&blob, &offset, &owner, &251i128, &salt));
+}
+
+#[test]
+fn test_verify_commitment_at_rejects_out_of_bounds_offset() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"embedded");
+    let commitment = client.create_amount_commitment(&owner, &250i128, &salt);
+    let blob = Bytes::from_array(&env, &commitment.to_array());
+
+    assert!(client.verify_commitment_at(&blob, &0, &owner, &250i128, &salt));
+    assert!(!client.verify_commitment_at(&blob, &1, &owner, &250i128, &salt));
+    assert!(!client.verify_commitment_at(&blob, &u32::MAX, &owner, &250i128, &salt));
+}
+
+// ============================================================================
+// Recorded Openings
+// ============================================================================
+
+#[test]
+fn test_record_opening_by_stores_revealer() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let revealer = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"opening");
+    let commitment = client.create_amount_commitment(&owner, &700i128, &salt);
+    assert_eq!(client.opening_revealer(&commitment), None);
+
+    client.record_opening_by(&revealer, &commitment, &owner, &700i128, &salt);
+    assert_eq!(client.opening_revealer(&commitment), Some(revealer.clone()));
+
+    // The first revealer stays on record.
+    let other = Address::generate(&env);
+    let again = client.try_record_opening_by(&other, &commitment, &owner, &700i128, &salt);
+    assert_eq!(again, Err(Ok(QuickexError::CommitmentAlreadyExists)));
+    assert_eq!(client.opening_revealer(&commitment), Some(revealer));
+}
+
+#[test]
+fn test_record_opening_by_rejects_wrong_opening() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let revealer = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"opening");
+    let commitment = client.create_amount_commitment(&owner, &700i128, &salt);
+
+    let result = client.try_record_opening_by(&revealer, &commitment, &owner, &701i128, &salt);
+    assert_eq!(result, Err(Ok(QuickexError::CommitmentMismatch)));
+    assert_eq!(client.opening_revealer(&commitment), None);
+}
+
+// ============================================================================
+// Linking
+// ============================================================================
+
+#[test]
+fn test_shared_owner_pair_returns_true() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt_a = Bytes::from_slice(&env, b"first");
+    let salt_b = Bytes::from_slice(&env, b"second");
+    let commitment_a = client.create_amount_commitment(&owner, &100i128, &salt_a);
+    let commitment_b = client.create_amount_commitment(&owner, &250i128, &salt_b);
+
+    assert!(client.shared_owner(
+        &commitment_a,
+        &100i128,
+        &salt_a,
+        &commitment_b,
+        &250i128,
+        &salt_b,
+        &owner
+    ));
+}
+
+#[test]
+fn test_shared_owner_non_matching_pair_returns_false() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+    let salt_a = Bytes::from_slice(&env, b"first");
+    let salt_b = Bytes::from_slice(&env, b"second");
+    let commitment_a = client.create_amount_commitment(&owner, &100i128, &salt_a);
+    let commitment_b = client.create_amount_commitment(&other, &250i128, &salt_b);
+
+    assert!(!client.shared_owner(
+        &commitment_a,
+        &100i128,
+        &salt_a,
+        &commitment_b,
+        &250i128,
+        &salt_b,
+        &owner
+    ));
+    // A wrong opening also fails even when both commitments belong to `owner`.
+    let commitment_c = client.create_amount_commitment(&owner, &250i128, &salt_b);
+    assert!(!client.shared_owner(
+        &commitment_a,
+        &100i128,
+        &salt_a,
+        &commitment_c,
+        &999i128,
+        &salt_b,
+        &owner
+    ));
+}
+
+// ============================================================================
+// Unsigned Amounts
+// ============================================================================
+
+#[test]
+fn test_u128_commitment_extremes_self_verify() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"u128_salt");
+
+    for amount in [0u128, u128::MAX] {
+        let commitment = client.create_u128_commitment(&owner, &amount, &salt);
+        assert!(client.verify_u128_commitment(&commitment, &owner, &amount, &salt));
+    }
+
+    let max = client.create_u128_commitment(&owner, &u128::MAX, &salt);
+    assert!(!client.verify_u128_commitment(&max, &owner, &(u128::MAX - 1), &salt));
+}
+
+#[test]
+fn test_u128_commitment_matches_signed_in_shared_range() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"shared_range");
+
+    assert_eq!(
+        client.create_u128_commitment(&owner, &1_000u128, &salt),
+        client.create_amount_commitment(&owner, &1_000i128, &salt)
+    );
+}
+
+// ============================================================================
+// Automatic Salts
+// ============================================================================
+
+#[test]
+fn test_auto_salt_verifies_returned_commitment() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+
+    let (commitment, salt) = client.create_commitment_auto_salt(&owner, &4_200i128);
+
+    assert_eq!(salt.len(), 32);
+    assert!(client.verify_amount_commitment(&commitment, &owner, &4_200i128, &salt));
+    assert!(!client.verify_amount_commitment(&commitment, &owner, &4_201i128, &salt));
+
+    let (_, other_salt) = client.create_commitment_auto_salt(&owner, &4_200i128);
+    assert_ne!(salt, other_salt);
+}
+
+// ============================================================================
+// Public Commitments
+// ============================================================================
+
+#[test]
+fn test_public_commitment_is_deterministic_per_owner() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let first = client.create_public_commitment(&owner, &500i128);
+    assert_eq!(first, client.create_public_commitment(&owner, &500i128));
+    assert_ne!(first, client.create_public_commitment(&other, &500i128));
+    assert_ne!(first, client.create_public_commitment(&owner, &501i128));
+
+    // Domain-separated from a salted commitment with an empty salt.
+    let empty_salt = client.create_amount_commitment(&owner, &500i128, &Bytes::new(&env));
+    assert_ne!(first, empty_salt);
+
+    let result = client.try_create_public_commitment(&owner, &-1i128);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidAmount)));
+}
+
+// ============================================================================
+// Length-Prefixed (v2) Commitments
+// ============================================================================
+
+/// v1 preimage followed by a trailing variable-length field.
+fn v1_preimage_with_trailer(
+    env: &Env,
+    owner: &Address,
+    amount: i128,
+    salt: &[u8],
+    trailer: &[u8],
+) -> Bytes {
+    let mut preimage = Bytes::from_slice(env, DEFAULT_DOMAIN_TAG);
+    preimage.append(&owner.clone().to_xdr(env));
+    preimage.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+    preimage.append(&Bytes::from_slice(env, salt));
+    preimage.append(&Bytes::from_slice(env, trailer));
+    preimage
+}
+
+/// v2 preimage followed by a trailing variable-length field.
+fn v2_preimage_with_trailer(
+    env: &Env,
+    owner: &Address,
+    amount: i128,
+    salt: &[u8],
+    trailer: &[u8],
+) -> Bytes {
+    let mut preimage = Bytes::from_slice(env, DEFAULT_DOMAIN_TAG);
+    preimage.push_back(COMMITMENT_VERSION_V2);
+    preimage.append(&owner.clone().to_xdr(env));
+    preimage.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+    preimage.append(&Bytes::from_array(env, &(salt.len() as u32).to_be_bytes()));
+    preimage.append(&Bytes::from_slice(env, salt));
+    preimage.append(&Bytes::from_slice(env, trailer));
+    preimage
+}
+
+#[test]
+fn test_v2_commitment_differs_from_v1_and_verifies() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"versioned");
+
+    let v1 = client.create_amount_commitment(&owner, &400i128, &salt);
+    let v2 = client.create_amount_commitment_v2(&owner, &400i128, &salt);
+    assert_ne!(v1, v2);
+
+    let expected: BytesN<32> = env
+        .crypto()
+        .sha256(&v2_preimage_with_trailer(
+            &env,
+            &owner,
+            400,
+            b"versioned",
+            b"",
+        ))
+        .into();
+    assert_eq!(v2, expected);
+
+    assert!(client.verify_amount_commitment_v2(&v2, &owner, &400i128, &salt));
+    assert!(!client.verify_amount_commitment_v2(&v1, &owner, &400i128, &salt));
+    assert!(!client.verify_amount_commitment_v2(&v2, &owner, &401i128, &salt));
+    assert!(!client.verify_amount_commitment(&v2, &owner, &400i128, &salt));
+
+    let result = client.try_create_amount_commitment_v2(&owner, &-1i128, &salt);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidAmount)));
+}
+
+#[test]
+fn test_v2_length_prefix_resolves_salt_boundary_ambiguity() {
+    let (env, _client) = setup();
+    let owner = Address::generate(&env);
+
+    // With a field after the salt, v1 cannot tell salt "ab" + memo "c" from
+    // salt "a" + memo "bc".
+    assert_eq!(
+        v1_preimage_with_trailer(&env, &owner, 7, b"ab", b"c"),
+        v1_preimage_with_trailer(&env, &owner, 7, b"a", b"bc")
+    );
+    assert_ne!(
+        v2_preimage_with_trailer(&env, &owner, 7, b"ab", b"c"),
+        v2_preimage_with_trailer(&env, &owner, 7, b"a", b"bc")
+    );
+}
+
+// ============================================================================
+// Hex Salts
+// ============================================================================
+
+#[test]
+fn test_hex_salt_matches_raw_salt() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let raw = Bytes::from_array(&env, &[0xde, 0xad, 0xBE, 0xef, 0x00, 0x7f]);
+
+    let from_hex = client.create_amount_commitment_hex(
+        &owner,
+        &42i128,
+        &Bytes::from_slice(&env, b"deADbeef007F"),
+    );
+
+    assert_eq!(
+        from_hex,
+        client.create_amount_commitment(&owner, &42i128, &raw)
+    );
+}
+
+#[test]
+fn test_invalid_hex_salt_rejected() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+
+    for bad in [b"abc" as &[u8], b"zz", b"0x12"] {
+        let result =
+            client.try_create_amount_commitment_hex(&owner, &42i128, &Bytes::from_slice(&env, bad));
+        assert_eq!(result, Err(Ok(QuickexError::InvalidHex)));
+    }
+}
+
+#[test]
+fn test_hex_salt_length_capped_before_decoding() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+
+    let max = Bytes::from_slice(&env, &[b'a'; 2048]);
+    let expected =
+        client.create_amount_commitment(&owner, &1i128, &Bytes::from_array(&env, &[0xaa; 1024]));
+    assert_eq!(
+        client.create_amount_commitment_hex(&owner, &1i128, &max),
+        expected
+    );
+
+    let oversized = Bytes::from_slice(&env, &[b'a'; 2050]);
+    let result = client.try_create_amount_commitment_hex(&owner, &1i128, &oversized);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidSalt)));
+}
+
+// ============================================================================
+// Salt Policy
+// ============================================================================
+
+#[test]
+fn test_is_valid_salt_default_policy() {
+    let (env, client) = setup();
+
+    assert!(client.is_valid_salt(&Bytes::new(&env)));
+    assert!(client.is_valid_salt(&Bytes::from_array(&env, &[0u8; 1024])));
+    assert!(!client.is_valid_salt(&Bytes::from_array(&env, &[0u8; 1025])));
+}
+
+#[test]
+fn test_is_valid_salt_under_configured_limits() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_salt_policy(&admin, &16, &32);
+
+    assert!(client.is_valid_salt(&Bytes::from_array(&env, &[1u8; 16])));
+    assert!(client.is_valid_salt(&Bytes::from_array(&env, &[1u8; 32])));
+    assert!(!client.is_valid_salt(&Bytes::from_array(&env, &[1u8; 15])));
+    assert!(!client.is_valid_salt(&Bytes::from_array(&env, &[1u8; 33])));
+
+    let params = client.commitment_params();
+    assert_eq!((params.min_salt, params.max_salt), (16, 32));
+}
+
+#[test]
+fn test_commitment_enforces_configured_salt_policy() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_salt_policy(&admin, &16, &32);
+
+    let short = Bytes::from_slice(&env, b"short");
+    assert!(!client.is_valid_salt(&short));
+    let result = client.try_create_amount_commitment(&owner, &1_000i128, &short);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidSalt)));
+
+    let ok = Bytes::from_array(&env, &[7u8; 20]);
+    assert!(client.is_valid_salt(&ok));
+    let result = client.try_create_amount_commitment(&owner, &1_000i128, &ok);
+    assert!(matches!(result, Ok(Ok(_))));
+}
+
+#[test]
+fn test_strict_verify_rejects_salt_outside_policy() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    client.initialize(&admin);
+    let short = Bytes::from_slice(&env, b"short");
+    let commitment = client.create_amount_commitment(&owner, &1_000i128, &short);
+    assert!(client.verify_commitment_strict(&commitment, &owner, &1_000i128, &short));
+
+    client.set_salt_policy(&admin, &16, &32);
+
+    assert!(client.verify_amount_commitment(&commitment, &owner, &1_000i128, &short));
+    assert!(!client.verify_commitment_strict(&commitment, &owner, &1_000i128, &short));
+    assert!(!client.verify_commitment_strict(&commitment, &owner, &1_001i128, &short));
+}
+
+#[test]
+fn test_set_salt_policy_rejects_invalid_bounds() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.initialize(&admin);
+
+    let inverted = client.try_set_salt_policy(&admin, &32, &16);
+    assert_eq!(inverted, Err(Ok(QuickexError::InvalidSaltPolicy)));
+
+    let over_cap = client.try_set_salt_policy(&admin, &0, &1025);
+    assert_eq!(over_cap, Err(Ok(QuickexError::InvalidSaltPolicy)));
+
+    let result = client.try_set_salt_policy(&stranger, &0, &64);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+}
+
+// ============================================================================
+// Viewing-Key Commitments
+// ============================================================================
+
+#[test]
+fn test_viewkey_commitment_requires_correct_viewkey() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"viewkey_salt");
+    let viewkey = BytesN::from_array(&env, &[0x11; 32]);
+    let wrong_key = BytesN::from_array(&env, &[0x12; 32]);
+
+    let commitment = client.create_viewkey_commitment(&owner, &800i128, &salt, &viewkey);
+
+    assert!(client.verify_viewkey_commitment(&commitment, &owner, &800i128, &salt, &viewkey));
+    assert!(!client.verify_viewkey_commitment(&commitment, &owner, &800i128, &salt, &wrong_key));
+    assert!(!client.verify_viewkey_commitment(&commitment, &owner, &801i128, &salt, &viewkey));
+    // The plain opening without the viewing key does not verify either.
+    assert!(!client.verify_amount_commitment(&commitment, &owner, &800i128, &salt));
+}
+
+// ============================================================================
+// Preimage Structure
+// ============================================================================
+
+fn amount_preimage(env: &Env, owner: &Address, amount: i128, salt: &Bytes) -> Bytes {
+    let mut preimage = Bytes::from_slice(env, DEFAULT_DOMAIN_TAG);
+    preimage.append(&owner.clone().to_xdr(env));
+    preimage.extend_from_array(&amount.to_be_bytes());
+    preimage.append(salt);
+    preimage
+}
+
+#[test]
+fn test_validate_preimage_structure_accepts_well_formed_preimage() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"structure");
+    let preimage = amount_preimage(&env, &owner, 1_000, &salt);
+
+    assert!(client.validate_preimage_structure(&preimage, &owner));
+    let hash: BytesN<32> = env.crypto().sha256(&preimage).into();
+    assert_eq!(
+        hash,
+        client.create_amount_commitment(&owner, &1_000i128, &salt)
+    );
+}
+
+#[test]
+fn test_validate_preimage_structure_rejects_malformed_preimage() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"structure");
+    let preimage = amount_preimage(&env, &owner, 1_000, &salt);
+
+    assert!(!client.validate_preimage_structure(&preimage, &other));
+    assert!(!client.validate_preimage_structure(&preimage.slice(..preimage.len() - 10), &owner));
+
+    let oversized = amount_preimage(&env, &owner, 1_000, &Bytes::from_array(&env, &[0xFF; 1025]));
+    assert!(!client.validate_preimage_structure(&oversized, &owner));
+}
+
+// ============================================================================
+// Payload Commitments
+// ============================================================================
+
+#[test]
+fn test_payload_commitment_self_verifies() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let payload = BytesN::from_array(&env, &[0x5A; 32]);
+    let salt = Bytes::from_slice(&env, b"payload_salt");
+
+    let commitment = client.create_payload_commitment(&owner, &payload, &salt);
+
+    assert!(client.verify_payload_commitment(&commitment, &owner, &payload, &salt));
+}
+
+#[test]
+fn test_payload_commitment_rejects_single_bit_change() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let mut raw = [0x5A; 32];
+    let payload = BytesN::from_array(&env, &raw);
+    let salt = Bytes::from_slice(&env, b"payload_salt");
+
+    let commitment = client.create_payload_commitment(&owner, &payload, &salt);
+
+    raw[31] ^= 0x01;
+    let flipped = BytesN::from_array(&env, &raw);
+    assert!(!client.verify_payload_commitment(&commitment, &owner, &flipped, &salt));
+}
+
+#[test]
+fn test_payload_commitment_differs_from_amount_commitment() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    // A payload whose first 16 bytes encode an amount, with the rest carried as salt.
+    let mut raw = [0u8; 32];
+    raw[..16].copy_from_slice(&1_000i128.to_be_bytes());
+    let payload = BytesN::from_array(&env, &raw);
+
+    let payload_commitment = client.create_payload_commitment(&owner, &payload, &Bytes::new(&env));
+    let amount_commitment =
+        client.create_amount_commitment(&owner, &1_000i128, &Bytes::from_array(&env, &[0u8; 16]));
+
+    assert_ne!(payload_commitment, amount_commitment);
+}
+
+#[test]
+fn test_payload_commitment_oversized_salt_rejected() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let payload = BytesN::from_array(&env, &[1; 32]);
+    let salt = Bytes::from_array(&env, &[0xFF; 1025]);
+
+    let result = client.try_create_payload_commitment(&owner, &payload, &salt);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidSalt)));
+}
+
+#[test]
+fn test_map_commitment_is_insertion_order_independent() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"fee_breakdown");
+    let base = Symbol::new(&env, "base");
+    let network = Symbol::new(&env, "network");
+    let service = Symbol::new(&env, "service");
+
+    let mut forward = Map::new(&env);
+    forward.set(base.clone(), 1_000i128);
+    forward.set(network.clone(), 25i128);
+    forward.set(service.clone(), 10i128);
+    let mut backward = Map::new(&env);
+    backward.set(service, 10i128);
+    backward.set(network.clone(), 25i128);
+    backward.set(base, 1_000i128);
+
+    let commitment = client.create_map_commitment(&owner, &forward, &salt);
+    assert_eq!(
+        client.create_map_commitment(&owner, &backward, &salt),
+        commitment
+    );
+    assert!(client.verify_map_commitment(&commitment, &owner, &backward, &salt));
+
+    backward.set(network, 26i128);
+    assert!(!client.verify_map_commitment(&commitment, &owner, &backward, &salt));
+}
+
+#[test]
+fn test_map_commitment_rejects_negative_amount() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"salt");
+    let mut entries = Map::new(&env);
+    entries.set(Symbol::new(&env, "base"), 100i128);
+    entries.set(Symbol::new(&env, "refund"), -1i128);
+
+    let result = client.try_create_map_commitment(&owner, &entries, &salt);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidAmount)));
+}
+
+#[test]
+fn test_merge_commitments_commits_to_sum() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt_a = Bytes::from_slice(&env, b"merge_a");
+    let salt_b = Bytes::from_slice(&env, b"merge_b");
+    let merged_salt = Bytes::from_slice(&env, b"merged");
+    let a = client.create_amount_commitment(&owner, &300i128, &salt_a);
+    let b = client.create_amount_commitment(&owner, &450i128, &salt_b);
+
+    let merged = client.merge_commitments(
+        &a,
+        &300i128,
+        &salt_a,
+        &b,
+        &450i128,
+        &salt_b,
+        &merged_salt,
+        &owner,
+    );
+    assert!(client.verify_amount_commitment(&merged, &owner, &750i128, &merged_salt));
+
+    // A wrong opening is rejected.
+    let result = client.try_merge_commitments(
+        &a,
+        &301i128,
+        &salt_a,
+        &b,
+        &450i128,
+        &salt_b,
+        &merged_salt,
+        &owner,
+    );
+    assert_eq!(result, Err(Ok(QuickexError::CommitmentMismatch)));
+}
+
+#[test]
+fn test_merge_commitments_overflow_reverts() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let salt_a = Bytes::from_slice(&env, b"max_a");
+    let salt_b = Bytes::from_slice(&env, b"max_b");
+    let a = client.create_amount_commitment(&owner, &i128::MAX, &salt_a);
+    let b = client.create_amount_commitment(&owner, &1i128, &salt_b);
+
+    let result = client.try_merge_commitments(
+        &a,
+        &i128::MAX,
+        &salt_a,
+        &b,
+        &1i128,
+        &salt_b,
+        &Bytes::from_slice(&env, b"merged"),
+        &owner,
+    );
+    assert_eq!(result, Err(Ok(QuickexError::InvalidAmount)));
+}
+
+#[test]
+fn test_verify_aggregate_matches_parts() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let parts = vec![
+        &env,
+        client.create_amount_commitment(&owner, &1i128, &Bytes::from_slice(&env, b"p1")),
+        client.create_amount_commitment(&owner, &2i128, &Bytes::from_slice(&env, b"p2")),
+        client.create_amount_commitment(&owner, &3i128, &Bytes::from_slice(&env, b"p3")),
+    ];
+
+    let root = client.aggregate_commitments(&parts);
+    assert!(client.verify_aggregate(&root, &parts));
+    assert!(client.verify_aggregate(&client.aggregate_commitments(&vec![&env]), &vec![&env]));
+}
+
+#[test]
+fn test_verify_aggregate_rejects_altered_parts() {
+    let (env, client) = setup();
+    let a = BytesN::from_array(&env, &[1u8; 32]);
+    let b = BytesN::from_array(&env, &[2u8; 32]);
+    let root = client.aggregate_commitments(&vec![&env, a.clone(), b.clone()]);
+
+    let altered = BytesN::from_array(&env, &[3u8; 32]);
+    assert!(!client.verify_aggregate(&root, &vec![&env, a.clone(), altered]));
+    assert!(!client.verify_aggregate(&root, &vec![&env, b.clone(), a.clone()]));
+    assert!(!client.verify_aggregate(&root, &vec![&env, a]));
+}
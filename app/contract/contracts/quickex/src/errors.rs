@@ -15,6 +15,34 @@ pub enum QuickexError {
     InvalidAmount = 100,
     InvalidSalt = 101,
     InvalidPrivacyLevel = 102,
+    /// Signature is malformed or the signer has no ed25519 key.
+    InvalidSignature = 103,
+    /// Commitment domain tag is empty or exceeds the maximum length.
+    InvalidDomainTag = 104,
+    /// Escrow expiry ledger is not in the future.
+    InvalidExpiry = 105,
+    /// Salt length policy has `min > max` or exceeds the hard salt cap.
+    InvalidSaltPolicy = 106,
+    /// History limit is zero or exceeds the global maximum.
+    InvalidHistoryLimit = 107,
+    /// Query range is inverted or wider than the allowed maximum.
+    InvalidRange = 108,
+    /// Approval threshold is zero or exceeds the number of approvers.
+    InvalidThreshold = 109,
+    /// Input expected as ASCII hex has odd length or a non-hex character.
+    InvalidHex = 110,
+    /// Escrow sender and recipient are the same address.
+    SelfEscrow = 111,
+    /// Fee or fee discount exceeds 10000 basis points.
+    InvalidFeeBps = 112,
+    /// Escrow amount exceeds the admin-configured ceiling.
+    AmountTooLarge = 113,
+    /// Paired input lists have different lengths.
+    LengthMismatch = 114,
+    /// Opened amount is below the required minimum.
+    AmountBelowMinimum = 115,
+    /// Encrypted memo exceeds the maximum length.
+    MemoTooLong = 116,
     // Auth/admin failures (200-299)
     Unauthorized = 200,
     AlreadyInitialized = 201,
@@ -32,6 +60,38 @@ pub enum QuickexError {
     EscrowNotExpired = 308,
     /// Caller is not the original owner of the escrow.
     InvalidOwner = 309,
+    /// No id-based escrow exists for the given id.
+    EscrowNotFound = 310,
+    /// Account has no privacy level configured.
+    PrivacyNotConfigured = 311,
+    /// No campaign exists for the given id.
+    CampaignNotFound = 312,
+    /// Escrow already belongs to a campaign.
+    AlreadyInCampaign = 313,
+    /// Escrow has not collected enough approvals to be released.
+    InsufficientApprovals = 314,
+    /// Account's privacy settings are frozen by the admin.
+    PrivacyFrozen = 315,
+    /// Escrow's minimum lock period has not elapsed yet.
+    LockNotElapsed = 316,
+    /// Name registry could not resolve the escrow recipient.
+    NameNotResolved = 317,
+    /// Owner exceeded the per-ledger limit for tracked commitment creations.
+    RateLimited = 318,
+    /// Escrow is not in the `Disputed` state.
+    EscrowNotDisputed = 319,
+    /// Dispute deadline has not been reached yet.
+    DisputeWindowOpen = 320,
+    /// Preimage does not hash to the escrow's hash lock.
+    HashlockMismatch = 321,
+    /// Account sealed its privacy settings; they can no longer change.
+    PrivacySealed = 322,
+    /// Commitment Merkle tree has no free leaf left.
+    MerkleTreeFull = 323,
+    /// Too many escrows already expire at the requested ledger.
+    ExpiryLedgerFull = 324,
+    /// Domain tag was already set, or deposits exist that were committed under it.
+    DomainTagLocked = 325,
     // Internal/unexpected conditions (900-999)
     InternalError = 900,
 }
@@ -13,6 +13,9 @@
 //! - `refund` fails with [`EscrowNotExpired`] if `expires_at == 0` or `now < expires_at`.
 //! - Both fail with [`AlreadySpent`] if status is not `Pending`.
 //! - `refund` fails with [`InvalidOwner`] if caller ≠ `entry.owner`.
+//!
+//! Every transition keeps the per-token locked balance in sync, so [`reconcile`]
+//! can compare it against the contract's actual token holdings.
 
 use soroban_sdk::{token, Address, Bytes, BytesN, Env};
 
@@ -20,7 +23,7 @@ use crate::{
     commitment,
     errors::QuickexError,
     events,
-    storage::{get_escrow, has_escrow, put_escrow},
+    storage::{self, get_escrow, get_locked_balance, has_escrow, put_escrow, set_locked_balance},
     types::{EscrowEntry, EscrowStatus},
 };
 
@@ -35,6 +38,47 @@ fn is_expired(env: &Env, entry: &EscrowEntry) -> bool {
     entry.expires_at > 0 && env.ledger().timestamp() >= entry.expires_at
 }
 
+/// Record `amount` of `token` as held by an open escrow.
+fn lock(env: &Env, token: &Address, amount: i128) {
+    set_locked_balance(env, token, get_locked_balance(env, token) + amount);
+}
+
+/// Release `amount` of `token` from the locked balance once an escrow settles.
+fn unlock(env: &Env, token: &Address, amount: i128) {
+    set_locked_balance(env, token, get_locked_balance(env, token) - amount);
+}
+
+/// Fix the domain tag once the first deposit is keyed by a commitment under it.
+fn fix_domain_tag(env: &Env) {
+    if !storage::is_domain_tag_locked(env) {
+        storage::lock_domain_tag(env);
+    }
+}
+
+/// Find the escrow opened by (`owner`, `amount`, `salt`) and return it with its key.
+///
+/// Looks up the commitment under the current domain tag first, then the untagged
+/// commitment of deposits made before domain tags existed.
+///
+/// # Errors
+/// - [`InvalidAmount`] / [`InvalidSalt`] – see [`commitment::create_amount_commitment`].
+/// - [`CommitmentNotFound`] – no escrow for either commitment.
+pub fn find_escrow(
+    env: &Env,
+    owner: &Address,
+    amount: i128,
+    salt: Bytes,
+) -> Result<(BytesN<32>, EscrowEntry), QuickexError> {
+    let commitment =
+        commitment::create_amount_commitment(env, owner.clone(), amount, salt.clone())?;
+    if let Some(entry) = get_escrow(env, &commitment.clone().into()) {
+        return Ok((commitment, entry));
+    }
+    let legacy = commitment::legacy_amount_commitment(env, owner.clone(), amount, salt);
+    let entry = get_escrow(env, &legacy.clone().into()).ok_or(QuickexError::CommitmentNotFound)?;
+    Ok((legacy, entry))
+}
+
 // ---------------------------------------------------------------------------
 // deposit
 // ---------------------------------------------------------------------------
@@ -81,9 +125,11 @@ pub fn deposit(
     };
 
     put_escrow(env, &commitment.clone().into(), &entry);
+    fix_domain_tag(env);
 
     let token_client = token::Client::new(env, &token);
     token_client.transfer(&owner, env.current_contract_address(), &amount);
+    lock(env, &token, amount);
 
     events::publish_deposit(env, commitment.clone(), token, amount);
 
@@ -122,6 +168,7 @@ pub fn deposit_with_commitment(
 
     let token_client = token::Client::new(env, &token);
     token_client.transfer(&from, env.current_contract_address(), &amount);
+    lock(env, &token, amount);
 
     let now = env.ledger().timestamp();
     let expires_at = if timeout_secs > 0 {
@@ -140,6 +187,7 @@ pub fn deposit_with_commitment(
     };
 
     put_escrow(env, &commitment.clone().into(), &entry);
+    fix_domain_tag(env);
     events::publish_deposit(env, commitment, token, amount);
 
     Ok(())
@@ -152,7 +200,8 @@ pub fn deposit_with_commitment(
 /// Withdraw escrowed funds by proving commitment ownership.
 ///
 /// The caller (`to`) must authorize. The commitment is recomputed from
-/// `to`, `amount`, and `salt` and must match an existing pending escrow.
+/// `to`, `amount`, and `salt` and must match an existing pending escrow, see
+/// [`find_escrow`].
 ///
 /// # Errors
 /// - [`InvalidAmount`] – amount ≤ 0.
@@ -167,12 +216,9 @@ pub fn withdraw(env: &Env, amount: i128, to: Address, salt: Bytes) -> Result<boo
 
     to.require_auth();
 
-    let commitment = commitment::create_amount_commitment(env, to.clone(), amount, salt)?;
+    let (commitment, entry) = find_escrow(env, &to, amount, salt)?;
     let commitment_bytes: Bytes = commitment.clone().into();
 
-    let entry: EscrowEntry =
-        get_escrow(env, &commitment_bytes).ok_or(QuickexError::CommitmentNotFound)?;
-
     if entry.status != EscrowStatus::Pending {
         return Err(QuickexError::AlreadySpent);
     }
@@ -192,12 +238,40 @@ pub fn withdraw(env: &Env, amount: i128, to: Address, salt: Bytes) -> Result<boo
 
     let token_client = token::Client::new(env, &entry.token);
     token_client.transfer(&env.current_contract_address(), &to, &amount);
+    unlock(env, &entry.token, amount);
 
     events::publish_withdraw_toggled(env, to, commitment);
 
     Ok(true)
 }
 
+// ---------------------------------------------------------------------------
+// settle_at_least
+// ---------------------------------------------------------------------------
+
+/// Withdraw the escrow opened by (`to`, `amount`, `salt`) if `amount` covers `minimum`.
+///
+/// Same lookup and transfer as [`withdraw`], which recomputes the commitment from the
+/// opening. This does not keep the amount private: `amount` is a plain call argument
+/// and the token transfer event carries it. Only the QuickEx event leaves it out.
+///
+/// # Errors
+/// - [`AmountBelowMinimum`] – `amount < minimum`.
+/// - Any error of [`withdraw`].
+pub fn settle_at_least(
+    env: &Env,
+    to: Address,
+    amount: i128,
+    salt: Bytes,
+    minimum: i128,
+) -> Result<(), QuickexError> {
+    if amount < minimum {
+        return Err(QuickexError::AmountBelowMinimum);
+    }
+    withdraw(env, amount, to, salt)?;
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // refund
 // ---------------------------------------------------------------------------
@@ -238,8 +312,45 @@ pub fn refund(env: &Env, commitment: BytesN<32>, caller: Address) -> Result<(),
 
     let token_client = token::Client::new(env, &entry.token);
     token_client.transfer(&env.current_contract_address(), &entry.owner, &entry.amount);
+    unlock(env, &entry.token, entry.amount);
 
     events::publish_refunded(env, entry.owner, commitment, entry.amount);
 
     Ok(())
 }
+
+// ---------------------------------------------------------------------------
+// reconcile
+// ---------------------------------------------------------------------------
+
+/// Return the sum of amounts held by open escrows of `token`.
+///
+/// Maintained incrementally on deposit, withdrawal, and refund. Unlike the raw
+/// on-chain balance it ignores funds not backed by an escrow.
+pub fn locked_balance(env: &Env, token: &Address) -> i128 {
+    get_locked_balance(env, token)
+}
+
+/// Return the contract's `token` balance minus the amount locked in open escrows.
+///
+/// Zero in the healthy case. A positive value means the contract holds unaccounted
+/// funds (e.g. a direct transfer); a negative value means escrows are under-funded.
+pub fn reconcile(env: &Env, token: Address) -> i128 {
+    let balance = token::Client::new(env, &token).balance(&env.current_contract_address());
+    balance - locked_balance(env, &token)
+}
+
+/// Return the contract's `token` balance as basis points of what it owes in `token`.
+///
+/// Obligations are the locked principal of open escrows. Below `10_000` the contract
+/// is under-collateralized. With no obligations, or a ratio that does not fit, returns
+/// `u32::MAX`.
+pub fn solvency_ratio_bps(env: &Env, token: Address) -> u32 {
+    let obligations = locked_balance(env, &token);
+    if obligations <= 0 {
+        return u32::MAX;
+    }
+    let balance = token::Client::new(env, &token).balance(&env.current_contract_address());
+    let ratio = balance.saturating_mul(10_000) / obligations;
+    u32::try_from(ratio.max(0)).unwrap_or(u32::MAX)
+}
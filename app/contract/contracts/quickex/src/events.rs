@@ -26,6 +26,40 @@ pub struct DepositToggledEvent {
     pub amount: i128,
 }
 
+/// Emitted when an account's numeric privacy level is set and its events are verbose.
+#[contractevent(topics = ["PrivacyLevelSet"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrivacyLevelSetEvent {
+    #[topic]
+    pub account: Address,
+
+    pub level: u32,
+    pub timestamp: u64,
+}
+
+/// Emitted instead of [`PrivacyLevelSetEvent`] for accounts without verbose events.
+///
+/// Carries only the account; the level is withheld.
+#[contractevent(topics = ["PrivacyLevelSetRedacted"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrivacyLevelSetRedactedEvent {
+    #[topic]
+    pub account: Address,
+}
+
+pub(crate) fn publish_privacy_level_set(env: &Env, account: Address, level: u32) {
+    PrivacyLevelSetEvent {
+        account,
+        level,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+pub(crate) fn publish_privacy_level_set_redacted(env: &Env, account: Address) {
+    PrivacyLevelSetRedactedEvent { account }.publish(env);
+}
+
 pub(crate) fn publish_privacy_toggled(env: &Env, owner: Address, enabled: bool, timestamp: u64) {
     PrivacyToggledEvent {
         owner,
@@ -135,3 +169,179 @@ pub(crate) fn publish_refunded(env: &Env, owner: Address, commitment: BytesN<32>
     }
     .publish(env);
 }
+
+#[contractevent(topics = ["EscrowCreated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowCreatedEvent {
+    #[topic]
+    pub id: u64,
+    pub from: Address,
+    pub to: Address,
+    pub amount: u64,
+}
+
+pub(crate) fn publish_escrow_created(env: &Env, id: u64, from: Address, to: Address, amount: u64) {
+    EscrowCreatedEvent {
+        id,
+        from,
+        to,
+        amount,
+    }
+    .publish(env);
+}
+
+/// Creation event for escrows whose sender has a high privacy level.
+///
+/// Carries only the escrow id; counterparties and amount are withheld.
+#[contractevent(topics = ["EscrowCreatedPrivate"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowCreatedPrivateEvent {
+    #[topic]
+    pub id: u64,
+}
+
+pub(crate) fn publish_escrow_created_private(env: &Env, id: u64) {
+    EscrowCreatedPrivateEvent { id }.publish(env);
+}
+
+#[contractevent(topics = ["EscrowReleased"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowReleasedEvent {
+    #[topic]
+    pub id: u64,
+    pub to: Address,
+    pub amount: u64,
+    pub timestamp: u64,
+}
+
+pub(crate) fn publish_escrow_released(env: &Env, id: u64, to: Address, amount: u64) {
+    EscrowReleasedEvent {
+        id,
+        to,
+        amount,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+#[contractevent(topics = ["EscrowRefunded"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowRefundedEvent {
+    #[topic]
+    pub id: u64,
+    pub from: Address,
+    pub amount: u64,
+    pub timestamp: u64,
+}
+
+pub(crate) fn publish_escrow_refunded(env: &Env, id: u64, from: Address, amount: u64) {
+    EscrowRefundedEvent {
+        id,
+        from,
+        amount,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+/// Emitted when an escrow is refunded because it reached its expiry ledger.
+#[contractevent(topics = ["EscrowExpired"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowExpiredEvent {
+    #[topic]
+    pub id: u64,
+    pub refunded_amount: u64,
+    pub timestamp: u64,
+}
+
+pub(crate) fn publish_escrow_expired(env: &Env, id: u64, refunded_amount: u64) {
+    EscrowExpiredEvent {
+        id,
+        refunded_amount,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+#[contractevent(topics = ["EscrowCancelled"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowCancelledEvent {
+    #[topic]
+    pub id: u64,
+    pub cancelled_by: Address,
+    pub timestamp: u64,
+}
+
+pub(crate) fn publish_escrow_cancelled(env: &Env, id: u64, cancelled_by: Address) {
+    EscrowCancelledEvent {
+        id,
+        cancelled_by,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+#[contractevent(topics = ["EscrowDisputed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowDisputedEvent {
+    #[topic]
+    pub id: u64,
+    pub disputed_by: Address,
+    pub timestamp: u64,
+}
+
+pub(crate) fn publish_escrow_disputed(env: &Env, id: u64, disputed_by: Address) {
+    EscrowDisputedEvent {
+        id,
+        disputed_by,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+/// Governance event for an admin break-glass resolution of an escrow.
+#[contractevent(topics = ["EscrowForceResolved"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowForceResolvedEvent {
+    #[topic]
+    pub id: u64,
+    pub admin: Address,
+    pub refunded_to_sender: bool,
+    pub timestamp: u64,
+}
+
+pub(crate) fn publish_escrow_force_resolved(
+    env: &Env,
+    id: u64,
+    admin: Address,
+    refunded_to_sender: bool,
+) {
+    EscrowForceResolvedEvent {
+        id,
+        admin,
+        refunded_to_sender,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+/// Emitted when the admin collects the escrow fees withheld in a token.
+#[contractevent(topics = ["EscrowFeesCollected"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowFeesCollectedEvent {
+    #[topic]
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub(crate) fn publish_escrow_fees_collected(env: &Env, token: Address, to: Address, amount: i128) {
+    EscrowFeesCollectedEvent {
+        token,
+        to,
+        amount,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
@@ -0,0 +1,1797 @@
+//! Id-based escrow logic: create, release, expiry refunds, and campaigns.
+//!
+//! These escrows are addressed by the sequential id returned from `create_escrow`
+//! and record sender, recipient, and amount in the clear. For commitment-keyed
+//! escrows see [`crate::escrow`].
+//!
+//! # State Machine
+//!
+//! ```text
+//! [*] --> Pending  : create_escrow() / create_unfunded_escrow() / create_escrow_with_expiry() / open_escrow_terms()
+//! Pending --> Released : release_with_receipt(receipt signed by `from`)
+//! Pending --> Refunded : claim_expired()  [sequence >= expiry_ledger]
+//! Pending --> Expired : mark_expired()  [sequence >= expiry_ledger]
+//! Expired --> Refunded : claim_expired_funds(from)
+//! Pending --> Cancelled : cancel_escrow(to; from too once expired)
+//! Pending --> Refunded : refund_escrow(to)
+//! Pending --> Released : release_campaign(campaign owner or admin)
+//! Pending --> Released : release_escrow(from) / release_to_many(from)
+//! Pending --> Disputed : dispute_escrow(from or to)
+//! Pending / Disputed --> Released / Refunded : admin_force_resolve(admin)
+//! Disputed --> Released / Refunded : resolve_dispute(arbiter)
+//! Disputed --> Refunded : resolve_dispute_timeout(from or to)  [deadline passed]
+//! ```
+//!
+//! Guard rails:
+//! - Every transition fails with [`EscrowNotFound`] for an unknown id.
+//! - Every transition fails with [`AlreadySpent`] if status is not `Pending`, except
+//!   `claim_expired_funds`, which requires `Expired`.
+//! - `claim_expired` and `mark_expired` fail with [`EscrowNotExpired`] if
+//!   `expiry_ledger == 0` or `sequence < expiry_ledger`; `claim_expired_funds` fails
+//!   with it while the escrow is still `Pending`.
+//! - An `Expired` escrow keeps its funds and counts towards the open-value total until
+//!   its sender claims the refund.
+//! - `cancel_escrow` and `dispute_escrow` fail with [`Unauthorized`] if the caller is
+//!   neither party; `cancel_escrow` by the sender fails with [`EscrowNotExpired`] before
+//!   the expiry ledger.
+//! - A `Disputed` escrow keeps its funds and counts towards the open-value total, but
+//!   only its arbiter (`resolve_dispute`) or `admin_force_resolve` can move it on until
+//!   the dispute deadline passes, after which either party may refund the sender with
+//!   `resolve_dispute_timeout`. The arbiter is the admin unless both parties appointed
+//!   another with `change_arbiter`.
+//! - Every release path fails with [`LockNotElapsed`] before
+//!   `created_ledger + min_lock_ledgers`, and with [`InsufficientApprovals`] for an
+//!   M-of-N escrow that has fewer than `threshold` distinct approvals;
+//!   `release_campaign` skips such escrows.
+//!
+//! The sum of all `Pending` amounts is kept as a running total (see [`total_escrowed`]),
+//! increased on creation and decreased whenever an escrow settles. Each sender's number
+//! of unsettled escrows is cached the same way (see [`open_escrow_count`]) and can be
+//! rebuilt from the per-sender index with [`repair_open_count`].
+//!
+//! Escrows with an expiry are tracked while `Pending` in a bucket per expiry ledger,
+//! holding at most [`MAX_EXPIRY_BUCKET_LEN`] ids. [`due_refunds`] walks the buckets from
+//! a caller-supplied ledger for keepers, and [`sweep_expired`] keeps its own cursor, each
+//! reading at most [`MAX_EXPIRY_SCAN_LEDGERS`] buckets per call.
+//!
+//! Hashlock escrows (see [`create_hashlock_escrow`]) are released by revealing the
+//! preimage of their hash lock before their timeout ledger, and refunded to the sender
+//! after it, for HTLC-style atomic swaps.
+//!
+//! Escrows can be grouped under a [`Campaign`] so they can be managed as a batch.
+//!
+//! Creation emits a full `EscrowCreated` event, or an id-only `EscrowCreatedPrivate`
+//! event when the sender's privacy level is at least
+//! [`HIGH_PRIVACY_LEVEL`](crate::privacy::HIGH_PRIVACY_LEVEL).
+//!
+//! Escrows created with an interest rate accrue simple interest on `amount` for each
+//! ledger between creation and release; see [`accrued_interest`].
+//!
+//! An admin-configured escrow fee is withheld from the payout on release. Senders at
+//! higher privacy levels can be given a discount on it; see [`effective_fee_bps`].
+//!
+//! Escrows created with a token (see [`create_escrow`] and [`create_escrow_from_allowance`]) hold real funds:
+//! release pays the recipients in that token and refunds or cancellations return the
+//! principal to the sender. Withheld fees accrue per token until the admin collects
+//! them with [`collect_escrow_fees`].
+//!
+//! A sender can opt an escrow into a release callback with [`set_release_callback`]:
+//! when it is released to a contract recipient, the recipient's `on_escrow_received`
+//! is invoked with the id and payout (see [`crate::receiver`]).
+
+use soroban_sdk::{
+    address_payload::AddressPayload, token, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Vec,
+};
+
+use crate::{
+    commitment,
+    errors::QuickexError,
+    events, privacy, receiver, registry,
+    storage::{
+        self, add_to_expiry_bucket, get_admin, get_campaign, get_escrow_approvals,
+        get_escrow_counter, get_escrow_data, get_escrow_fee_bps, get_escrow_hashlock,
+        get_escrow_memo, get_expiry_bucket, get_fee_discount, get_max_escrow_amount,
+        get_open_escrow_count, get_privacy_level, get_total_open_value, increment_campaign_counter,
+        increment_escrow_counter, put_campaign, put_escrow_approvals, put_escrow_data,
+        put_escrow_hashlock, put_escrow_memo, remove_from_expiry_bucket, set_open_escrow_count,
+        set_total_open_value,
+    },
+    types::{Campaign, EscrowApprovals, EscrowData, EscrowStatus},
+};
+
+/// Cap on the total interest an escrow can accrue, in basis points of its principal.
+pub const MAX_INTEREST_BPS: u128 = 10_000;
+
+/// Label separating escrow term commitments from amount commitments.
+const ESCROW_TERMS_LABEL: &[u8] = b"escrow_terms";
+
+/// Upper bound for the escrow fee and for fee discounts, in basis points.
+pub const MAX_FEE_BPS: u32 = 10_000;
+
+/// Maximum length in bytes of an encrypted escrow memo.
+pub const MAX_MEMO_LEN: u32 = 256;
+
+/// Maximum number of escrows that may expire at the same ledger.
+pub const MAX_EXPIRY_BUCKET_LEN: u32 = 50;
+
+/// Maximum number of expiry ledgers a single [`due_refunds`] or [`sweep_expired`] call
+/// reads.
+pub const MAX_EXPIRY_SCAN_LEDGERS: u32 = 128;
+
+/// Maximum number of ids a single [`export_escrows`] call may span.
+pub const MAX_EXPORT_RANGE: u64 = 100;
+
+/// Maximum number of escrow ids a single [`stuck_escrow_count`] call reads.
+pub const MAX_STUCK_SCAN_LEN: u32 = 100;
+
+/// Number of slots in one page of a per-party escrow index.
+pub const ESCROW_INDEX_PAGE_LEN: u32 = 64;
+
+/// Maximum number of index pages a single [`repair_open_count`] call reads.
+pub const MAX_REPAIR_PAGES: u32 = 2;
+
+/// Cap on the quick-cancellation rebate, in basis points of the escrowed amount.
+pub const MAX_CANCEL_REBATE_BPS: u64 = 1_000;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Load an escrow that must still be `Pending`.
+fn load_pending(env: &Env, id: u64) -> Result<EscrowData, QuickexError> {
+    let escrow = get_escrow_data(env, id).ok_or(QuickexError::EscrowNotFound)?;
+    if escrow.status != EscrowStatus::Pending {
+        return Err(QuickexError::AlreadySpent);
+    }
+    Ok(escrow)
+}
+
+/// Returns `true` when an escrow has reached its expiry ledger.
+///
+/// An escrow with `expiry_ledger == 0` never expires.
+fn is_expired(env: &Env, escrow: &EscrowData) -> bool {
+    escrow.expiry_ledger > 0 && env.ledger().sequence() >= escrow.expiry_ledger
+}
+
+/// Move a pending escrow into a terminal `status`, dropping it from the expiry index
+/// and the open-value total.
+///
+/// For token-backed escrows this also unlocks the principal and, when the escrow is
+/// `Refunded` or `Cancelled`, returns it to the sender. A `Released` one adds the
+/// `fee_amount` set by [`price_payout`] to the token's uncollected fees.
+fn settle(env: &Env, escrow: &mut EscrowData, status: EscrowStatus) {
+    settle_refunding(env, escrow, status, escrow.amount);
+}
+
+/// Like [`settle`], but a `Refunded` or `Cancelled` token-backed escrow returns only
+/// `refund` to the sender.
+fn settle_refunding(env: &Env, escrow: &mut EscrowData, status: EscrowStatus, refund: u64) {
+    escrow.status = status;
+    put_escrow_data(env, escrow);
+    if escrow.expiry_ledger > 0 {
+        remove_from_expiry_bucket(env, escrow.expiry_ledger, escrow.id);
+    }
+    adjust_open_value(env, -(escrow.amount as i128));
+    adjust_open_count(env, &escrow.from, -1);
+
+    if let Some(token) = &escrow.token {
+        let locked = storage::get_locked_balance(env, token);
+        storage::set_locked_balance(env, token, locked - escrow.amount as i128);
+        if status == EscrowStatus::Released && escrow.fee_amount > 0 {
+            let fees = storage::get_collected_fees(env, token);
+            storage::set_collected_fees(env, token, fees + escrow.fee_amount as i128);
+        }
+        if matches!(status, EscrowStatus::Refunded | EscrowStatus::Cancelled) {
+            pay(env, escrow, &escrow.from, refund);
+        }
+    }
+}
+
+/// Transfer `amount` of a token-backed escrow's token from the contract to `to`.
+///
+/// No-op for escrows without a token.
+fn pay(env: &Env, escrow: &EscrowData, to: &Address, amount: u64) {
+    if let Some(token) = &escrow.token {
+        token::Client::new(env, token).transfer(
+            &env.current_contract_address(),
+            to,
+            &(amount as i128),
+        );
+    }
+}
+
+fn adjust_open_value(env: &Env, delta: i128) {
+    set_total_open_value(env, get_total_open_value(env) + delta);
+}
+
+fn adjust_open_count(env: &Env, sender: &Address, delta: i32) {
+    let count = get_open_escrow_count(env, sender).saturating_add_signed(delta);
+    set_open_escrow_count(env, sender, count);
+}
+
+/// Check the conditions every release path shares.
+///
+/// # Errors
+/// - [`LockNotElapsed`] – the escrow's minimum lock period has not passed.
+/// - [`InsufficientApprovals`] – an M-of-N escrow lacks `threshold` approvals.
+fn ensure_releasable(env: &Env, escrow: &EscrowData) -> Result<(), QuickexError> {
+    let unlock_ledger = escrow
+        .created_ledger
+        .saturating_add(escrow.min_lock_ledgers);
+    if env.ledger().sequence() < unlock_ledger {
+        return Err(QuickexError::LockNotElapsed);
+    }
+    if let Some(approvals) = get_escrow_approvals(env, escrow.id) {
+        if approvals.approved.len() < approvals.threshold {
+            return Err(QuickexError::InsufficientApprovals);
+        }
+    }
+    Ok(())
+}
+
+/// Mark a pending escrow as released to its recipient and publish the event.
+///
+/// The recipient receives the principal plus any interest accrued so far, minus the
+/// escrow fee for the sender's privacy level.
+fn release(env: &Env, mut escrow: EscrowData) {
+    price_payout(env, &mut escrow);
+    pay_out(env, escrow);
+}
+
+/// Settle an escrow priced by [`price_payout`] as released and pay `released_amount`
+/// to its recipient.
+fn pay_out(env: &Env, mut escrow: EscrowData) {
+    settle(env, &mut escrow, EscrowStatus::Released);
+    pay(env, &escrow, &escrow.to, escrow.released_amount);
+    if storage::has_release_callback(env, escrow.id) {
+        receiver::notify(env, &escrow.to, escrow.id, escrow.released_amount);
+    }
+    events::publish_escrow_released(env, escrow.id, escrow.to, escrow.released_amount);
+}
+
+/// Fill in the fee withheld from and the total paid out by a release at the current ledger.
+fn price_payout(env: &Env, escrow: &mut EscrowData) {
+    let level = get_privacy_level(env, &escrow.from).unwrap_or(0);
+    escrow.fee_amount = (escrow.amount as u128 * effective_fee_bps(env, level) as u128
+        / MAX_FEE_BPS as u128) as u64;
+    escrow.released_amount =
+        escrow.amount.saturating_add(interest_owed(env, escrow)) - escrow.fee_amount;
+}
+
+/// Escrow fee in basis points charged to a sender at privacy `level`.
+///
+/// The level's discount is taken off the base fee, flooring at zero.
+pub fn effective_fee_bps(env: &Env, level: u32) -> u32 {
+    get_escrow_fee_bps(env).saturating_sub(fee_discount_bps(env, level))
+}
+
+/// Fee discount in basis points for senders at privacy `level`.
+pub fn fee_discount_bps(env: &Env, level: u32) -> u32 {
+    get_fee_discount(env, level)
+}
+
+/// Set the base escrow fee. The caller must already be checked as admin.
+///
+/// # Errors
+/// - [`InvalidFeeBps`] – `fee_bps` exceeds [`MAX_FEE_BPS`].
+pub fn set_escrow_fee_bps(env: &Env, fee_bps: u32) -> Result<(), QuickexError> {
+    if fee_bps > MAX_FEE_BPS {
+        return Err(QuickexError::InvalidFeeBps);
+    }
+    storage::set_escrow_fee_bps(env, fee_bps);
+    Ok(())
+}
+
+/// Transfer the escrow fees withheld in `token` to `to` and return the amount sent.
+/// The caller must already be checked as admin.
+///
+/// Returns `0` without a transfer when nothing is owed.
+pub fn collect_escrow_fees(env: &Env, token: Address, to: Address) -> i128 {
+    let amount = storage::get_collected_fees(env, &token);
+    if amount == 0 {
+        return 0;
+    }
+    storage::set_collected_fees(env, &token, 0);
+    token::Client::new(env, &token).transfer(&env.current_contract_address(), &to, &amount);
+    events::publish_escrow_fees_collected(env, token, to, amount);
+    amount
+}
+
+/// Uncollected escrow fees withheld in `token`.
+pub fn collected_fees(env: &Env, token: &Address) -> i128 {
+    storage::get_collected_fees(env, token)
+}
+
+/// Set the fee discount for senders at privacy `level`. The caller must already be
+/// checked as admin.
+///
+/// # Errors
+/// - [`InvalidFeeBps`] – `discount_bps` exceeds [`MAX_FEE_BPS`].
+pub fn set_fee_discount_bps(env: &Env, level: u32, discount_bps: u32) -> Result<(), QuickexError> {
+    if discount_bps > MAX_FEE_BPS {
+        return Err(QuickexError::InvalidFeeBps);
+    }
+    storage::set_fee_discount(env, level, discount_bps);
+    Ok(())
+}
+
+/// Simple interest owed on `escrow` at the current ledger, capped at
+/// [`MAX_INTEREST_BPS`] of the principal.
+fn interest_owed(env: &Env, escrow: &EscrowData) -> u64 {
+    let amount = escrow.amount as u128;
+    let cap = amount * MAX_INTEREST_BPS / 10_000;
+    let elapsed = env
+        .ledger()
+        .sequence()
+        .saturating_sub(escrow.created_ledger) as u128;
+
+    let interest = amount
+        .checked_mul(escrow.interest_bps_per_ledger as u128)
+        .and_then(|scaled| scaled.checked_mul(elapsed))
+        .map_or(cap, |scaled| (scaled / 10_000).min(cap));
+    interest as u64
+}
+
+// ---------------------------------------------------------------------------
+// create_escrow
+// ---------------------------------------------------------------------------
+
+/// Create a non-expiring token-backed escrow from `from` to `to` and return its id.
+///
+/// Moves `amount` of `token` from `from` into the contract with `transfer`, under the
+/// sender's own authorization; no prior allowance is needed. Release pays the
+/// recipient in `token` and refunds or cancellations return it to `from`.
+///
+/// Ids are allocated from the global escrow counter, starting at 1.
+///
+/// # Errors
+/// - [`InvalidAmount`] – `amount` is zero.
+/// - [`SelfEscrow`] – `from` and `to` are the same address.
+/// - [`AmountTooLarge`] – `amount` exceeds the configured ceiling.
+///
+/// These checks apply to every `create_*` variant. Traps if the sender's balance does
+/// not cover `amount`.
+pub fn create_escrow(
+    env: &Env,
+    from: Address,
+    to: Address,
+    amount: u64,
+    token: Address,
+) -> Result<u64, QuickexError> {
+    from.require_auth();
+
+    let mut escrow = draft(env, from.clone(), to, amount);
+    escrow.token = Some(token.clone());
+    let id = create(env, escrow)?;
+
+    let contract = env.current_contract_address();
+    token::Client::new(env, &token).transfer(&from, &contract, &(amount as i128));
+    Ok(id)
+}
+
+/// Create a non-expiring `Pending` escrow record that holds no funds and return its id.
+///
+/// Release and refunds only change the record's status; no tokens move.
+///
+/// # Errors
+/// - [`InvalidAmount`] / [`SelfEscrow`] / [`AmountTooLarge`] – see [`create_escrow`].
+pub fn create_unfunded_escrow(
+    env: &Env,
+    from: Address,
+    to: Address,
+    amount: u64,
+) -> Result<u64, QuickexError> {
+    from.require_auth();
+    create(env, draft(env, from, to, amount))
+}
+
+/// Create a non-expiring token-backed escrow funded from a prior allowance.
+///
+/// Pulls `amount` of `token` from `from` into the contract with `transfer_from`, so
+/// `from` must first `approve` the contract as spender. The sender must authorize.
+///
+/// # Errors
+/// - [`InvalidAmount`] / [`SelfEscrow`] / [`AmountTooLarge`] – see [`create_escrow`].
+///
+/// Traps if the allowance or the sender's balance does not cover `amount`.
+pub fn create_escrow_from_allowance(
+    env: &Env,
+    from: Address,
+    to: Address,
+    amount: u64,
+    token: Address,
+) -> Result<u64, QuickexError> {
+    from.require_auth();
+
+    let mut escrow = draft(env, from.clone(), to, amount);
+    escrow.token = Some(token.clone());
+    let id = create(env, escrow)?;
+
+    let contract = env.current_contract_address();
+    token::Client::new(env, &token).transfer_from(&contract, &from, &contract, &(amount as i128));
+    Ok(id)
+}
+
+/// Create a non-expiring token-backed escrow, funded by a direct transfer, that pays
+/// `arbiter_fee` to its arbiter if a dispute over it is settled with [`resolve_dispute`].
+///
+/// The fee comes out of the escrowed amount; a normal release pays it to the recipient.
+///
+/// # Errors
+/// - [`InvalidAmount`] – `arbiter_fee` exceeds `amount`.
+/// - [`InvalidAmount`] / [`SelfEscrow`] / [`AmountTooLarge`] – see [`create_escrow`].
+pub fn create_escrow_with_arbiter_fee(
+    env: &Env,
+    from: Address,
+    to: Address,
+    amount: u64,
+    token: Address,
+    arbiter_fee: u64,
+) -> Result<u64, QuickexError> {
+    if arbiter_fee > amount {
+        return Err(QuickexError::InvalidAmount);
+    }
+    let id = create_escrow(env, from, to, amount, token)?;
+    storage::put_escrow_arbiter_fee(env, id, arbiter_fee);
+    Ok(id)
+}
+
+/// Fee the escrow pays its arbiter when [`resolve_dispute`] settles it. `0` if none.
+pub fn arbiter_fee(env: &Env, id: u64) -> u64 {
+    storage::get_escrow_arbiter_fee(env, id)
+}
+
+/// Create a non-expiring `Pending` escrow and return its id with a creation receipt.
+///
+/// See [`escrow_receipt`] for the receipt layout.
+pub fn create_escrow_with_receipt(
+    env: &Env,
+    from: Address,
+    to: Address,
+    amount: u64,
+) -> Result<(u64, BytesN<32>), QuickexError> {
+    let id = create_unfunded_escrow(env, from.clone(), to.clone(), amount)?;
+    let receipt = escrow_receipt(env, id, &from, &to, amount, env.ledger().sequence());
+    Ok((id, receipt))
+}
+
+/// Creation receipt of an escrow.
+///
+/// Receipt = SHA256(BE64(id) || XDR(from) || XDR(to) || BE64(amount) || BE32(created_ledger))
+pub fn escrow_receipt(
+    env: &Env,
+    id: u64,
+    from: &Address,
+    to: &Address,
+    amount: u64,
+    created_ledger: u32,
+) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &id.to_be_bytes());
+    preimage.append(&from.clone().to_xdr(env));
+    preimage.append(&to.clone().to_xdr(env));
+    preimage.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+    preimage.append(&Bytes::from_array(env, &created_ledger.to_be_bytes()));
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Returns `true` when `receipt` matches the creation receipt re-derived from the
+/// stored escrow `id`.
+pub fn verify_escrow_receipt(env: &Env, id: u64, receipt: BytesN<32>) -> bool {
+    match get_escrow_data(env, id) {
+        Some(escrow) => {
+            escrow_receipt(
+                env,
+                escrow.id,
+                &escrow.from,
+                &escrow.to,
+                escrow.amount,
+                escrow.created_ledger,
+            ) == receipt
+        }
+        None => false,
+    }
+}
+
+/// Create a non-expiring `Pending` escrow to the address `registry` resolves `name` to.
+///
+/// The name is resolved once, at creation; the escrow stores the resolved address.
+///
+/// # Errors
+/// - [`NameNotResolved`] – the registry has no address for `name` or the call failed.
+/// - [`SelfEscrow`] – `name` resolves to `from`.
+pub fn create_escrow_to_name(
+    env: &Env,
+    from: Address,
+    name: Symbol,
+    amount: u64,
+    registry: Address,
+) -> Result<u64, QuickexError> {
+    let to = registry::resolve(env, &registry, &name)?;
+    create_unfunded_escrow(env, from, to, amount)
+}
+
+/// Create a `Pending` escrow that becomes refundable at `expiry_ledger`.
+///
+/// # Errors
+/// - [`InvalidExpiry`] – `expiry_ledger` is not after the current ledger sequence.
+/// - [`ExpiryLedgerFull`] – [`MAX_EXPIRY_BUCKET_LEN`] pending escrows already expire at
+///   `expiry_ledger`.
+pub fn create_escrow_with_expiry(
+    env: &Env,
+    from: Address,
+    to: Address,
+    amount: u64,
+    expiry_ledger: u32,
+) -> Result<u64, QuickexError> {
+    if expiry_ledger <= env.ledger().sequence() {
+        return Err(QuickexError::InvalidExpiry);
+    }
+
+    from.require_auth();
+    let mut escrow = draft(env, from, to, amount);
+    escrow.expiry_ledger = expiry_ledger;
+    create(env, escrow)
+}
+
+/// Create a non-expiring `Pending` escrow that accrues simple interest until release.
+///
+/// At release the recipient is paid
+/// `amount + amount * interest_bps_per_ledger * elapsed_ledgers / 10_000`,
+/// with the interest capped at [`MAX_INTEREST_BPS`] of `amount`.
+pub fn create_escrow_with_interest(
+    env: &Env,
+    from: Address,
+    to: Address,
+    amount: u64,
+    interest_bps_per_ledger: u32,
+) -> Result<u64, QuickexError> {
+    from.require_auth();
+    let mut escrow = draft(env, from, to, amount);
+    escrow.interest_bps_per_ledger = interest_bps_per_ledger;
+    create(env, escrow)
+}
+
+/// Create a non-expiring `Pending` escrow that cannot be released before
+/// `created_ledger + min_lock_ledgers`.
+pub fn create_escrow_with_min_lock(
+    env: &Env,
+    from: Address,
+    to: Address,
+    amount: u64,
+    min_lock_ledgers: u32,
+) -> Result<u64, QuickexError> {
+    from.require_auth();
+    let mut escrow = draft(env, from, to, amount);
+    escrow.min_lock_ledgers = min_lock_ledgers;
+    create(env, escrow)
+}
+
+/// Create a non-expiring `Pending` escrow carrying a memo encrypted to the recipient.
+///
+/// The contract stores `encrypted_memo` as-is and never decrypts it; only the
+/// recipient can read it back with [`get_encrypted_memo`].
+///
+/// # Errors
+/// - [`MemoTooLong`] – `encrypted_memo` is longer than [`MAX_MEMO_LEN`] bytes.
+pub fn create_escrow_encrypted_memo(
+    env: &Env,
+    from: Address,
+    to: Address,
+    amount: u64,
+    encrypted_memo: Bytes,
+) -> Result<u64, QuickexError> {
+    if encrypted_memo.len() > MAX_MEMO_LEN {
+        return Err(QuickexError::MemoTooLong);
+    }
+
+    from.require_auth();
+    let id = create(env, draft(env, from, to, amount))?;
+    put_escrow_memo(env, id, &encrypted_memo);
+    Ok(id)
+}
+
+/// Return the encrypted memo of escrow `id`, or `None` if it has none.
+/// The escrow's recipient must authorize.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+pub fn get_encrypted_memo(env: &Env, id: u64) -> Result<Option<Bytes>, QuickexError> {
+    let escrow = get_escrow_data(env, id).ok_or(QuickexError::EscrowNotFound)?;
+    escrow.to.require_auth();
+    Ok(get_escrow_memo(env, id))
+}
+
+/// Create a non-expiring `Pending` escrow that needs `threshold` of `approvers` to
+/// approve before it can be released.
+///
+/// Duplicate addresses in `approvers` are collapsed.
+///
+/// # Errors
+/// - [`InvalidThreshold`] – `threshold` is zero or exceeds the number of distinct approvers.
+pub fn create_mofn_escrow(
+    env: &Env,
+    from: Address,
+    to: Address,
+    amount: u64,
+    approvers: Vec<Address>,
+    threshold: u32,
+) -> Result<u64, QuickexError> {
+    let mut unique = Vec::new(env);
+    for approver in approvers.iter() {
+        if !unique.contains(&approver) {
+            unique.push_back(approver);
+        }
+    }
+    if threshold == 0 || threshold > unique.len() {
+        return Err(QuickexError::InvalidThreshold);
+    }
+
+    from.require_auth();
+    let id = create(env, draft(env, from, to, amount))?;
+    put_escrow_approvals(
+        env,
+        id,
+        &EscrowApprovals {
+            approvers: unique,
+            threshold,
+            approved: Vec::new(env),
+        },
+    );
+    Ok(id)
+}
+
+/// Commitment to the full terms of an escrow, agreed off-chain before it is posted.
+///
+/// Commitment = SHA256(domain_tag || "escrow_terms" || XDR(from) || XDR(to) ||
+/// BE64(amount) || BE32(expiry_ledger) || salt). An `expiry_ledger` of 0 means the
+/// escrow never expires.
+///
+/// # Errors
+/// - [`InvalidSalt`] – salt violates the salt length policy.
+pub fn create_escrow_term_commitment(
+    env: &Env,
+    from: Address,
+    to: Address,
+    amount: u64,
+    expiry_ledger: u32,
+    salt: Bytes,
+) -> Result<BytesN<32>, QuickexError> {
+    if !commitment::is_valid_salt(env, &salt) {
+        return Err(QuickexError::InvalidSalt);
+    }
+
+    let mut preimage = commitment::domain_tag(env);
+    preimage.append(&Bytes::from_slice(env, ESCROW_TERMS_LABEL));
+    preimage.append(&from.to_xdr(env));
+    preimage.append(&to.to_xdr(env));
+    preimage.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+    preimage.append(&Bytes::from_array(env, &expiry_ledger.to_be_bytes()));
+    preimage.append(&salt);
+    Ok(env.crypto().sha256(&preimage).into())
+}
+
+/// Create the escrow described by pre-agreed terms once they match `term_commitment`.
+///
+/// # Errors
+/// - [`InvalidSalt`] – salt violates the salt length policy.
+/// - [`CommitmentMismatch`] – the terms do not open `term_commitment`.
+/// - [`InvalidExpiry`] – `expiry_ledger` is non-zero and not in the future.
+pub fn open_escrow_terms(
+    env: &Env,
+    term_commitment: BytesN<32>,
+    from: Address,
+    to: Address,
+    amount: u64,
+    expiry_ledger: u32,
+    salt: Bytes,
+) -> Result<u64, QuickexError> {
+    let expected =
+        create_escrow_term_commitment(env, from.clone(), to.clone(), amount, expiry_ledger, salt)?;
+    if expected != term_commitment {
+        return Err(QuickexError::CommitmentMismatch);
+    }
+
+    if expiry_ledger == 0 {
+        create_unfunded_escrow(env, from, to, amount)
+    } else {
+        create_escrow_with_expiry(env, from, to, amount, expiry_ledger)
+    }
+}
+
+/// Build an unsaved non-expiring `Pending` escrow with every optional term unset.
+fn draft(env: &Env, from: Address, to: Address, amount: u64) -> EscrowData {
+    EscrowData {
+        id: 0,
+        from,
+        to,
+        amount,
+        status: EscrowStatus::Pending,
+        created_ledger: env.ledger().sequence(),
+        expiry_ledger: 0,
+        campaign_id: 0,
+        interest_bps_per_ledger: 0,
+        released_amount: 0,
+        min_lock_ledgers: 0,
+        fee_amount: 0,
+        token: None,
+    }
+}
+
+/// Allocate an id for a drafted escrow, store and index it, and publish its creation.
+fn create(env: &Env, mut escrow: EscrowData) -> Result<u64, QuickexError> {
+    if escrow.amount == 0 {
+        return Err(QuickexError::InvalidAmount);
+    }
+    if escrow.from == escrow.to {
+        return Err(QuickexError::SelfEscrow);
+    }
+    if escrow.amount > get_max_escrow_amount(env) {
+        return Err(QuickexError::AmountTooLarge);
+    }
+    if escrow.expiry_ledger > 0
+        && get_expiry_bucket(env, escrow.expiry_ledger).len() >= MAX_EXPIRY_BUCKET_LEN
+    {
+        return Err(QuickexError::ExpiryLedgerFull);
+    }
+
+    let id = increment_escrow_counter(env);
+    escrow.id = id;
+    put_escrow_data(env, &escrow);
+    if escrow.expiry_ledger > 0 {
+        add_to_expiry_bucket(env, escrow.expiry_ledger, id);
+        let cursor = storage::get_expiry_sweep_cursor(env).unwrap_or(escrow.expiry_ledger);
+        storage::set_expiry_sweep_cursor(env, cursor.min(escrow.expiry_ledger));
+    }
+    adjust_open_value(env, escrow.amount as i128);
+    adjust_open_count(env, &escrow.from, 1);
+    if let Some(token) = &escrow.token {
+        let locked = storage::get_locked_balance(env, token);
+        storage::set_locked_balance(env, token, locked + escrow.amount as i128);
+    }
+    let sender_slot = push_index(env, Side::Sender, &escrow.from, id);
+    let recipient_slot = push_index(env, Side::Recipient, &escrow.to, id);
+    storage::put_escrow_index_slots(env, id, (sender_slot, recipient_slot));
+
+    if privacy::is_high_privacy(env, &escrow.from) {
+        events::publish_escrow_created_private(env, id);
+    } else {
+        events::publish_escrow_created(env, id, escrow.from, escrow.to, escrow.amount);
+    }
+
+    Ok(id)
+}
+
+/// Sum of the amounts of all `Pending` escrows.
+///
+/// Maintained incrementally, so reading it does not scan escrows. Accrued interest
+/// is not included.
+pub fn total_escrowed(env: &Env) -> i128 {
+    get_total_open_value(env)
+}
+
+/// Interest accrued so far by a pending escrow.
+///
+/// Returns `0` for unknown or settled escrows.
+pub fn accrued_interest(env: &Env, id: u64) -> u64 {
+    match get_escrow_data(env, id) {
+        Some(escrow) if escrow.status == EscrowStatus::Pending => interest_owed(env, &escrow),
+        _ => 0,
+    }
+}
+
+/// Number of `Pending` escrows created more than `older_than_ledgers` ledgers ago among
+/// the ids from `start_id` on, and the id to resume from.
+///
+/// Reads at most `limit` ids, capped at [`MAX_STUCK_SCAN_LEN`]. Pass the returned id as
+/// `start_id` to continue; it is past the last escrow once every id has been read.
+pub fn stuck_escrow_count(
+    env: &Env,
+    older_than_ledgers: u32,
+    start_id: u64,
+    limit: u32,
+) -> (u32, u64) {
+    let now = env.ledger().sequence();
+    let end = get_escrow_counter(env)
+        .saturating_add(1)
+        .min(start_id.saturating_add(limit.min(MAX_STUCK_SCAN_LEN) as u64));
+    let mut count = 0;
+    for id in start_id..end {
+        let Some(escrow) = get_escrow_data(env, id) else {
+            continue;
+        };
+        if escrow.status == EscrowStatus::Pending
+            && now.saturating_sub(escrow.created_ledger) > older_than_ledgers
+        {
+            count += 1;
+        }
+    }
+    (count, end.max(start_id))
+}
+
+/// Ids of the escrows `sender` sends on index page `page`, in creation order.
+pub fn escrows_by_sender(env: &Env, sender: &Address, page: u32) -> Vec<u64> {
+    index_page(env, Side::Sender, sender, page)
+}
+
+/// Number of pages in `sender`'s escrow index.
+pub fn sender_escrow_pages(env: &Env, sender: &Address) -> u32 {
+    index_pages(env, Side::Sender, sender)
+}
+
+/// Ids of the escrows `sender` sends on index page `page` that are currently in
+/// `status`, in creation order.
+///
+/// Status is kept on the escrow record rather than in an index of its own, so this
+/// walks one page of the per-sender index and keeps the ids whose record matches.
+/// A page can therefore come back empty while later pages still hold matches; callers
+/// must walk every page up to [`sender_escrow_pages`].
+pub fn escrows_by_sender_and_status(
+    env: &Env,
+    sender: &Address,
+    status: EscrowStatus,
+    page: u32,
+) -> Vec<u64> {
+    let mut ids = Vec::new(env);
+    for id in escrows_by_sender(env, sender, page).iter() {
+        if get_escrow_data(env, id).is_some_and(|escrow| escrow.status == status) {
+            ids.push_back(id);
+        }
+    }
+    ids
+}
+
+/// Number of `sender`'s escrows that are still `Pending`, `Disputed` or `Expired`.
+///
+/// Read from a cached counter; see [`repair_open_count`] if it drifts.
+pub fn open_escrow_count(env: &Env, sender: &Address) -> u32 {
+    get_open_escrow_count(env, sender)
+}
+
+/// Recount `sender`'s non-terminal escrows from the per-sender index, up to
+/// [`MAX_REPAIR_PAGES`] index pages per call, and return the count so far and the
+/// number of pages left.
+///
+/// Progress is stored between calls. The call that reads the last page overwrites
+/// the cached count with the total, clears the progress and reports `0` pages left.
+/// The caller must already be verified as admin.
+pub fn repair_open_count(env: &Env, sender: &Address) -> (u32, u32) {
+    let (start, mut count) = storage::get_open_count_repair(env, sender);
+    let pages = sender_escrow_pages(env, sender);
+    let end = pages.min(start.saturating_add(MAX_REPAIR_PAGES));
+    for page in start..end {
+        for id in escrows_by_sender(env, sender, page).iter() {
+            if let Some(escrow) = get_escrow_data(env, id) {
+                if matches!(
+                    escrow.status,
+                    EscrowStatus::Pending | EscrowStatus::Disputed | EscrowStatus::Expired
+                ) {
+                    count += 1;
+                }
+            }
+        }
+    }
+    if end < pages {
+        storage::set_open_count_repair(env, sender, (end, count));
+    } else {
+        storage::remove_open_count_repair(env, sender);
+        set_open_escrow_count(env, sender, count);
+    }
+    (count, pages - end)
+}
+
+/// Ids of the escrows `recipient` receives on index page `page`, in creation order.
+pub fn escrows_by_recipient(env: &Env, recipient: &Address, page: u32) -> Vec<u64> {
+    index_page(env, Side::Recipient, recipient, page)
+}
+
+/// Number of pages in `recipient`'s escrow index.
+pub fn recipient_escrow_pages(env: &Env, recipient: &Address) -> u32 {
+    index_pages(env, Side::Recipient, recipient)
+}
+
+/// Replace party `old` of a pending escrow with `new`, moving the escrow between
+/// the per-party indexes.
+///
+/// `caller` must authorize and be either `old` or the admin. The admin override is
+/// limited to tokenless escrows: re-pointing a party of a token-backed escrow moves
+/// where its funds go, so only that party may do it.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`AlreadySpent`] – escrow is not `Pending`.
+/// - [`Unauthorized`] – `caller` is neither `old` nor the admin, or is the admin
+///   and the escrow is token-backed.
+/// - [`InvalidOwner`] – `old` is not a party to the escrow.
+/// - [`SelfEscrow`] – `new` is the escrow's other party.
+pub fn migrate_escrow_party(
+    env: &Env,
+    id: u64,
+    caller: Address,
+    old: Address,
+    new: Address,
+) -> Result<(), QuickexError> {
+    caller.require_auth();
+
+    let mut escrow = load_pending(env, id)?;
+    if caller != old && (escrow.token.is_some() || Some(caller) != get_admin(env)) {
+        return Err(QuickexError::Unauthorized);
+    }
+
+    if escrow.from == old {
+        if new == escrow.to {
+            return Err(QuickexError::SelfEscrow);
+        }
+        escrow.from = new.clone();
+        move_id(env, id, Side::Sender, &old, &new);
+        adjust_open_count(env, &old, -1);
+        adjust_open_count(env, &new, 1);
+    } else if escrow.to == old {
+        if new == escrow.from {
+            return Err(QuickexError::SelfEscrow);
+        }
+        escrow.to = new.clone();
+        move_id(env, id, Side::Recipient, &old, &new);
+    } else {
+        return Err(QuickexError::InvalidOwner);
+    }
+
+    put_escrow_data(env, &escrow);
+    Ok(())
+}
+
+/// Which per-party escrow index to address.
+#[derive(Clone, Copy)]
+enum Side {
+    Sender,
+    Recipient,
+}
+
+fn index_count(env: &Env, side: Side, party: &Address) -> u32 {
+    match side {
+        Side::Sender => storage::get_sender_escrow_count(env, party),
+        Side::Recipient => storage::get_recipient_escrow_count(env, party),
+    }
+}
+
+fn read_index_page(env: &Env, side: Side, party: &Address, page: u32) -> Vec<u64> {
+    match side {
+        Side::Sender => storage::get_sender_escrow_page(env, party, page),
+        Side::Recipient => storage::get_recipient_escrow_page(env, party, page),
+    }
+}
+
+fn write_index_page(env: &Env, side: Side, party: &Address, page: u32, ids: &Vec<u64>) {
+    match side {
+        Side::Sender => storage::set_sender_escrow_page(env, party, page, ids),
+        Side::Recipient => storage::set_recipient_escrow_page(env, party, page, ids),
+    }
+}
+
+/// Number of pages in a per-party index.
+fn index_pages(env: &Env, side: Side, party: &Address) -> u32 {
+    index_count(env, side, party).div_ceil(ESCROW_INDEX_PAGE_LEN)
+}
+
+/// Live ids on one page of a per-party index, skipping migrated-away slots.
+fn index_page(env: &Env, side: Side, party: &Address, page: u32) -> Vec<u64> {
+    let mut ids = Vec::new(env);
+    for id in read_index_page(env, side, party, page).iter() {
+        if id != 0 {
+            ids.push_back(id);
+        }
+    }
+    ids
+}
+
+/// Append `id` to a per-party index and return the slot it landed in.
+fn push_index(env: &Env, side: Side, party: &Address, id: u64) -> u32 {
+    let slot = index_count(env, side, party);
+    let page = slot / ESCROW_INDEX_PAGE_LEN;
+    let mut ids = read_index_page(env, side, party, page);
+    ids.push_back(id);
+    write_index_page(env, side, party, page, &ids);
+    match side {
+        Side::Sender => storage::set_sender_escrow_count(env, party, slot + 1),
+        Side::Recipient => storage::set_recipient_escrow_count(env, party, slot + 1),
+    }
+    slot
+}
+
+/// Move `id` from `old`'s index to the end of `new`'s, leaving a `0` in its old slot
+/// so the other slots keep their positions.
+fn move_id(env: &Env, id: u64, side: Side, old: &Address, new: &Address) {
+    let (sender_slot, recipient_slot) =
+        storage::get_escrow_index_slots(env, id).unwrap_or((u32::MAX, u32::MAX));
+    let old_slot = match side {
+        Side::Sender => sender_slot,
+        Side::Recipient => recipient_slot,
+    };
+    let page = old_slot / ESCROW_INDEX_PAGE_LEN;
+    let mut ids = read_index_page(env, side, old, page);
+    let offset = old_slot % ESCROW_INDEX_PAGE_LEN;
+    if ids.get(offset) == Some(id) {
+        ids.set(offset, 0);
+        write_index_page(env, side, old, page, &ids);
+    }
+
+    let new_slot = push_index(env, side, new, id);
+    let slots = match side {
+        Side::Sender => (new_slot, recipient_slot),
+        Side::Recipient => (sender_slot, new_slot),
+    };
+    storage::put_escrow_index_slots(env, id, slots);
+}
+
+/// Returns `true` when `addr` is the sender of escrow `id`. `false` for unknown ids.
+pub fn is_escrow_sender(env: &Env, id: u64, addr: &Address) -> bool {
+    get_escrow_data(env, id).is_some_and(|escrow| escrow.from == *addr)
+}
+
+/// Returns `true` when `addr` is the recipient of escrow `id`. `false` for unknown ids.
+pub fn is_escrow_recipient(env: &Env, id: u64, addr: &Address) -> bool {
+    get_escrow_data(env, id).is_some_and(|escrow| escrow.to == *addr)
+}
+
+/// Status of each escrow in `ids`, in input order. `None` for unknown ids.
+pub fn get_escrows_status(env: &Env, ids: Vec<u64>) -> Vec<Option<EscrowStatus>> {
+    let mut statuses = Vec::new(env);
+    for id in ids.iter() {
+        statuses.push_back(get_escrow_data(env, id).map(|escrow| escrow.status));
+    }
+    statuses
+}
+
+/// Returns `true` when escrows `id_a` and `id_b` have the same sender. `false` if
+/// either id is unknown.
+pub fn escrows_same_sender(env: &Env, id_a: u64, id_b: u64) -> bool {
+    match (get_escrow_data(env, id_a), get_escrow_data(env, id_b)) {
+        (Some(a), Some(b)) => a.from == b.from,
+        _ => false,
+    }
+}
+
+/// Returns `true` when escrow `id` was created at a ledger before `ledger`.
+/// `false` for unknown ids.
+pub fn escrow_created_before(env: &Env, id: u64, ledger: u32) -> bool {
+    get_escrow_data(env, id).is_some_and(|escrow| escrow.created_ledger < ledger)
+}
+
+// ---------------------------------------------------------------------------
+// release_escrow / approvals
+// ---------------------------------------------------------------------------
+
+/// Release a pending escrow to its recipient.
+///
+/// `caller` must be the escrow sender and must authorize.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`AlreadySpent`] – escrow is not `Pending`.
+/// - [`Unauthorized`] – caller is not the sender.
+/// - [`InsufficientApprovals`] – an M-of-N escrow lacks `threshold` approvals.
+pub fn release_escrow(env: &Env, id: u64, caller: Address) -> Result<(), QuickexError> {
+    caller.require_auth();
+
+    let escrow = load_pending(env, id)?;
+    if caller != escrow.from {
+        return Err(QuickexError::Unauthorized);
+    }
+    ensure_releasable(env, &escrow)?;
+
+    release(env, escrow);
+    Ok(())
+}
+
+/// Release a pending escrow split across several recipients.
+///
+/// `amounts[i]` goes to `recipients[i]`. The amounts must add up to exactly what a
+/// single release would pay out now: the principal plus accrued interest, minus the fee.
+/// The escrow sender must authorize. One `EscrowReleased` event is published per recipient,
+/// and with a [`set_release_callback`] opt-in each contract recipient is notified of its share.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`AlreadySpent`] – escrow is not `Pending`.
+/// - [`LengthMismatch`] – `recipients` and `amounts` differ in length.
+/// - [`InvalidAmount`] – the amounts do not add up to the payout.
+/// - [`LockNotElapsed`] / [`InsufficientApprovals`] – see [`release_escrow`].
+pub fn release_to_many(
+    env: &Env,
+    id: u64,
+    recipients: Vec<Address>,
+    amounts: Vec<u64>,
+) -> Result<(), QuickexError> {
+    let mut escrow = load_pending(env, id)?;
+    escrow.from.require_auth();
+
+    if recipients.len() != amounts.len() {
+        return Err(QuickexError::LengthMismatch);
+    }
+    ensure_releasable(env, &escrow)?;
+
+    price_payout(env, &mut escrow);
+    let total = amounts
+        .iter()
+        .try_fold(0u64, |sum, amount| sum.checked_add(amount));
+    if total != Some(escrow.released_amount) {
+        return Err(QuickexError::InvalidAmount);
+    }
+
+    settle(env, &mut escrow, EscrowStatus::Released);
+    let callback = storage::has_release_callback(env, id);
+    for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+        pay(env, &escrow, &recipient, amount);
+        if callback {
+            receiver::notify(env, &recipient, id, amount);
+        }
+        events::publish_escrow_released(env, id, recipient, amount);
+    }
+    Ok(())
+}
+
+/// Record `approver`'s approval of a pending M-of-N escrow and return the number of
+/// distinct approvals collected.
+///
+/// Approving twice is a no-op, so an approver never counts more than once.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`AlreadySpent`] – escrow is not `Pending`.
+/// - [`Unauthorized`] – `approver` is not in the escrow's approver set.
+pub fn approve_escrow(env: &Env, id: u64, approver: Address) -> Result<u32, QuickexError> {
+    approver.require_auth();
+
+    load_pending(env, id)?;
+    let mut approvals = get_escrow_approvals(env, id).ok_or(QuickexError::Unauthorized)?;
+    if !approvals.approvers.contains(&approver) {
+        return Err(QuickexError::Unauthorized);
+    }
+
+    if !approvals.approved.contains(&approver) {
+        approvals.approved.push_back(approver);
+        put_escrow_approvals(env, id, &approvals);
+    }
+    Ok(approvals.approved.len())
+}
+
+// ---------------------------------------------------------------------------
+// release_with_receipt
+// ---------------------------------------------------------------------------
+
+/// Message the sender signs to release escrow `id` against `receipt_hash`.
+///
+/// Message = SHA256(XDR(contract) || BE64(id) || receipt_hash), so a signature only
+/// ever releases one escrow of this contract.
+pub fn receipt_message(env: &Env, id: u64, receipt_hash: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = env.current_contract_address().to_xdr(env);
+    preimage.append(&Bytes::from_array(env, &id.to_be_bytes()));
+    preimage.append(&receipt_hash.clone().into());
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Release an escrow against a delivery receipt signed by the sender.
+///
+/// `signer` must be the escrow's `from` account; its ed25519 account key must have
+/// signed [`receipt_message`] for `id` and `receipt_hash`. Anyone may submit the
+/// receipt, typically the recipient. Each receipt of a sender is accepted once.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`AlreadySpent`] – escrow is not `Pending`, or `receipt_hash` was already used.
+/// - [`Unauthorized`] – `signer` is not the escrow sender.
+/// - [`InvalidSignature`] – signature is not 64 bytes or `signer` is not an ed25519 account.
+/// - [`InsufficientApprovals`] – an M-of-N escrow lacks `threshold` approvals.
+///
+/// A well-formed but forged signature traps in the host and reverts the call.
+pub fn release_with_receipt(
+    env: &Env,
+    id: u64,
+    receipt_hash: BytesN<32>,
+    signature: Bytes,
+    signer: Address,
+) -> Result<(), QuickexError> {
+    let escrow = load_pending(env, id)?;
+
+    if signer != escrow.from {
+        return Err(QuickexError::Unauthorized);
+    }
+    if storage::is_receipt_used(env, &signer, &receipt_hash) {
+        return Err(QuickexError::AlreadySpent);
+    }
+
+    let public_key = match signer.to_payload() {
+        Some(AddressPayload::AccountIdPublicKeyEd25519(key)) => key,
+        _ => return Err(QuickexError::InvalidSignature),
+    };
+    let signature: BytesN<64> = signature
+        .try_into()
+        .map_err(|_| QuickexError::InvalidSignature)?;
+
+    let message = receipt_message(env, id, &receipt_hash);
+    env.crypto()
+        .ed25519_verify(&public_key, &message.into(), &signature);
+
+    ensure_releasable(env, &escrow)?;
+    storage::set_receipt_used(env, &signer, &receipt_hash);
+    release(env, escrow);
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Hashlock escrows
+// ---------------------------------------------------------------------------
+
+/// Create a `Pending` escrow released by revealing a preimage of `hashlock` before
+/// `timeout_ledger`, see [`claim_hashlock`].
+///
+/// The timeout is stored as the escrow's expiry ledger, so from then on the sender
+/// can be refunded through [`refund_hashlock`] or the regular expiry refunds.
+///
+/// # Errors
+/// - [`InvalidExpiry`] – `timeout_ledger` is not in the future.
+pub fn create_hashlock_escrow(
+    env: &Env,
+    from: Address,
+    to: Address,
+    amount: u64,
+    hashlock: BytesN<32>,
+    timeout_ledger: u32,
+) -> Result<u64, QuickexError> {
+    if timeout_ledger <= env.ledger().sequence() {
+        return Err(QuickexError::InvalidExpiry);
+    }
+
+    from.require_auth();
+    let mut escrow = draft(env, from, to, amount);
+    escrow.expiry_ledger = timeout_ledger;
+    let id = create(env, escrow)?;
+    put_escrow_hashlock(env, id, &hashlock);
+    Ok(id)
+}
+
+/// Release a hashlock escrow to its recipient once `sha256(preimage)` matches its
+/// hash lock.
+///
+/// Anyone may submit the preimage; the funds always go to the escrow's recipient.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`AlreadySpent`] – escrow is not `Pending`.
+/// - [`HashlockMismatch`] – the escrow has no hash lock or `preimage` does not open it.
+/// - [`EscrowExpired`] – the timeout ledger has been reached.
+pub fn claim_hashlock(env: &Env, id: u64, preimage: Bytes) -> Result<(), QuickexError> {
+    let escrow = load_pending(env, id)?;
+    let hashlock = get_escrow_hashlock(env, id).ok_or(QuickexError::HashlockMismatch)?;
+    if is_expired(env, &escrow) {
+        return Err(QuickexError::EscrowExpired);
+    }
+    let digest: BytesN<32> = env.crypto().sha256(&preimage).into();
+    if digest != hashlock {
+        return Err(QuickexError::HashlockMismatch);
+    }
+
+    release(env, escrow);
+    Ok(())
+}
+
+/// Refund an unclaimed hashlock escrow to its sender once its timeout is reached.
+///
+/// Callable by anyone, like [`claim_expired`].
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`HashlockMismatch`] – the escrow has no hash lock.
+/// - [`AlreadySpent`] – escrow was already claimed or refunded.
+/// - [`EscrowNotExpired`] – the timeout ledger has not been reached.
+pub fn refund_hashlock(env: &Env, id: u64) -> Result<(), QuickexError> {
+    get_escrow_data(env, id).ok_or(QuickexError::EscrowNotFound)?;
+    if get_escrow_hashlock(env, id).is_none() {
+        return Err(QuickexError::HashlockMismatch);
+    }
+    claim_expired(env, id)
+}
+
+// ---------------------------------------------------------------------------
+// cancel_escrow
+// ---------------------------------------------------------------------------
+
+/// Cancel a pending escrow.
+///
+/// `caller` must be the escrow sender or recipient and must authorize. Before expiry
+/// only the recipient may cancel, since that gives up its own claim; from the expiry
+/// ledger on either party may. An escrow without expiry never lets the sender cancel.
+///
+/// Cancelling a token-backed escrow within the grace window of [`set_cancel_rebate`]
+/// also pays the sender the flat rebate in the escrow's token, out of that token's
+/// [`rebate_pool`]. The rebate never exceeds [`MAX_CANCEL_REBATE_BPS`] of the escrowed
+/// amount, a short pool pays what it holds, and tokenless escrows get nothing.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`AlreadySpent`] – escrow is not `Pending`.
+/// - [`Unauthorized`] – caller is neither party.
+/// - [`EscrowNotExpired`] – the sender cancels before the expiry ledger.
+pub fn cancel_escrow(env: &Env, id: u64, caller: Address) -> Result<(), QuickexError> {
+    caller.require_auth();
+
+    let mut escrow = load_pending(env, id)?;
+    if caller != escrow.from && caller != escrow.to {
+        return Err(QuickexError::Unauthorized);
+    }
+    if caller != escrow.to && !is_expired(env, &escrow) {
+        return Err(QuickexError::EscrowNotExpired);
+    }
+
+    settle(env, &mut escrow, EscrowStatus::Cancelled);
+    let (rebate, grace_ledgers) = storage::get_cancel_rebate(env);
+    if let Some(token) = &escrow.token {
+        if env.ledger().sequence() < escrow.created_ledger.saturating_add(grace_ledgers) {
+            let pool = storage::get_rebate_pool(env, token);
+            let cap = escrow.amount as u128 * MAX_CANCEL_REBATE_BPS as u128 / 10_000;
+            let paid = (rebate as u128).min(cap).min(pool as u128) as i128;
+            if paid > 0 {
+                storage::set_rebate_pool(env, token, pool - paid);
+                pay(env, &escrow, &escrow.from, paid as u64);
+            }
+        }
+    }
+    events::publish_escrow_cancelled(env, id, caller);
+    Ok(())
+}
+
+/// Refund a pending escrow to its sender at the recipient's request.
+///
+/// `caller` must be the escrow recipient, giving up its claim, and must authorize.
+/// Unlike [`cancel_escrow`] no rebate is credited and the escrow ends `Refunded`.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`AlreadySpent`] – escrow is not `Pending`.
+/// - [`Unauthorized`] – caller is not the recipient.
+pub fn refund_escrow(env: &Env, id: u64, caller: Address) -> Result<(), QuickexError> {
+    caller.require_auth();
+
+    let mut escrow = load_pending(env, id)?;
+    if caller != escrow.to {
+        return Err(QuickexError::Unauthorized);
+    }
+
+    settle(env, &mut escrow, EscrowStatus::Refunded);
+    events::publish_escrow_refunded(env, id, escrow.from, escrow.amount);
+    Ok(())
+}
+
+/// Configure the flat rebate paid to the sender of an escrow cancelled fewer than
+/// `grace_ledgers` ledgers after creation. The caller must already be checked as admin.
+pub fn set_cancel_rebate(env: &Env, amount: u64, grace_ledgers: u32) {
+    storage::set_cancel_rebate(env, amount, grace_ledgers);
+}
+
+/// Move `amount` of `token` from `funder` into the rebate pool of `token`.
+///
+/// # Errors
+/// - [`InvalidAmount`] – `amount` is not positive.
+pub fn fund_rebate_pool(
+    env: &Env,
+    funder: Address,
+    token: Address,
+    amount: i128,
+) -> Result<(), QuickexError> {
+    funder.require_auth();
+    if amount <= 0 {
+        return Err(QuickexError::InvalidAmount);
+    }
+
+    let contract = env.current_contract_address();
+    token::Client::new(env, &token).transfer(&funder, &contract, &amount);
+    let pool = storage::get_rebate_pool(env, &token);
+    storage::set_rebate_pool(env, &token, pool + amount);
+    Ok(())
+}
+
+/// Funds left in the rebate pool of `token`.
+pub fn rebate_pool(env: &Env, token: &Address) -> i128 {
+    storage::get_rebate_pool(env, token)
+}
+
+// ---------------------------------------------------------------------------
+// Disputes
+// ---------------------------------------------------------------------------
+
+/// Ledgers a dispute may stay unresolved before either party can time it out,
+/// when the admin has not configured [`dispute_deadline_ledgers`] (about a week).
+pub const DEFAULT_DISPUTE_DEADLINE_LEDGERS: u32 = 120_960;
+
+/// Ledgers a dispute may stay unresolved before [`resolve_dispute_timeout`] applies.
+pub fn dispute_deadline_ledgers(env: &Env) -> u32 {
+    storage::get_dispute_deadline_ledgers(env).unwrap_or(DEFAULT_DISPUTE_DEADLINE_LEDGERS)
+}
+
+/// Freeze a pending escrow until the admin resolves it. Either party may dispute.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`AlreadySpent`] – escrow is not `Pending`.
+/// - [`Unauthorized`] – `caller` is neither the sender nor the recipient.
+pub fn dispute_escrow(env: &Env, id: u64, caller: Address) -> Result<(), QuickexError> {
+    caller.require_auth();
+
+    let mut escrow = load_pending(env, id)?;
+    if caller != escrow.from && caller != escrow.to {
+        return Err(QuickexError::Unauthorized);
+    }
+
+    escrow.status = EscrowStatus::Disputed;
+    put_escrow_data(env, &escrow);
+    storage::set_disputed_at(env, id, env.ledger().sequence());
+    events::publish_escrow_disputed(env, id, caller);
+    Ok(())
+}
+
+/// The arbiter of escrow `id`: the one its parties appointed, otherwise the admin.
+pub fn arbiter_of(env: &Env, id: u64) -> Option<Address> {
+    storage::get_escrow_arbiter(env, id).or_else(|| get_admin(env))
+}
+
+/// Appoint `new_arbiter` for a `Pending` or `Disputed` escrow. Both the sender and
+/// the recipient must authorize.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`AlreadySpent`] – escrow is in a terminal state.
+pub fn change_arbiter(env: &Env, id: u64, new_arbiter: Address) -> Result<(), QuickexError> {
+    let escrow = get_escrow_data(env, id).ok_or(QuickexError::EscrowNotFound)?;
+    if escrow.status != EscrowStatus::Pending && escrow.status != EscrowStatus::Disputed {
+        return Err(QuickexError::AlreadySpent);
+    }
+    escrow.from.require_auth();
+    escrow.to.require_auth();
+
+    storage::put_escrow_arbiter(env, id, &new_arbiter);
+    Ok(())
+}
+
+/// Choose whether releasing a pending escrow invokes `on_escrow_received` on its
+/// recipient, or on each recipient of [`release_to_many`]. The sender must authorize.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`AlreadySpent`] – escrow is not `Pending`.
+pub fn set_release_callback(env: &Env, id: u64, enabled: bool) -> Result<(), QuickexError> {
+    let escrow = load_pending(env, id)?;
+    escrow.from.require_auth();
+
+    storage::set_release_callback(env, id, enabled);
+    Ok(())
+}
+
+/// Settle a disputed escrow as its arbiter, see [`arbiter_of`].
+///
+/// Refunds the sender when `refund_to_sender` is set, otherwise releases to the
+/// recipient with any accrued interest. Either way the escrow's [`arbiter_fee`] is
+/// withheld from the payout and paid to the arbiter.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`EscrowNotDisputed`] – escrow is not `Disputed`.
+/// - [`Unauthorized`] – `caller` is not the escrow's arbiter.
+pub fn resolve_dispute(
+    env: &Env,
+    id: u64,
+    caller: Address,
+    refund_to_sender: bool,
+) -> Result<(), QuickexError> {
+    caller.require_auth();
+
+    let mut escrow = get_escrow_data(env, id).ok_or(QuickexError::EscrowNotFound)?;
+    if escrow.status != EscrowStatus::Disputed {
+        return Err(QuickexError::EscrowNotDisputed);
+    }
+    if arbiter_of(env, id) != Some(caller.clone()) {
+        return Err(QuickexError::Unauthorized);
+    }
+
+    let fee = storage::get_escrow_arbiter_fee(env, id);
+    if refund_to_sender {
+        let refund = escrow.amount - fee;
+        settle_refunding(env, &mut escrow, EscrowStatus::Refunded, refund);
+        pay(env, &escrow, &caller, fee);
+        events::publish_escrow_refunded(env, id, escrow.from, refund);
+    } else {
+        price_payout(env, &mut escrow);
+        let fee = fee.min(escrow.released_amount);
+        escrow.released_amount -= fee;
+        pay(env, &escrow, &caller, fee);
+        pay_out(env, escrow);
+    }
+    events::publish_escrow_force_resolved(env, id, caller, refund_to_sender);
+    Ok(())
+}
+
+/// Resolve a `Pending` or `Disputed` escrow regardless of its release conditions.
+///
+/// Refunds the sender when `refund_to_sender` is set, otherwise releases to the
+/// recipient with any accrued interest. The caller must already be verified as admin.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`AlreadySpent`] – escrow is in a terminal state.
+pub fn admin_force_resolve(
+    env: &Env,
+    id: u64,
+    admin: Address,
+    refund_to_sender: bool,
+) -> Result<(), QuickexError> {
+    let mut escrow = get_escrow_data(env, id).ok_or(QuickexError::EscrowNotFound)?;
+    if escrow.status != EscrowStatus::Pending && escrow.status != EscrowStatus::Disputed {
+        return Err(QuickexError::AlreadySpent);
+    }
+
+    if refund_to_sender {
+        settle(env, &mut escrow, EscrowStatus::Refunded);
+        events::publish_escrow_refunded(env, id, escrow.from, escrow.amount);
+    } else {
+        release(env, escrow);
+    }
+    events::publish_escrow_force_resolved(env, id, admin, refund_to_sender);
+    Ok(())
+}
+
+/// Refund the sender of a dispute the arbiter left unresolved.
+///
+/// Either party may call once [`dispute_deadline_ledgers`] have passed since the
+/// dispute was raised.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`EscrowNotDisputed`] – escrow is not `Disputed`.
+/// - [`Unauthorized`] – `caller` is neither the sender nor the recipient.
+/// - [`DisputeWindowOpen`] – the dispute deadline has not been reached.
+pub fn resolve_dispute_timeout(env: &Env, id: u64, caller: Address) -> Result<(), QuickexError> {
+    caller.require_auth();
+
+    let mut escrow = get_escrow_data(env, id).ok_or(QuickexError::EscrowNotFound)?;
+    if escrow.status != EscrowStatus::Disputed {
+        return Err(QuickexError::EscrowNotDisputed);
+    }
+    if caller != escrow.from && caller != escrow.to {
+        return Err(QuickexError::Unauthorized);
+    }
+    let disputed_at = storage::get_disputed_at(env, id).unwrap_or(0);
+    let deadline = disputed_at.saturating_add(dispute_deadline_ledgers(env));
+    if env.ledger().sequence() < deadline {
+        return Err(QuickexError::DisputeWindowOpen);
+    }
+
+    settle(env, &mut escrow, EscrowStatus::Refunded);
+    events::publish_escrow_refunded(env, id, escrow.from, escrow.amount);
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Expiry refunds
+// ---------------------------------------------------------------------------
+
+/// Refund an expired escrow to its sender.
+///
+/// Callable by anyone once the expiry ledger is reached, so keepers can process
+/// the ids returned by [`due_refunds`]. Publishes an `EscrowExpired` event with the
+/// refunded amount alongside `EscrowRefunded`.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`AlreadySpent`] – escrow is not `Pending`.
+/// - [`EscrowNotExpired`] – escrow has no expiry or has not reached it yet.
+pub fn claim_expired(env: &Env, id: u64) -> Result<(), QuickexError> {
+    let mut escrow = load_pending(env, id)?;
+
+    if !is_expired(env, &escrow) {
+        return Err(QuickexError::EscrowNotExpired);
+    }
+
+    settle(env, &mut escrow, EscrowStatus::Refunded);
+    events::publish_escrow_refunded(env, escrow.id, escrow.from, escrow.amount);
+    events::publish_escrow_expired(env, escrow.id, escrow.amount);
+    Ok(())
+}
+
+/// Mark an escrow that has reached its expiry ledger as `Expired`, leaving the funds
+/// for its sender to pull with [`claim_expired_funds`].
+///
+/// Callable by anyone. Publishes an `EscrowExpired` event.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`AlreadySpent`] – escrow is not `Pending`.
+/// - [`EscrowNotExpired`] – escrow has no expiry or has not reached it yet.
+pub fn mark_expired(env: &Env, id: u64) -> Result<(), QuickexError> {
+    let mut escrow = load_pending(env, id)?;
+
+    if !is_expired(env, &escrow) {
+        return Err(QuickexError::EscrowNotExpired);
+    }
+
+    escrow.status = EscrowStatus::Expired;
+    put_escrow_data(env, &escrow);
+    remove_from_expiry_bucket(env, escrow.expiry_ledger, escrow.id);
+    events::publish_escrow_expired(env, escrow.id, escrow.amount);
+    Ok(())
+}
+
+/// Refund an `Expired` escrow to its sender. The sender must authorize.
+///
+/// # Errors
+/// - [`EscrowNotFound`] – no escrow for `id`.
+/// - [`EscrowNotExpired`] – escrow is still `Pending`; call [`mark_expired`] first.
+/// - [`AlreadySpent`] – escrow is in any other state.
+pub fn claim_expired_funds(env: &Env, id: u64) -> Result<(), QuickexError> {
+    let mut escrow = get_escrow_data(env, id).ok_or(QuickexError::EscrowNotFound)?;
+    match escrow.status {
+        EscrowStatus::Expired => {}
+        EscrowStatus::Pending => return Err(QuickexError::EscrowNotExpired),
+        _ => return Err(QuickexError::AlreadySpent),
+    }
+    escrow.from.require_auth();
+
+    settle(env, &mut escrow, EscrowStatus::Refunded);
+    events::publish_escrow_refunded(env, escrow.id, escrow.from, escrow.amount);
+    Ok(())
+}
+
+/// Refund escrows that have reached their expiry ledger and return their ids.
+///
+/// Resumes from the stored sweep cursor and processes the ids
+/// `due_refunds(cursor, env.ledger().sequence(), limit)` returns, then moves the cursor
+/// past them. Each refund publishes an `EscrowRefunded` and an `EscrowExpired` event.
+pub fn sweep_expired(env: &Env, limit: u32) -> Vec<u64> {
+    let Some(cursor) = storage::get_expiry_sweep_cursor(env) else {
+        return Vec::new(env);
+    };
+    let (due, next_ledger) = due_refunds(env, cursor, env.ledger().sequence(), limit);
+    for id in due.iter() {
+        // Every due id is `Pending` and expired, so the claim cannot fail.
+        let _ = claim_expired(env, id);
+    }
+    storage::set_expiry_sweep_cursor(env, next_ledger);
+    due
+}
+
+/// Return the ids of `Pending` escrows expiring in `from_ledger..=up_to_ledger`, and the
+/// ledger to resume from.
+///
+/// Ids are ordered by expiry ledger, earliest first. Reads at most
+/// [`MAX_EXPIRY_SCAN_LEDGERS`] ledgers and stops after the first ledger that brings the
+/// result to `limit` ids; a ledger's ids are always returned together, so the result
+/// can exceed `limit` by less than [`MAX_EXPIRY_BUCKET_LEN`]. Pass the returned ledger as
+/// `from_ledger` to continue; it is `up_to_ledger + 1` once the range is exhausted.
+pub fn due_refunds(env: &Env, from_ledger: u32, up_to_ledger: u32, limit: u32) -> (Vec<u64>, u32) {
+    let mut due = Vec::new(env);
+    let end = up_to_ledger.min(from_ledger.saturating_add(MAX_EXPIRY_SCAN_LEDGERS - 1));
+    let mut ledger = from_ledger;
+    while ledger <= end && due.len() < limit {
+        for id in get_expiry_bucket(env, ledger).iter() {
+            if get_escrow_data(env, id).is_some_and(|escrow| escrow.status == EscrowStatus::Pending)
+            {
+                due.push_back(id);
+            }
+        }
+        if ledger == u32::MAX {
+            break;
+        }
+        ledger += 1;
+    }
+    (due, ledger)
+}
+
+// ---------------------------------------------------------------------------
+// Campaigns
+// ---------------------------------------------------------------------------
+
+/// Create an empty campaign owned by `owner` and return its id.
+pub fn create_campaign(env: &Env, owner: Address) -> u64 {
+    owner.require_auth();
+
+    let id = increment_campaign_counter(env);
+    put_campaign(
+        env,
+        &Campaign {
+            id,
+            owner,
+            escrow_ids: Vec::new(env),
+        },
+    );
+    id
+}
+
+/// Attach a pending escrow to a campaign.
+///
+/// Both the campaign owner and the escrow sender must authorize, since the
+/// campaign owner gains control over settling the escrow.
+///
+/// # Errors
+/// - [`CampaignNotFound`] – no campaign for `campaign_id`.
+/// - [`EscrowNotFound`] – no escrow for `escrow_id`.
+/// - [`AlreadySpent`] – escrow is not `Pending`.
+/// - [`AlreadyInCampaign`] – escrow already belongs to a campaign.
+pub fn add_escrow_to_campaign(
+    env: &Env,
+    campaign_id: u64,
+    escrow_id: u64,
+) -> Result<(), QuickexError> {
+    let mut campaign = get_campaign(env, campaign_id).ok_or(QuickexError::CampaignNotFound)?;
+    let mut escrow = load_pending(env, escrow_id)?;
+
+    if escrow.campaign_id != 0 {
+        return Err(QuickexError::AlreadyInCampaign);
+    }
+
+    campaign.owner.require_auth();
+    escrow.from.require_auth();
+
+    escrow.campaign_id = campaign_id;
+    put_escrow_data(env, &escrow);
+    campaign.escrow_ids.push_back(escrow_id);
+    put_campaign(env, &campaign);
+    Ok(())
+}
+
+/// Return the ids of the escrows attached to a campaign.
+///
+/// Returns an empty vec for an unknown campaign.
+pub fn campaign_escrows(env: &Env, campaign_id: u64) -> Vec<u64> {
+    get_campaign(env, campaign_id)
+        .map(|campaign| campaign.escrow_ids)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Release every pending escrow of a campaign and return how many were released.
+///
+/// `caller` must be the campaign owner or the contract admin and must authorize.
+/// Escrows already in a terminal state, or not yet releasable, are skipped.
+///
+/// # Errors
+/// - [`CampaignNotFound`] – no campaign for `campaign_id`.
+/// - [`Unauthorized`] – caller is neither the campaign owner nor the admin.
+pub fn release_campaign(env: &Env, campaign_id: u64, caller: Address) -> Result<u32, QuickexError> {
+    caller.require_auth();
+
+    let campaign = get_campaign(env, campaign_id).ok_or(QuickexError::CampaignNotFound)?;
+    if caller != campaign.owner && Some(caller) != get_admin(env) {
+        return Err(QuickexError::Unauthorized);
+    }
+
+    let mut released = 0;
+    for escrow_id in campaign.escrow_ids.iter() {
+        if let Ok(escrow) = load_pending(env, escrow_id) {
+            if ensure_releasable(env, &escrow).is_ok() {
+                release(env, escrow);
+                released += 1;
+            }
+        }
+    }
+    Ok(released)
+}
+
+// ---------------------------------------------------------------------------
+// Export
+// ---------------------------------------------------------------------------
+
+/// Return the escrows with ids in `start..end`, in id order.
+///
+/// Ids without a record are skipped.
+///
+/// # Errors
+/// - [`InvalidRange`] – `start > end` or the range spans more than [`MAX_EXPORT_RANGE`] ids.
+pub fn export_escrows(env: &Env, start: u64, end: u64) -> Result<Vec<EscrowData>, QuickexError> {
+    if start > end || end - start > MAX_EXPORT_RANGE {
+        return Err(QuickexError::InvalidRange);
+    }
+
+    let mut escrows = Vec::new(env);
+    for id in start..end {
+        if let Some(escrow) = get_escrow_data(env, id) {
+            escrows.push_back(escrow);
+        }
+    }
+    Ok(escrows)
+}
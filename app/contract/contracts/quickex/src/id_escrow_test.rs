@@ -0,0 +1,2426 @@
+//! Id-based escrow tests.
+//!
+//! Covers the lifecycle of escrows created via `create_escrow` and addressed by id.
+
+use crate::{
+    errors::QuickexError,
+    events::{
+        EscrowCreatedEvent, EscrowCreatedPrivateEvent, EscrowExpiredEvent, EscrowForceResolvedEvent,
+    },
+    id_escrow::{
+        ESCROW_INDEX_PAGE_LEN, MAX_EXPIRY_BUCKET_LEN, MAX_REPAIR_PAGES, MAX_STUCK_SCAN_LEN,
+    },
+    EscrowStatus, QuickexContract, QuickexContractClient,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    address_payload::AddressPayload,
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+    token, vec,
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, Event, IntoVal, Map, Symbol, Val,
+};
+
+extern crate std;
+
+fn setup<'a>() -> (Env, QuickexContractClient<'a>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(QuickexContract, ());
+    let client = QuickexContractClient::new(&env, &contract_id);
+    (env, client)
+}
+
+/// Build an account address whose master key is `signing_key`.
+fn account_for(env: &Env, signing_key: &SigningKey) -> Address {
+    let public_key = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+    Address::from_payload(env, AddressPayload::AccountIdPublicKeyEd25519(public_key))
+}
+
+/// Sign the release message of escrow `id` for `receipt_hash`.
+fn sign(
+    env: &Env,
+    client: &QuickexContractClient,
+    signing_key: &SigningKey,
+    id: u64,
+    receipt_hash: &BytesN<32>,
+) -> Bytes {
+    let message = client.receipt_message(&id, receipt_hash);
+    let signature = signing_key.sign(&message.to_array());
+    Bytes::from_array(env, &signature.to_bytes())
+}
+
+// ============================================================================
+// create_escrow
+// ============================================================================
+
+#[test]
+fn test_create_escrow_stores_record() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    let id = client.create_unfunded_escrow(&from, &to, &750);
+
+    let escrow = client.get_escrow(&id).unwrap();
+    assert_eq!(escrow.id, id);
+    assert_eq!(escrow.from, from);
+    assert_eq!(escrow.to, to);
+    assert_eq!(escrow.amount, 750);
+    assert_eq!(escrow.status, EscrowStatus::Pending);
+    assert_eq!(escrow.created_ledger, env.ledger().sequence());
+    assert!(client.get_escrow(&(id + 1)).is_none());
+}
+
+#[test]
+fn test_create_escrow_rejects_self_escrow() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    let result = client.try_create_unfunded_escrow(&from, &from, &750);
+    assert_eq!(result, Err(Ok(QuickexError::SelfEscrow)));
+    let result = client.try_create_escrow_with_min_lock(&from, &from, &750, &10);
+    assert_eq!(result, Err(Ok(QuickexError::SelfEscrow)));
+    assert_eq!(client.total_escrowed(), 0);
+
+    let id = client.create_unfunded_escrow(&from, &to, &750);
+    assert_eq!(id, 1);
+}
+
+#[test]
+fn test_create_escrow_respects_max_amount() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    client.initialize(&admin);
+    assert_eq!(client.max_escrow_amount(), u64::MAX);
+
+    client.set_max_escrow_amount(&admin, &1_000);
+    assert_eq!(client.max_escrow_amount(), 1_000);
+
+    let result = client.try_create_unfunded_escrow(&from, &to, &1_001);
+    assert_eq!(result, Err(Ok(QuickexError::AmountTooLarge)));
+    let result = client.try_create_escrow_with_interest(&from, &to, &5_000, &1);
+    assert_eq!(result, Err(Ok(QuickexError::AmountTooLarge)));
+
+    let id = client.create_unfunded_escrow(&from, &to, &1_000);
+    assert_eq!(client.get_escrow(&id).unwrap().amount, 1_000);
+
+    let result = client.try_set_max_escrow_amount(&from, &5);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+}
+
+#[test]
+fn test_escrow_party_predicates() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &to, &750);
+
+    assert!(client.is_escrow_sender(&id, &from));
+    assert!(!client.is_escrow_sender(&id, &to));
+    assert!(!client.is_escrow_sender(&id, &stranger));
+    assert!(client.is_escrow_recipient(&id, &to));
+    assert!(!client.is_escrow_recipient(&id, &from));
+    assert!(!client.is_escrow_recipient(&id, &stranger));
+    assert!(!client.is_escrow_sender(&(id + 1), &from));
+}
+
+#[test]
+fn test_get_escrows_status_mixes_known_and_unknown_ids() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let pending = client.create_unfunded_escrow(&from, &to, &100);
+    let cancelled = client.create_unfunded_escrow(&from, &to, &100);
+    client.cancel_escrow(&cancelled, &to);
+
+    let statuses = client.get_escrows_status(&vec![&env, cancelled, 99, pending, 0]);
+    assert_eq!(
+        statuses,
+        vec![
+            &env,
+            Some(EscrowStatus::Cancelled),
+            None,
+            Some(EscrowStatus::Pending),
+            None
+        ]
+    );
+}
+
+#[test]
+fn test_escrows_same_sender() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let other = Address::generate(&env);
+    let to = Address::generate(&env);
+    let first = client.create_unfunded_escrow(&from, &to, &100);
+    let second = client.create_unfunded_escrow(&from, &other, &200);
+    let foreign = client.create_unfunded_escrow(&other, &to, &300);
+
+    assert!(client.escrows_same_sender(&first, &second));
+    assert!(!client.escrows_same_sender(&first, &foreign));
+    assert!(!client.escrows_same_sender(&first, &99));
+    assert!(!client.escrows_same_sender(&99, &99));
+}
+
+#[test]
+fn test_escrows_by_sender_and_status_filters_by_status() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let other = Address::generate(&env);
+    let to = Address::generate(&env);
+    let pending_a = client.create_unfunded_escrow(&from, &to, &100);
+    let released = client.create_unfunded_escrow(&from, &to, &100);
+    let cancelled = client.create_unfunded_escrow(&from, &to, &100);
+    let disputed = client.create_unfunded_escrow(&from, &to, &100);
+    let pending_b = client.create_unfunded_escrow(&from, &to, &100);
+    client.create_unfunded_escrow(&other, &to, &100);
+    client.release_escrow(&released, &from);
+    client.cancel_escrow(&cancelled, &to);
+    client.dispute_escrow(&disputed, &to);
+
+    assert_eq!(
+        client.escrows_by_sender_and_status(&from, &EscrowStatus::Pending, &0),
+        vec![&env, pending_a, pending_b]
+    );
+    assert_eq!(
+        client.escrows_by_sender_and_status(&from, &EscrowStatus::Released, &0),
+        vec![&env, released]
+    );
+    assert_eq!(
+        client.escrows_by_sender_and_status(&from, &EscrowStatus::Cancelled, &0),
+        vec![&env, cancelled]
+    );
+    assert_eq!(
+        client.escrows_by_sender_and_status(&from, &EscrowStatus::Disputed, &0),
+        vec![&env, disputed]
+    );
+    assert_eq!(
+        client
+            .escrows_by_sender_and_status(&from, &EscrowStatus::Refunded, &0)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_escrows_by_sender_and_status_sparse_pages() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    for _ in 0..ESCROW_INDEX_PAGE_LEN {
+        client.create_unfunded_escrow(&from, &to, &100);
+    }
+    let released = client.create_unfunded_escrow(&from, &to, &100);
+    client.release_escrow(&released, &from);
+    assert_eq!(client.sender_escrow_pages(&from), 2);
+
+    // The first page holds no released escrow, but the second one does.
+    let by_status =
+        |page: u32| client.escrows_by_sender_and_status(&from, &EscrowStatus::Released, &page);
+    assert_eq!(by_status(0).len(), 0);
+    assert_eq!(by_status(1), vec![&env, released]);
+
+    let mut all = vec![&env];
+    for page in 0..client.sender_escrow_pages(&from) {
+        all.append(&by_status(page));
+    }
+    assert_eq!(all, vec![&env, released]);
+    assert_eq!(by_status(2).len(), 0);
+}
+
+#[test]
+fn test_stuck_escrow_count_uses_creation_ledger() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    env.ledger().set_sequence_number(100);
+    client.create_unfunded_escrow(&from, &to, &100);
+    let settled = client.create_unfunded_escrow(&from, &to, &100);
+    env.ledger().set_sequence_number(150);
+    client.create_unfunded_escrow(&from, &to, &100);
+    env.ledger().set_sequence_number(190);
+    client.create_unfunded_escrow(&from, &to, &100);
+    client.cancel_escrow(&settled, &to);
+
+    env.ledger().set_sequence_number(200);
+    assert_eq!(client.stuck_escrow_count(&100, &1, &10), (0, 5));
+    assert_eq!(client.stuck_escrow_count(&99, &1, &10), (1, 5));
+    assert_eq!(client.stuck_escrow_count(&49, &1, &10), (2, 5));
+    assert_eq!(client.stuck_escrow_count(&0, &1, &10), (3, 5));
+
+    env.ledger().set_sequence_number(260);
+    assert_eq!(client.stuck_escrow_count(&100, &1, &10), (2, 5));
+}
+
+#[test]
+fn test_stuck_escrow_count_pages_by_id() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    for _ in 0..MAX_STUCK_SCAN_LEN + 5 {
+        client.create_unfunded_escrow(&from, &to, &100);
+    }
+    env.ledger().set_sequence_number(10);
+
+    assert_eq!(client.stuck_escrow_count(&0, &1, &3), (3, 4));
+    assert_eq!(client.stuck_escrow_count(&0, &4, &3), (3, 7));
+
+    // A single call never reads more than MAX_STUCK_SCAN_LEN ids.
+    let (first, next) = client.stuck_escrow_count(&0, &1, &u32::MAX);
+    assert_eq!(first, MAX_STUCK_SCAN_LEN);
+    assert_eq!(next, MAX_STUCK_SCAN_LEN as u64 + 1);
+    let (rest, next) = client.stuck_escrow_count(&0, &next, &u32::MAX);
+    assert_eq!(rest, 5);
+    assert_eq!(next, MAX_STUCK_SCAN_LEN as u64 + 6);
+
+    // Past the last escrow nothing is read and the cursor stays put.
+    assert_eq!(client.stuck_escrow_count(&0, &next, &10), (0, next));
+}
+
+#[test]
+fn test_escrow_created_before_uses_creation_ledger() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    env.ledger().set_sequence_number(100);
+    let early = client.create_unfunded_escrow(&from, &to, &100);
+    env.ledger().set_sequence_number(200);
+    let late = client.create_unfunded_escrow(&from, &to, &100);
+
+    assert!(client.escrow_created_before(&early, &150));
+    assert!(!client.escrow_created_before(&late, &150));
+    assert!(!client.escrow_created_before(&early, &100));
+    assert!(!client.escrow_created_before(&(late + 1), &u32::MAX));
+}
+
+#[test]
+fn test_migrate_escrow_recipient_updates_record_and_index() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let new_to = Address::generate(&env);
+    let first = client.create_unfunded_escrow(&from, &to, &100);
+    let second = client.create_unfunded_escrow(&from, &to, &200);
+    assert_eq!(
+        client.escrows_by_recipient(&to, &0),
+        vec![&env, first, second]
+    );
+
+    client.migrate_escrow_party(&to, &first, &to, &new_to);
+
+    let escrow = client.get_escrow(&first).unwrap();
+    assert_eq!(escrow.to, new_to);
+    assert_eq!(escrow.from, from);
+    assert_eq!(client.escrows_by_recipient(&to, &0), vec![&env, second]);
+    assert_eq!(client.escrows_by_recipient(&new_to, &0), vec![&env, first]);
+    assert_eq!(
+        client.escrows_by_sender(&from, &0),
+        vec![&env, first, second]
+    );
+    assert!(client.is_escrow_recipient(&first, &new_to));
+}
+
+#[test]
+fn test_escrow_indexes_are_paged() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let new_to = Address::generate(&env);
+    let mut last = 0;
+    for _ in 0..=ESCROW_INDEX_PAGE_LEN {
+        last = client.create_unfunded_escrow(&from, &to, &100);
+    }
+
+    assert_eq!(client.sender_escrow_pages(&from), 2);
+    assert_eq!(client.recipient_escrow_pages(&to), 2);
+    let first_page = client.escrows_by_recipient(&to, &0);
+    assert_eq!(first_page.len(), ESCROW_INDEX_PAGE_LEN);
+    assert_eq!(first_page.get(0), Some(1));
+    assert_eq!(client.escrows_by_recipient(&to, &1), vec![&env, last]);
+    assert_eq!(client.escrows_by_recipient(&to, &2).len(), 0);
+
+    client.migrate_escrow_party(&to, &2, &to, &new_to);
+    let first_page = client.escrows_by_recipient(&to, &0);
+    assert_eq!(first_page.len(), ESCROW_INDEX_PAGE_LEN - 1);
+    assert!(!first_page.contains(2));
+    assert_eq!(client.escrows_by_recipient(&to, &1), vec![&env, last]);
+    assert_eq!(client.escrows_by_recipient(&new_to, &0), vec![&env, 2]);
+    assert_eq!(client.recipient_escrow_pages(&to), 2);
+
+    client.migrate_escrow_party(&new_to, &2, &new_to, &to);
+    assert_eq!(client.escrows_by_recipient(&to, &1), vec![&env, last, 2]);
+    assert_eq!(client.escrows_by_recipient(&new_to, &0).len(), 0);
+}
+
+#[test]
+fn test_migrate_escrow_party_by_admin_and_rejections() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let new_from = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &to, &100);
+
+    let result = client.try_migrate_escrow_party(&stranger, &id, &from, &new_from);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+    let result = client.try_migrate_escrow_party(&stranger, &id, &stranger, &new_from);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidOwner)));
+    let result = client.try_migrate_escrow_party(&from, &id, &from, &to);
+    assert_eq!(result, Err(Ok(QuickexError::SelfEscrow)));
+
+    client.migrate_escrow_party(&admin, &id, &from, &new_from);
+    assert_eq!(client.get_escrow(&id).unwrap().from, new_from);
+    assert_eq!(client.escrows_by_sender(&from, &0).len(), 0);
+    assert_eq!(client.escrows_by_sender(&new_from, &0), vec![&env, id]);
+}
+
+#[test]
+fn test_create_escrow_low_privacy_sender_emits_full_event() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    client.enable_privacy(&from, &1, &None);
+
+    let id = client.create_unfunded_escrow(&from, &to, &750);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected = EscrowCreatedEvent {
+        id,
+        from,
+        to,
+        amount: 750,
+    };
+    assert_eq!(topics, expected.topics(&env));
+    let data: Map<Symbol, Val> = data.into_val(&env);
+    assert!(data.contains_key(Symbol::new(&env, "from")));
+    assert!(data.contains_key(Symbol::new(&env, "to")));
+    assert!(data.contains_key(Symbol::new(&env, "amount")));
+}
+
+#[test]
+fn test_create_escrow_high_privacy_sender_emits_redacted_event() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    client.enable_privacy(&from, &2, &None);
+
+    let id = client.create_unfunded_escrow(&from, &to, &750);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, EscrowCreatedPrivateEvent { id }.topics(&env));
+    let data: Map<Symbol, Val> = data.into_val(&env);
+    assert!(data.is_empty());
+}
+
+// ============================================================================
+// release_with_receipt
+// ============================================================================
+
+#[test]
+fn test_release_with_receipt_valid_signature_releases() {
+    let (env, client) = setup();
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let from = account_for(&env, &signing_key);
+    let to = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &to, &1_000);
+
+    let receipt_hash = BytesN::from_array(&env, &[42u8; 32]);
+    let signature = sign(&env, &client, &signing_key, id, &receipt_hash);
+
+    client.release_with_receipt(&id, &receipt_hash, &signature, &from);
+
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Released
+    );
+
+    // A second release with the same receipt must fail.
+    let result = client.try_release_with_receipt(&id, &receipt_hash, &signature, &from);
+    assert_eq!(result, Err(Ok(QuickexError::AlreadySpent)));
+}
+
+#[test]
+fn test_release_with_receipt_signature_bound_to_escrow() {
+    let (env, client) = setup();
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let from = account_for(&env, &signing_key);
+    let to = Address::generate(&env);
+    let id_a = client.create_unfunded_escrow(&from, &to, &1_000);
+    let id_b = client.create_unfunded_escrow(&from, &to, &1_000);
+
+    let receipt_hash = BytesN::from_array(&env, &[42u8; 32]);
+    let signature_a = sign(&env, &client, &signing_key, id_a, &receipt_hash);
+
+    // A signature made for escrow A does not verify against escrow B.
+    let result = client.try_release_with_receipt(&id_b, &receipt_hash, &signature_a, &from);
+    assert!(result.is_err());
+    assert_eq!(
+        client.get_escrow(&id_b).unwrap().status,
+        EscrowStatus::Pending
+    );
+
+    // Once used for A, the receipt cannot be replayed against B either.
+    client.release_with_receipt(&id_a, &receipt_hash, &signature_a, &from);
+    let signature_b = sign(&env, &client, &signing_key, id_b, &receipt_hash);
+    let result = client.try_release_with_receipt(&id_b, &receipt_hash, &signature_b, &from);
+    assert_eq!(result, Err(Ok(QuickexError::AlreadySpent)));
+    assert_eq!(
+        client.get_escrow(&id_b).unwrap().status,
+        EscrowStatus::Pending
+    );
+}
+
+#[test]
+fn test_release_with_receipt_forged_signature_reverts() {
+    let (env, client) = setup();
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let forger_key = SigningKey::from_bytes(&[9u8; 32]);
+    let from = account_for(&env, &signing_key);
+    let to = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &to, &1_000);
+
+    let receipt_hash = BytesN::from_array(&env, &[42u8; 32]);
+    let forged = sign(&env, &client, &forger_key, id, &receipt_hash);
+
+    let result = client.try_release_with_receipt(&id, &receipt_hash, &forged, &from);
+    assert!(result.is_err());
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Pending
+    );
+}
+
+#[test]
+fn test_release_with_receipt_rejects_non_sender_signer() {
+    let (env, client) = setup();
+    let sender_key = SigningKey::from_bytes(&[7u8; 32]);
+    let other_key = SigningKey::from_bytes(&[9u8; 32]);
+    let from = account_for(&env, &sender_key);
+    let other = account_for(&env, &other_key);
+    let to = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &to, &1_000);
+
+    let receipt_hash = BytesN::from_array(&env, &[42u8; 32]);
+    let signature = sign(&env, &client, &other_key, id, &receipt_hash);
+
+    let result = client.try_release_with_receipt(&id, &receipt_hash, &signature, &other);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+}
+
+#[test]
+fn test_release_with_receipt_malformed_signature_fails() {
+    let (env, client) = setup();
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let from = account_for(&env, &signing_key);
+    let to = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &to, &1_000);
+
+    let receipt_hash = BytesN::from_array(&env, &[42u8; 32]);
+    let short = Bytes::from_array(&env, &[0u8; 10]);
+
+    let result = client.try_release_with_receipt(&id, &receipt_hash, &short, &from);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidSignature)));
+}
+
+#[test]
+fn test_release_with_receipt_unknown_escrow_fails() {
+    let (env, client) = setup();
+    let signer = Address::generate(&env);
+    let receipt_hash = BytesN::from_array(&env, &[42u8; 32]);
+    let signature = Bytes::from_array(&env, &[0u8; 64]);
+
+    let result = client.try_release_with_receipt(&99, &receipt_hash, &signature, &signer);
+    assert_eq!(result, Err(Ok(QuickexError::EscrowNotFound)));
+}
+
+// ============================================================================
+// Creation receipts
+// ============================================================================
+
+/// Re-derive a creation receipt off-chain:
+/// `sha256(BE64(id) || XDR(from) || XDR(to) || BE64(amount) || BE32(ledger))`.
+fn derive_receipt(
+    env: &Env,
+    id: u64,
+    from: &Address,
+    to: &Address,
+    amount: u64,
+    ledger: u32,
+) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &id.to_be_bytes());
+    preimage.append(&from.clone().to_xdr(env));
+    preimage.append(&to.clone().to_xdr(env));
+    preimage.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+    preimage.append(&Bytes::from_array(env, &ledger.to_be_bytes()));
+    env.crypto().sha256(&preimage).into()
+}
+
+#[test]
+fn test_escrow_receipt_re_derives() {
+    let (env, client) = setup();
+    env.ledger().set_sequence_number(77);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    let (id, receipt) = client.create_escrow_with_receipt(&from, &to, &1_500);
+
+    assert_eq!(receipt, derive_receipt(&env, id, &from, &to, 1_500, 77));
+    assert!(client.verify_escrow_receipt(&id, &receipt));
+}
+
+#[test]
+fn test_escrow_receipt_detects_tampered_amount() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let (id, receipt) = client.create_escrow_with_receipt(&from, &to, &1_500);
+
+    let ledger = env.ledger().sequence();
+    let tampered = derive_receipt(&env, id, &from, &to, 1_501, ledger);
+    assert!(!client.verify_escrow_receipt(&id, &tampered));
+    assert!(!client.verify_escrow_receipt(&(id + 1), &receipt));
+}
+
+// ============================================================================
+// Escrow term commitments
+// ============================================================================
+
+#[test]
+fn test_open_escrow_terms_creates_committed_escrow() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"terms");
+    let expiry = env.ledger().sequence() + 100;
+    let terms = client.create_escrow_term_commitment(&from, &to, &2_000, &expiry, &salt);
+
+    let id = client.open_escrow_terms(&terms, &from, &to, &2_000, &expiry, &salt);
+
+    let escrow = client.get_escrow(&id).unwrap();
+    assert_eq!(escrow.from, from);
+    assert_eq!(escrow.to, to);
+    assert_eq!(escrow.amount, 2_000);
+    assert_eq!(escrow.expiry_ledger, expiry);
+    assert_eq!(escrow.status, EscrowStatus::Pending);
+}
+
+#[test]
+fn test_open_escrow_terms_rejects_mismatched_terms() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"terms");
+    let terms = client.create_escrow_term_commitment(&from, &to, &2_000, &0, &salt);
+
+    let result = client.try_open_escrow_terms(&terms, &from, &to, &2_001, &0, &salt);
+    assert_eq!(result, Err(Ok(QuickexError::CommitmentMismatch)));
+    let result = client.try_open_escrow_terms(&terms, &from, &to, &2_000, &500, &salt);
+    assert_eq!(result, Err(Ok(QuickexError::CommitmentMismatch)));
+    assert_eq!(client.total_escrowed(), 0);
+
+    let id = client.open_escrow_terms(&terms, &from, &to, &2_000, &0, &salt);
+    assert_eq!(client.get_escrow(&id).unwrap().expiry_ledger, 0);
+}
+
+// ============================================================================
+// create_escrow_to_name
+// ============================================================================
+
+/// Name registry stand-in resolving names registered via `register`.
+#[contract]
+struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn register(env: Env, name: Symbol, address: Address) {
+        env.storage().instance().set(&name, &address);
+    }
+
+    pub fn resolve(env: Env, name: Symbol) -> Option<Address> {
+        env.storage().instance().get(&name)
+    }
+}
+
+#[test]
+fn test_create_escrow_to_name_stores_resolved_address() {
+    let (env, client) = setup();
+    let registry = env.register(MockRegistry, ());
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let name = Symbol::new(&env, "alice");
+    MockRegistryClient::new(&env, &registry).register(&name, &to);
+
+    let id = client.create_escrow_to_name(&from, &name, &900, &registry);
+
+    let escrow = client.get_escrow(&id).unwrap();
+    assert_eq!(escrow.to, to);
+    assert_eq!(escrow.amount, 900);
+}
+
+#[test]
+fn test_create_escrow_to_name_unresolved_reverts() {
+    let (env, client) = setup();
+    let registry = env.register(MockRegistry, ());
+    let from = Address::generate(&env);
+
+    let result =
+        client.try_create_escrow_to_name(&from, &Symbol::new(&env, "nobody"), &900, &registry);
+    assert_eq!(result, Err(Ok(QuickexError::NameNotResolved)));
+
+    // A registry address without the `resolve` entry point also fails cleanly.
+    let not_a_registry = env.register(QuickexContract, ());
+    let result =
+        client.try_create_escrow_to_name(&from, &Symbol::new(&env, "alice"), &900, &not_a_registry);
+    assert_eq!(result, Err(Ok(QuickexError::NameNotResolved)));
+    assert_eq!(client.total_escrowed(), 0);
+}
+
+// ============================================================================
+// release callback
+// ============================================================================
+
+/// Recipient contract recording the release callbacks it receives.
+#[contract]
+struct MockReceiver;
+
+#[contractimpl]
+impl MockReceiver {
+    pub fn on_escrow_received(env: Env, id: u64, amount: u64) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("received"), &(id, amount));
+    }
+
+    pub fn received(env: Env) -> Option<(u64, u64)> {
+        env.storage().instance().get(&symbol_short!("received"))
+    }
+}
+
+#[test]
+fn test_release_escrow_calls_back_recipient_contract() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = env.register(MockReceiver, ());
+    let receiver = MockReceiverClient::new(&env, &to);
+    let id = client.create_unfunded_escrow(&from, &to, &700);
+
+    client.set_release_callback(&id, &true);
+    client.release_escrow(&id, &from);
+
+    assert_eq!(receiver.received(), Some((id, 700)));
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Released
+    );
+}
+
+#[test]
+fn test_release_escrow_without_opt_in_skips_callback() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = env.register(MockReceiver, ());
+    let receiver = MockReceiverClient::new(&env, &to);
+    let id = client.create_unfunded_escrow(&from, &to, &700);
+
+    client.release_escrow(&id, &from);
+
+    assert_eq!(receiver.received(), None);
+}
+
+#[test]
+fn test_release_to_many_calls_back_each_recipient_contract() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let first = env.register(MockReceiver, ());
+    let second = env.register(MockReceiver, ());
+    let account = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &first, &700);
+
+    client.set_release_callback(&id, &true);
+    client.release_to_many(
+        &id,
+        &vec![&env, first.clone(), account, second.clone()],
+        &vec![&env, 400u64, 100, 200],
+    );
+
+    assert_eq!(
+        MockReceiverClient::new(&env, &first).received(),
+        Some((id, 400))
+    );
+    assert_eq!(
+        MockReceiverClient::new(&env, &second).received(),
+        Some((id, 200))
+    );
+}
+
+#[test]
+fn test_release_callback_tolerates_accounts_and_missing_entry_point() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let account = Address::generate(&env);
+    let plain_contract = env.register(MockRegistry, ());
+
+    for to in [account, plain_contract] {
+        let id = client.create_unfunded_escrow(&from, &to, &700);
+        client.set_release_callback(&id, &true);
+        client.release_escrow(&id, &from);
+        assert_eq!(
+            client.get_escrow(&id).unwrap().status,
+            EscrowStatus::Released
+        );
+    }
+}
+
+// ============================================================================
+// create_escrow_from_allowance
+// ============================================================================
+
+/// Register a Stellar asset and mint `amount` of it to `holder`.
+fn funded_token(env: &Env, holder: &Address, amount: i128) -> Address {
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    token::StellarAssetClient::new(env, &token).mint(holder, &amount);
+    token
+}
+
+#[test]
+fn test_create_escrow_moves_funds_on_create_and_release() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let token = funded_token(&env, &from, 1_000);
+    let balances = token::Client::new(&env, &token);
+
+    let id = client.create_escrow(&from, &to, &400, &token);
+
+    assert_eq!(balances.balance(&from), 600);
+    assert_eq!(balances.balance(&client.address), 400);
+    let escrow = client.get_escrow(&id).unwrap();
+    assert_eq!(escrow.token, Some(token.clone()));
+    assert_eq!(escrow.amount, 400);
+    assert_eq!(client.locked_balance(&token), 400);
+
+    client.release_escrow(&id, &from);
+
+    assert_eq!(balances.balance(&to), 400);
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(client.locked_balance(&token), 0);
+}
+
+#[test]
+fn test_arbiter_fee_paid_on_dispute_resolution() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    client.initialize(&admin);
+    let token = funded_token(&env, &from, 1_000);
+    let balances = token::Client::new(&env, &token);
+
+    let released = client.create_escrow_with_arbiter_fee(&from, &to, &500, &token, &50);
+    let refunded = client.create_escrow_with_arbiter_fee(&from, &to, &300, &token, &30);
+    assert_eq!(client.arbiter_fee(&released), 50);
+    for id in [released, refunded] {
+        client.change_arbiter(&id, &arbiter);
+        client.dispute_escrow(&id, &to);
+    }
+
+    client.resolve_dispute(&arbiter, &released, &false);
+    assert_eq!(balances.balance(&to), 450);
+    assert_eq!(client.get_escrow(&released).unwrap().released_amount, 450);
+
+    client.resolve_dispute(&arbiter, &refunded, &true);
+    assert_eq!(balances.balance(&from), 200 + 270);
+    assert_eq!(balances.balance(&arbiter), 80);
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(client.locked_balance(&token), 0);
+}
+
+#[test]
+fn test_arbiter_fee_not_paid_on_normal_release() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let token = funded_token(&env, &from, 1_000);
+    let balances = token::Client::new(&env, &token);
+
+    let result = client.try_create_escrow_with_arbiter_fee(&from, &to, &500, &token, &501);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidAmount)));
+
+    let id = client.create_escrow_with_arbiter_fee(&from, &to, &500, &token, &50);
+    client.release_escrow(&id, &from);
+
+    assert_eq!(balances.balance(&to), 500);
+    assert_eq!(balances.balance(&client.address), 0);
+}
+
+#[test]
+fn test_migrate_token_escrow_party_rejects_admin_override() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let new_from = Address::generate(&env);
+    let token = funded_token(&env, &from, 1_000);
+    let id = client.create_escrow(&from, &to, &400, &token);
+
+    let result = client.try_migrate_escrow_party(&admin, &id, &from, &new_from);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+    assert_eq!(client.get_escrow(&id).unwrap().from, from);
+
+    client.migrate_escrow_party(&from, &id, &from, &new_from);
+    assert_eq!(client.get_escrow(&id).unwrap().from, new_from);
+}
+
+#[test]
+fn test_escrow_fund_movements_blocked_while_paused() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let token = funded_token(&env, &from, 1_000);
+    let id = client.create_escrow(&from, &to, &400, &token);
+    client.set_paused(&admin, &true);
+
+    let paused = Some(Ok(QuickexError::ContractPaused));
+    assert_eq!(
+        client.try_create_escrow(&from, &to, &100, &token).err(),
+        paused
+    );
+    assert_eq!(
+        client
+            .try_create_escrow_from_allowance(&from, &to, &100, &token)
+            .err(),
+        paused
+    );
+    assert_eq!(
+        client
+            .try_create_escrow_with_arbiter_fee(&from, &to, &100, &token, &10)
+            .err(),
+        paused
+    );
+    assert_eq!(client.try_release_escrow(&id, &from).err(), paused);
+    assert_eq!(
+        client
+            .try_release_to_many(&id, &vec![&env, to.clone()], &vec![&env, 400u64])
+            .err(),
+        paused
+    );
+    assert_eq!(
+        client.try_claim_hashlock(&id, &Bytes::new(&env)).err(),
+        paused
+    );
+    assert_eq!(client.try_cancel_escrow(&id, &to).err(), paused);
+    assert_eq!(client.try_refund_escrow(&id, &to).err(), paused);
+    assert_eq!(client.try_claim_expired(&id).err(), paused);
+    assert_eq!(client.try_claim_expired_funds(&id).err(), paused);
+    assert_eq!(client.try_sweep_expired(&10).err(), paused);
+    assert_eq!(client.try_resolve_dispute(&admin, &id, &true).err(), paused);
+    assert_eq!(client.try_resolve_dispute_timeout(&id, &from).err(), paused);
+    let campaign_id = client.create_campaign(&from);
+    assert_eq!(
+        client.try_release_campaign(&campaign_id, &from).err(),
+        paused
+    );
+    let receipt_hash = BytesN::from_array(&env, &[42u8; 32]);
+    let signature = Bytes::from_array(&env, &[0u8; 64]);
+    assert_eq!(
+        client
+            .try_release_with_receipt(&id, &receipt_hash, &signature, &from)
+            .err(),
+        paused
+    );
+
+    client.set_paused(&admin, &false);
+    client.release_escrow(&id, &from);
+    assert_eq!(token::Client::new(&env, &token).balance(&to), 400);
+}
+
+#[test]
+fn test_create_escrow_from_allowance_pulls_funds() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let token = funded_token(&env, &from, 1_000);
+    let balances = token::Client::new(&env, &token);
+    balances.approve(&from, &client.address, &700, &1_000);
+
+    let id = client.create_escrow_from_allowance(&from, &to, &700, &token);
+
+    assert_eq!(balances.balance(&from), 300);
+    assert_eq!(balances.balance(&client.address), 700);
+    assert_eq!(balances.allowance(&from, &client.address), 0);
+    assert_eq!(client.get_escrow(&id).unwrap().token, Some(token.clone()));
+    assert_eq!(client.locked_balance(&token), 700);
+
+    client.release_escrow(&id, &from);
+    assert_eq!(balances.balance(&to), 700);
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(client.locked_balance(&token), 0);
+}
+
+#[test]
+fn test_create_escrow_from_allowance_insufficient_allowance_reverts() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let token = funded_token(&env, &from, 1_000);
+    let balances = token::Client::new(&env, &token);
+    balances.approve(&from, &client.address, &100, &1_000);
+
+    let result = client.try_create_escrow_from_allowance(&from, &to, &700, &token);
+    assert!(result.is_err());
+    assert_eq!(balances.balance(&from), 1_000);
+    assert_eq!(client.total_escrowed(), 0);
+    assert!(client.get_escrow(&1).is_none());
+}
+
+#[test]
+fn test_cancelled_token_escrow_refunds_sender() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let token = funded_token(&env, &from, 500);
+    let balances = token::Client::new(&env, &token);
+    balances.approve(&from, &client.address, &500, &1_000);
+    let id = client.create_escrow_from_allowance(&from, &to, &500, &token);
+
+    client.cancel_escrow(&id, &to);
+
+    assert_eq!(balances.balance(&from), 500);
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(client.locked_balance(&token), 0);
+}
+
+// ============================================================================
+// release_escrow / M-of-N approvals
+// ============================================================================
+
+#[test]
+fn test_release_escrow_by_sender() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &to, &100);
+
+    let result = client.try_release_escrow(&id, &to);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+
+    client.release_escrow(&id, &from);
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Released
+    );
+}
+
+#[test]
+fn test_refund_escrow_returns_funds_to_sender() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let token = funded_token(&env, &from, 1_000);
+    let balances = token::Client::new(&env, &token);
+    balances.approve(&from, &client.address, &600, &1_000);
+    let id = client.create_escrow_from_allowance(&from, &to, &600, &token);
+
+    let result = client.try_refund_escrow(&id, &from);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+
+    client.refund_escrow(&id, &to);
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(balances.balance(&from), 1_000);
+    assert_eq!(balances.balance(&client.address), 0);
+
+    let unknown = client.try_refund_escrow(&99, &to);
+    assert_eq!(unknown, Err(Ok(QuickexError::EscrowNotFound)));
+}
+
+#[test]
+fn test_escrow_cannot_complete_twice() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let released = client.create_unfunded_escrow(&from, &to, &100);
+    let refunded = client.create_unfunded_escrow(&from, &to, &100);
+    client.release_escrow(&released, &from);
+    client.refund_escrow(&refunded, &to);
+
+    for id in [released, refunded] {
+        let release = client.try_release_escrow(&id, &from);
+        assert_eq!(release, Err(Ok(QuickexError::AlreadySpent)));
+        let refund = client.try_refund_escrow(&id, &to);
+        assert_eq!(refund, Err(Ok(QuickexError::AlreadySpent)));
+    }
+}
+
+#[test]
+fn test_mofn_escrow_releases_at_threshold() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let approvers = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let id = client.create_mofn_escrow(&from, &to, &100, &approvers, &2);
+
+    assert_eq!(client.approve_escrow(&id, &approvers.get(0).unwrap()), 1);
+    assert_eq!(client.approve_escrow(&id, &approvers.get(2).unwrap()), 2);
+
+    client.release_escrow(&id, &from);
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Released
+    );
+}
+
+#[test]
+fn test_mofn_escrow_rejects_release_below_threshold() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let approvers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let id = client.create_mofn_escrow(&from, &to, &100, &approvers, &2);
+    client.approve_escrow(&id, &approvers.get(0).unwrap());
+
+    let result = client.try_release_escrow(&id, &from);
+    assert_eq!(result, Err(Ok(QuickexError::InsufficientApprovals)));
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Pending
+    );
+}
+
+#[test]
+fn test_mofn_escrow_ignores_duplicate_approvals() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let approver = Address::generate(&env);
+    let approvers = vec![&env, approver.clone(), Address::generate(&env)];
+    let id = client.create_mofn_escrow(&from, &to, &100, &approvers, &2);
+
+    assert_eq!(client.approve_escrow(&id, &approver), 1);
+    assert_eq!(client.approve_escrow(&id, &approver), 1);
+
+    let result = client.try_release_escrow(&id, &from);
+    assert_eq!(result, Err(Ok(QuickexError::InsufficientApprovals)));
+}
+
+#[test]
+fn test_mofn_escrow_rejects_invalid_setup_and_strangers() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let approver = Address::generate(&env);
+    let approvers = vec![&env, approver.clone(), approver.clone()];
+
+    // Duplicates collapse to one approver, so a threshold of 2 is unreachable.
+    let result = client.try_create_mofn_escrow(&from, &to, &100, &approvers, &2);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidThreshold)));
+    let result = client.try_create_mofn_escrow(&from, &to, &100, &approvers, &0);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidThreshold)));
+
+    let id = client.create_mofn_escrow(&from, &to, &100, &approvers, &1);
+    let stranger = Address::generate(&env);
+    let result = client.try_approve_escrow(&id, &stranger);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+}
+
+// ============================================================================
+// release_to_many
+// ============================================================================
+
+#[test]
+fn test_release_to_many_splits_payout() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &to, &1_000);
+
+    client.release_to_many(
+        &id,
+        &vec![&env, first.clone(), second.clone()],
+        &vec![&env, 600, 400],
+    );
+
+    let events = env.events().all();
+    assert_eq!(events.len(), 2);
+    let (_, _, data) = events.get(1).unwrap();
+    let data: Map<Symbol, Val> = data.into_val(&env);
+    let amount: u64 = data
+        .get(Symbol::new(&env, "amount"))
+        .unwrap()
+        .into_val(&env);
+    assert_eq!(amount, 400);
+
+    let escrow = client.get_escrow(&id).unwrap();
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.released_amount, 1_000);
+    assert_eq!(client.total_escrowed(), 0);
+}
+
+#[test]
+fn test_release_to_many_rejects_bad_inputs() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &to, &1_000);
+    let recipients = vec![&env, first, second];
+
+    let result = client.try_release_to_many(&id, &recipients, &vec![&env, 1_000]);
+    assert_eq!(result, Err(Ok(QuickexError::LengthMismatch)));
+    let result = client.try_release_to_many(&id, &recipients, &vec![&env, 600, 300]);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidAmount)));
+    let result = client.try_release_to_many(&id, &recipients, &vec![&env, u64::MAX, 1_001]);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidAmount)));
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Pending
+    );
+}
+
+// ============================================================================
+// Minimum lock
+// ============================================================================
+
+#[test]
+fn test_min_lock_blocks_early_release() {
+    let (env, client) = setup();
+    env.ledger().set_sequence_number(500);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let id = client.create_escrow_with_min_lock(&from, &to, &100, &20);
+
+    let result = client.try_release_escrow(&id, &from);
+    assert_eq!(result, Err(Ok(QuickexError::LockNotElapsed)));
+
+    env.ledger().set_sequence_number(519);
+    let result = client.try_release_escrow(&id, &from);
+    assert_eq!(result, Err(Ok(QuickexError::LockNotElapsed)));
+
+    env.ledger().set_sequence_number(520);
+    client.release_escrow(&id, &from);
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Released
+    );
+}
+
+// ============================================================================
+// Encrypted memos
+// ============================================================================
+
+#[test]
+fn test_encrypted_memo_readable_by_recipient() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let memo = Bytes::from_slice(&env, &[0xA5; 64]);
+
+    let id = client.create_escrow_encrypted_memo(&from, &to, &500, &memo);
+
+    assert_eq!(client.get_encrypted_memo(&id), Some(memo));
+    assert_eq!(env.auths()[0].0, to);
+    let plain = client.create_unfunded_escrow(&from, &to, &500);
+    assert_eq!(client.get_encrypted_memo(&plain), None);
+
+    let too_long = Bytes::from_slice(&env, &[0u8; 257]);
+    let result = client.try_create_escrow_encrypted_memo(&from, &to, &500, &too_long);
+    assert_eq!(result, Err(Ok(QuickexError::MemoTooLong)));
+}
+
+#[test]
+fn test_encrypted_memo_non_recipient_read_reverts() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let memo = Bytes::from_slice(&env, b"ciphertext");
+    let id = client.create_escrow_encrypted_memo(&from, &to, &500, &memo);
+
+    env.mock_auths(&[MockAuth {
+        address: &from,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "get_encrypted_memo",
+            args: (id,).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_get_encrypted_memo(&id).is_err());
+}
+
+// ============================================================================
+// total_escrowed
+// ============================================================================
+
+#[test]
+fn test_total_escrowed_tracks_open_escrows() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    assert_eq!(client.total_escrowed(), 0);
+
+    let released = client.create_unfunded_escrow(&from, &to, &100);
+    let cancelled = client.create_unfunded_escrow(&from, &to, &250);
+    let expiry_ledger = env.ledger().sequence() + 10;
+    let expiring = client.create_escrow_with_expiry(&from, &to, &650, &expiry_ledger);
+    assert_eq!(client.total_escrowed(), 1_000);
+
+    client.release_escrow(&released, &from);
+    assert_eq!(client.total_escrowed(), 900);
+
+    client.cancel_escrow(&cancelled, &to);
+    assert_eq!(client.total_escrowed(), 650);
+
+    env.ledger().set_sequence_number(expiry_ledger);
+    client.claim_expired(&expiring);
+    assert_eq!(client.total_escrowed(), 0);
+}
+
+#[test]
+fn test_open_escrow_count_tracks_unsettled_escrows() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    let released = client.create_unfunded_escrow(&from, &to, &100);
+    let disputed = client.create_unfunded_escrow(&from, &to, &100);
+    client.create_unfunded_escrow(&from, &to, &100);
+    assert_eq!(client.open_escrow_count(&from), 3);
+
+    client.release_escrow(&released, &from);
+    client.dispute_escrow(&disputed, &to);
+    assert_eq!(client.open_escrow_count(&from), 2);
+    assert_eq!(client.open_escrow_count(&to), 0);
+}
+
+#[test]
+fn test_repair_open_count_restores_desynced_count() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let cancelled = client.create_unfunded_escrow(&from, &to, &100);
+    let disputed = client.create_unfunded_escrow(&from, &to, &100);
+    client.create_unfunded_escrow(&from, &to, &100);
+    client.cancel_escrow(&cancelled, &to);
+    client.dispute_escrow(&disputed, &to);
+
+    env.as_contract(&client.address, || {
+        crate::storage::set_open_escrow_count(&env, &from, 42);
+    });
+    assert_eq!(client.open_escrow_count(&from), 42);
+
+    let result = client.try_repair_open_count(&from, &from);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+
+    assert_eq!(client.repair_open_count(&admin, &from), (2, 0));
+    assert_eq!(client.open_escrow_count(&from), 2);
+}
+
+#[test]
+fn test_repair_open_count_spans_calls() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let total = ESCROW_INDEX_PAGE_LEN * MAX_REPAIR_PAGES + 3;
+    for _ in 0..total {
+        client.create_unfunded_escrow(&from, &to, &100);
+    }
+    let cancelled = client.create_unfunded_escrow(&from, &to, &100);
+    client.cancel_escrow(&cancelled, &to);
+    assert_eq!(client.sender_escrow_pages(&from), MAX_REPAIR_PAGES + 1);
+
+    env.as_contract(&client.address, || {
+        crate::storage::set_open_escrow_count(&env, &from, 7);
+    });
+
+    // The first call covers MAX_REPAIR_PAGES full pages and leaves the count alone.
+    let first = ESCROW_INDEX_PAGE_LEN * MAX_REPAIR_PAGES;
+    assert_eq!(client.repair_open_count(&admin, &from), (first, 1));
+    assert_eq!(client.open_escrow_count(&from), 7);
+
+    // The second call reads the last page and writes the total.
+    assert_eq!(client.repair_open_count(&admin, &from), (total, 0));
+    assert_eq!(client.open_escrow_count(&from), total);
+
+    // A fresh repair starts over from the first page.
+    assert_eq!(client.repair_open_count(&admin, &from), (first, 1));
+}
+
+// ============================================================================
+// Interest
+// ============================================================================
+
+#[test]
+fn test_interest_escrow_accrues_per_formula() {
+    let (env, client) = setup();
+    env.ledger().set_sequence_number(100);
+    let signing_key = SigningKey::from_bytes(&[9u8; 32]);
+    let from = account_for(&env, &signing_key);
+    let to = Address::generate(&env);
+    let amount = 1_000_000u64;
+    let bps = 5u32;
+    let id = client.create_escrow_with_interest(&from, &to, &amount, &bps);
+    assert_eq!(client.accrued_interest(&id), 0);
+
+    env.ledger().set_sequence_number(300);
+    let expected = amount * bps as u64 * 200 / 10_000;
+    assert_eq!(expected, 100_000);
+    assert_eq!(client.accrued_interest(&id), expected);
+
+    let receipt_hash = BytesN::from_array(&env, &[3u8; 32]);
+    let signature = sign(&env, &client, &signing_key, id, &receipt_hash);
+    client.release_with_receipt(&id, &receipt_hash, &signature, &from);
+
+    let escrow = client.get_escrow(&id).unwrap();
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.released_amount, amount + expected);
+    assert_eq!(client.accrued_interest(&id), 0);
+}
+
+#[test]
+fn test_interest_escrow_caps_total_interest() {
+    let (env, client) = setup();
+    let signing_key = SigningKey::from_bytes(&[10u8; 32]);
+    let from = account_for(&env, &signing_key);
+    let to = Address::generate(&env);
+    let id = client.create_escrow_with_interest(&from, &to, &u64::MAX, &u32::MAX);
+
+    // amount * bps * elapsed overflows u128; interest is capped at the principal.
+    env.ledger().set_sequence_number(1_000_000);
+    assert_eq!(client.accrued_interest(&id), u64::MAX);
+
+    let receipt_hash = BytesN::from_array(&env, &[4u8; 32]);
+    let signature = sign(&env, &client, &signing_key, id, &receipt_hash);
+    client.release_with_receipt(&id, &receipt_hash, &signature, &from);
+    assert_eq!(client.get_escrow(&id).unwrap().released_amount, u64::MAX);
+}
+
+#[test]
+fn test_plain_escrow_releases_principal_only() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let backer = Address::generate(&env);
+    let campaign_id = client.create_campaign(&owner);
+    let id = client.create_unfunded_escrow(&backer, &owner, &500);
+    client.add_escrow_to_campaign(&campaign_id, &id);
+
+    env.ledger().set_sequence_number(10_000);
+    client.release_campaign(&campaign_id, &owner);
+
+    assert_eq!(client.get_escrow(&id).unwrap().released_amount, 500);
+}
+
+// ============================================================================
+// Fee discount tiers
+// ============================================================================
+
+#[test]
+fn test_release_fee_depends_on_sender_privacy_level() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_escrow_fee_bps(&admin, &100);
+    client.set_fee_discount_bps(&admin, &3, &60);
+    assert_eq!(client.fee_discount_bps(&3), 60);
+    assert_eq!(client.effective_fee_bps(&0), 100);
+    assert_eq!(client.effective_fee_bps(&3), 40);
+
+    let plain = Address::generate(&env);
+    let private = Address::generate(&env);
+    let to = Address::generate(&env);
+    client.enable_privacy(&private, &3, &None);
+    let full_fee = client.create_unfunded_escrow(&plain, &to, &10_000);
+    let discounted = client.create_unfunded_escrow(&private, &to, &10_000);
+
+    client.release_escrow(&full_fee, &plain);
+    client.release_escrow(&discounted, &private);
+
+    let escrow = client.get_escrow(&full_fee).unwrap();
+    assert_eq!(escrow.fee_amount, 100);
+    assert_eq!(escrow.released_amount, 9_900);
+    let escrow = client.get_escrow(&discounted).unwrap();
+    assert_eq!(escrow.fee_amount, 40);
+    assert_eq!(escrow.released_amount, 9_960);
+}
+
+#[test]
+fn test_token_escrow_fee_is_tracked_and_collected_by_admin() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_escrow_fee_bps(&admin, &250);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = funded_token(&env, &from, 10_000);
+    let balances = token::Client::new(&env, &token);
+
+    let id = client.create_escrow(&from, &to, &10_000, &token);
+    client.release_escrow(&id, &from);
+
+    assert_eq!(balances.balance(&to), 9_750);
+    assert_eq!(balances.balance(&client.address), 250);
+    assert_eq!(client.collected_fees(&token), 250);
+
+    let result = client.try_collect_escrow_fees(&from, &token, &from);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+
+    assert_eq!(client.collect_escrow_fees(&admin, &token, &treasury), 250);
+    assert_eq!(balances.balance(&treasury), 250);
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(client.collected_fees(&token), 0);
+    assert_eq!(client.collect_escrow_fees(&admin, &token, &treasury), 0);
+}
+
+#[test]
+fn test_fee_settings_validated_and_admin_only() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.initialize(&admin);
+
+    let result = client.try_set_escrow_fee_bps(&admin, &10_001);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidFeeBps)));
+    let result = client.try_set_fee_discount_bps(&admin, &1, &10_001);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidFeeBps)));
+    let result = client.try_set_escrow_fee_bps(&stranger, &50);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+
+    // A discount larger than the fee floors the fee at zero.
+    client.set_escrow_fee_bps(&admin, &50);
+    client.set_fee_discount_bps(&admin, &2, &80);
+    assert_eq!(client.effective_fee_bps(&2), 0);
+}
+
+// ============================================================================
+// Hashlock escrows
+// ============================================================================
+
+#[test]
+fn test_claim_hashlock_with_correct_preimage_releases() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let preimage = Bytes::from_slice(&env, b"swap secret");
+    let hashlock: BytesN<32> = env.crypto().sha256(&preimage).into();
+    let id = client.create_hashlock_escrow(&from, &to, &1_000, &hashlock, &100);
+
+    client.claim_hashlock(&id, &preimage);
+    let escrow = client.get_escrow(&id).unwrap();
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.released_amount, 1_000);
+
+    let result = client.try_claim_hashlock(&id, &preimage);
+    assert_eq!(result, Err(Ok(QuickexError::AlreadySpent)));
+}
+
+#[test]
+fn test_claim_hashlock_with_wrong_preimage_reverts() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let preimage = Bytes::from_slice(&env, b"swap secret");
+    let hashlock: BytesN<32> = env.crypto().sha256(&preimage).into();
+    let id = client.create_hashlock_escrow(&from, &to, &1_000, &hashlock, &100);
+
+    let wrong = Bytes::from_slice(&env, b"swap secreT");
+    let result = client.try_claim_hashlock(&id, &wrong);
+    assert_eq!(result, Err(Ok(QuickexError::HashlockMismatch)));
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Pending
+    );
+
+    // Escrows without a hash lock cannot be claimed by preimage at all.
+    let plain = client.create_unfunded_escrow(&from, &to, &1_000);
+    let result = client.try_claim_hashlock(&plain, &preimage);
+    assert_eq!(result, Err(Ok(QuickexError::HashlockMismatch)));
+}
+
+#[test]
+fn test_refund_hashlock_only_after_timeout() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let preimage = Bytes::from_slice(&env, b"swap secret");
+    let hashlock: BytesN<32> = env.crypto().sha256(&preimage).into();
+    env.ledger().set_sequence_number(50);
+    let id = client.create_hashlock_escrow(&from, &to, &1_000, &hashlock, &100);
+
+    env.ledger().set_sequence_number(99);
+    let result = client.try_refund_hashlock(&id);
+    assert_eq!(result, Err(Ok(QuickexError::EscrowNotExpired)));
+
+    env.ledger().set_sequence_number(100);
+    let result = client.try_claim_hashlock(&id, &preimage);
+    assert_eq!(result, Err(Ok(QuickexError::EscrowExpired)));
+    client.refund_hashlock(&id);
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_refund_hashlock_blocked_once_claimed() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let preimage = Bytes::from_slice(&env, b"swap secret");
+    let hashlock: BytesN<32> = env.crypto().sha256(&preimage).into();
+    let id = client.create_hashlock_escrow(&from, &to, &1_000, &hashlock, &100);
+    client.claim_hashlock(&id, &preimage);
+
+    env.ledger().set_sequence_number(100);
+    let result = client.try_refund_hashlock(&id);
+    assert_eq!(result, Err(Ok(QuickexError::AlreadySpent)));
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Released
+    );
+
+    let result = client.try_create_hashlock_escrow(&from, &to, &1_000, &hashlock, &100);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidExpiry)));
+}
+
+// ============================================================================
+// cancel_escrow
+// ============================================================================
+
+#[test]
+fn test_cancel_escrow_by_recipient_before_expiry() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let expiry_ledger = env.ledger().sequence() + 10;
+    let id = client.create_escrow_with_expiry(&from, &to, &100, &expiry_ledger);
+
+    client.cancel_escrow(&id, &to);
+
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Cancelled
+    );
+    let again = client.try_cancel_escrow(&id, &to);
+    assert_eq!(again, Err(Ok(QuickexError::AlreadySpent)));
+}
+
+#[test]
+fn test_cancel_escrow_by_sender_blocked_before_expiry() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let expiry_ledger = env.ledger().sequence() + 10;
+    let expiring = client.create_escrow_with_expiry(&from, &to, &100, &expiry_ledger);
+    let open_ended = client.create_unfunded_escrow(&from, &to, &100);
+
+    env.ledger().set_sequence_number(expiry_ledger - 1);
+    let result = client.try_cancel_escrow(&expiring, &from);
+    assert_eq!(result, Err(Ok(QuickexError::EscrowNotExpired)));
+    let result = client.try_cancel_escrow(&open_ended, &from);
+    assert_eq!(result, Err(Ok(QuickexError::EscrowNotExpired)));
+}
+
+#[test]
+fn test_cancel_escrow_by_either_party_after_expiry() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let expiry_ledger = env.ledger().sequence() + 10;
+    let by_sender = client.create_escrow_with_expiry(&from, &to, &100, &expiry_ledger);
+    let by_recipient = client.create_escrow_with_expiry(&from, &to, &100, &expiry_ledger);
+
+    env.ledger().set_sequence_number(expiry_ledger);
+    client.cancel_escrow(&by_sender, &from);
+    client.cancel_escrow(&by_recipient, &to);
+
+    assert_eq!(
+        client.get_escrow(&by_sender).unwrap().status,
+        EscrowStatus::Cancelled
+    );
+    assert_eq!(
+        client.get_escrow(&by_recipient).unwrap().status,
+        EscrowStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_cancel_escrow_by_stranger_fails() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &to, &100);
+
+    let result = client.try_cancel_escrow(&id, &stranger);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+}
+
+#[test]
+fn test_cancel_escrow_rebate_only_within_grace_window() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_cancel_rebate(&admin, &5, &10);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let token = funded_token(&env, &from, 200);
+    token::StellarAssetClient::new(&env, &token).mint(&admin, &50);
+    client.fund_rebate_pool(&admin, &token, &50);
+    let balances = token::Client::new(&env, &token);
+    env.ledger().set_sequence_number(100);
+    let quick = client.create_escrow(&from, &to, &100, &token);
+    let slow = client.create_escrow(&from, &to, &100, &token);
+
+    env.ledger().set_sequence_number(109);
+    client.cancel_escrow(&quick, &to);
+    assert_eq!(balances.balance(&from), 105);
+    assert_eq!(client.rebate_pool(&token), 45);
+
+    env.ledger().set_sequence_number(110);
+    client.cancel_escrow(&slow, &to);
+    assert_eq!(balances.balance(&from), 205);
+    assert_eq!(client.rebate_pool(&token), 45);
+    assert_eq!(balances.balance(&to), 0);
+}
+
+#[test]
+fn test_cancel_escrow_rebate_bounded_by_pool() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_cancel_rebate(&admin, &5, &10);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let token = funded_token(&env, &from, 300);
+    client.fund_rebate_pool(&from, &token, &7);
+    let balances = token::Client::new(&env, &token);
+
+    for _ in 0..3 {
+        let id = client.create_escrow(&from, &to, &50, &token);
+        client.cancel_escrow(&id, &to);
+    }
+    // The pool of 7 covers one full rebate and 2 of the next; then it is empty.
+    assert_eq!(balances.balance(&from), 300);
+    assert_eq!(client.rebate_pool(&token), 0);
+
+    // Tokenless escrows never draw a rebate.
+    let id = client.create_unfunded_escrow(&from, &to, &50);
+    client.cancel_escrow(&id, &to);
+    assert_eq!(balances.balance(&from), 300);
+
+    let result = client.try_fund_rebate_pool(&from, &token, &0);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidAmount)));
+}
+
+#[test]
+fn test_cancel_escrow_rebate_capped_by_escrowed_amount() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_cancel_rebate(&admin, &5, &10);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let token = funded_token(&env, &from, 100);
+    token::StellarAssetClient::new(&env, &token).mint(&admin, &50);
+    client.fund_rebate_pool(&admin, &token, &50);
+    let balances = token::Client::new(&env, &token);
+
+    // A zero-amount escrow cannot be opened to farm the rebate.
+    let result = client.try_create_escrow(&from, &to, &0, &token);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidAmount)));
+    let result = client.try_create_unfunded_escrow(&from, &to, &0);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidAmount)));
+
+    // The rebate is capped at MAX_CANCEL_REBATE_BPS of the escrowed amount: 10% of 20.
+    let id = client.create_escrow(&from, &to, &20, &token);
+    client.cancel_escrow(&id, &to);
+    assert_eq!(balances.balance(&from), 102);
+    assert_eq!(client.rebate_pool(&token), 48);
+
+    // Below 10 base units the cap rounds down to nothing.
+    let id = client.create_escrow(&from, &to, &9, &token);
+    client.cancel_escrow(&id, &to);
+    assert_eq!(balances.balance(&from), 102);
+    assert_eq!(client.rebate_pool(&token), 48);
+}
+
+#[test]
+fn test_set_cancel_rebate_requires_admin() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let token = funded_token(&env, &from, 150);
+    client.fund_rebate_pool(&from, &token, &50);
+
+    let result = client.try_set_cancel_rebate(&from, &5, &10);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+
+    // Without a configured rebate nothing is paid.
+    let id = client.create_escrow(&from, &to, &100, &token);
+    client.cancel_escrow(&id, &to);
+    assert_eq!(token::Client::new(&env, &token).balance(&from), 100);
+    assert_eq!(client.rebate_pool(&token), 50);
+}
+
+// ============================================================================
+// Disputes / admin_force_resolve
+// ============================================================================
+
+#[test]
+fn test_dispute_escrow_blocks_normal_release() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &to, &500);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_dispute_escrow(&id, &outsider);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+
+    client.dispute_escrow(&id, &to);
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Disputed
+    );
+    assert_eq!(client.total_escrowed(), 500);
+    let result = client.try_release_escrow(&id, &from);
+    assert_eq!(result, Err(Ok(QuickexError::AlreadySpent)));
+    let result = client.try_cancel_escrow(&id, &from);
+    assert_eq!(result, Err(Ok(QuickexError::AlreadySpent)));
+}
+
+#[test]
+fn test_admin_force_resolve_disputed_escrow_to_each_party() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    client.initialize(&admin);
+
+    let refunded = client.create_unfunded_escrow(&from, &to, &300);
+    client.dispute_escrow(&refunded, &from);
+    client.admin_force_resolve(&admin, &refunded, &true);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected = EscrowForceResolvedEvent {
+        id: refunded,
+        admin: admin.clone(),
+        refunded_to_sender: true,
+        timestamp: env.ledger().timestamp(),
+    };
+    assert_eq!(topics, expected.topics(&env));
+    let data: Map<Symbol, Val> = data.into_val(&env);
+    let flag: bool = data
+        .get(Symbol::new(&env, "refunded_to_sender"))
+        .unwrap()
+        .into_val(&env);
+    assert!(flag);
+    let escrow = client.get_escrow(&refunded).unwrap();
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.released_amount, 0);
+
+    let released = client.create_unfunded_escrow(&from, &to, &400);
+    client.dispute_escrow(&released, &to);
+    client.admin_force_resolve(&admin, &released, &false);
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected = EscrowForceResolvedEvent {
+        id: released,
+        admin: admin.clone(),
+        refunded_to_sender: false,
+        timestamp: env.ledger().timestamp(),
+    };
+    assert_eq!(topics, expected.topics(&env));
+    let escrow = client.get_escrow(&released).unwrap();
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.released_amount, 400);
+    assert_eq!(client.total_escrowed(), 0);
+
+    let result = client.try_admin_force_resolve(&admin, &released, &true);
+    assert_eq!(result, Err(Ok(QuickexError::AlreadySpent)));
+}
+
+#[test]
+fn test_resolve_dispute_timeout_refunds_after_deadline() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_dispute_deadline_ledgers(&admin, &100);
+    env.ledger().set_sequence_number(1_000);
+    let id = client.create_unfunded_escrow(&from, &to, &600);
+
+    let result = client.try_resolve_dispute_timeout(&id, &to);
+    assert_eq!(result, Err(Ok(QuickexError::EscrowNotDisputed)));
+
+    client.dispute_escrow(&id, &from);
+    env.ledger().set_sequence_number(1_099);
+    let result = client.try_resolve_dispute_timeout(&id, &to);
+    assert_eq!(result, Err(Ok(QuickexError::DisputeWindowOpen)));
+    let result = client.try_resolve_dispute_timeout(&id, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+
+    env.ledger().set_sequence_number(1_100);
+    client.resolve_dispute_timeout(&id, &to);
+    let escrow = client.get_escrow(&id).unwrap();
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(client.total_escrowed(), 0);
+}
+
+#[test]
+fn test_dispute_deadline_defaults_and_requires_admin() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    assert_eq!(client.dispute_deadline_ledgers(), 120_960);
+
+    let result = client.try_set_dispute_deadline_ledgers(&Address::generate(&env), &10);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+    client.set_dispute_deadline_ledgers(&admin, &10);
+    assert_eq!(client.dispute_deadline_ledgers(), 10);
+}
+
+#[test]
+fn test_change_arbiter_with_both_parties() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &to, &1_000);
+    assert_eq!(client.arbiter_of(&id), Some(admin.clone()));
+
+    client.change_arbiter(&id, &arbiter);
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(a, _)| a).collect();
+    assert_eq!(signers, std::vec![from.clone(), to.clone()]);
+    assert_eq!(client.arbiter_of(&id), Some(arbiter.clone()));
+
+    client.dispute_escrow(&id, &to);
+    let result = client.try_resolve_dispute(&admin, &id, &false);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+    client.resolve_dispute(&arbiter, &id, &false);
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Released
+    );
+}
+
+#[test]
+fn test_change_arbiter_rejects_single_party() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &to, &1_000);
+
+    env.mock_auths(&[MockAuth {
+        address: &from,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "change_arbiter",
+            args: (id, arbiter.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_change_arbiter(&id, &arbiter).is_err());
+    assert_eq!(client.arbiter_of(&id), None);
+}
+
+#[test]
+fn test_change_arbiter_rejected_once_resolved() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &to, &1_000);
+    client.dispute_escrow(&id, &from);
+    client.resolve_dispute(&admin, &id, &true);
+
+    let result = client.try_change_arbiter(&id, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(QuickexError::AlreadySpent)));
+    let result = client.try_resolve_dispute(&admin, &id, &true);
+    assert_eq!(result, Err(Ok(QuickexError::EscrowNotDisputed)));
+}
+
+#[test]
+fn test_admin_force_resolve_requires_admin() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    client.initialize(&admin);
+    let id = client.create_escrow_with_min_lock(&from, &to, &500, &1_000);
+
+    let result = client.try_admin_force_resolve(&from, &id, &false);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+
+    // Release conditions such as the minimum lock do not apply.
+    client.admin_force_resolve(&admin, &id, &false);
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Released
+    );
+}
+
+// ============================================================================
+// Expiry refunds
+// ============================================================================
+
+#[test]
+fn test_create_escrow_with_expiry_rejects_past_ledger() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    env.ledger().set_sequence_number(100);
+
+    let result = client.try_create_escrow_with_expiry(&from, &to, &500, &100);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidExpiry)));
+}
+
+#[test]
+fn test_claim_expired_refunds_after_expiry() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    env.ledger().set_sequence_number(100);
+    let id = client.create_escrow_with_expiry(&from, &to, &500, &150);
+
+    env.ledger().set_sequence_number(149);
+    let early = client.try_claim_expired(&id);
+    assert_eq!(early, Err(Ok(QuickexError::EscrowNotExpired)));
+
+    env.ledger().set_sequence_number(150);
+    client.claim_expired(&id);
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Refunded
+    );
+
+    let again = client.try_claim_expired(&id);
+    assert_eq!(again, Err(Ok(QuickexError::AlreadySpent)));
+}
+
+#[test]
+fn test_claim_expired_rejects_non_expiring_escrow() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let id = client.create_unfunded_escrow(&from, &to, &500);
+
+    env.ledger().set_sequence_number(1_000_000);
+    let result = client.try_claim_expired(&id);
+    assert_eq!(result, Err(Ok(QuickexError::EscrowNotExpired)));
+}
+
+#[test]
+fn test_mark_expired_then_claim_expired_funds() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    env.ledger().set_sequence_number(100);
+    let id = client.create_escrow_with_expiry(&from, &to, &500, &150);
+
+    env.ledger().set_sequence_number(149);
+    let early = client.try_mark_expired(&id);
+    assert_eq!(early, Err(Ok(QuickexError::EscrowNotExpired)));
+
+    env.ledger().set_sequence_number(150);
+    client.mark_expired(&id);
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Expired
+    );
+    assert_eq!(client.total_escrowed(), 500);
+    assert_eq!(client.due_refunds(&100, &150, &10).0.len(), 0);
+
+    client.claim_expired_funds(&id);
+    assert_eq!(env.auths()[0].0, from);
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(client.total_escrowed(), 0);
+    assert_eq!(client.open_escrow_count(&from), 0);
+
+    let again = client.try_claim_expired_funds(&id);
+    assert_eq!(again, Err(Ok(QuickexError::AlreadySpent)));
+}
+
+#[test]
+fn test_claim_expired_funds_requires_mark_expired() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    env.ledger().set_sequence_number(100);
+    let id = client.create_escrow_with_expiry(&from, &to, &500, &150);
+
+    env.ledger().set_sequence_number(200);
+    let result = client.try_claim_expired_funds(&id);
+    assert_eq!(result, Err(Ok(QuickexError::EscrowNotExpired)));
+    assert_eq!(
+        client.get_escrow(&id).unwrap().status,
+        EscrowStatus::Pending
+    );
+
+    let unknown = client.try_claim_expired_funds(&99);
+    assert_eq!(unknown, Err(Ok(QuickexError::EscrowNotFound)));
+}
+
+#[test]
+fn test_due_refunds_returns_only_due_pending_escrows() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    env.ledger().set_sequence_number(10);
+
+    // Created out of expiry order to exercise ordering by expiry ledger.
+    let at_200 = client.create_escrow_with_expiry(&from, &to, &1, &200);
+    let at_100 = client.create_escrow_with_expiry(&from, &to, &1, &100);
+    let at_150 = client.create_escrow_with_expiry(&from, &to, &1, &150);
+    let also_150 = client.create_escrow_with_expiry(&from, &to, &1, &150);
+    let _no_expiry = client.create_unfunded_escrow(&from, &to, &1);
+
+    assert_eq!(
+        client.due_refunds(&100, &200, &10),
+        (vec![&env, at_100, at_150, also_150, at_200], 201)
+    );
+    assert_eq!(client.due_refunds(&10, &99, &10), (vec![&env], 100));
+
+    // The limit stops the scan after the ledger that reaches it, keeping the ledger whole.
+    assert_eq!(
+        client.due_refunds(&100, &200, &2),
+        (vec![&env, at_100, at_150, also_150], 151)
+    );
+    assert_eq!(
+        client.due_refunds(&151, &200, &2),
+        (vec![&env, at_200], 201)
+    );
+
+    // Settled escrows drop out of the work list.
+    env.ledger().set_sequence_number(160);
+    client.claim_expired(&at_100);
+    assert_eq!(
+        client.due_refunds(&100, &200, &10),
+        (vec![&env, at_150, also_150, at_200], 201)
+    );
+}
+
+#[test]
+fn test_due_refunds_reads_bounded_ledger_window() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    env.ledger().set_sequence_number(10);
+    let at_100 = client.create_escrow_with_expiry(&from, &to, &1, &100);
+    let at_300 = client.create_escrow_with_expiry(&from, &to, &1, &300);
+
+    let (first, next) = client.due_refunds(&100, &300, &10);
+    assert_eq!((first, next), (vec![&env, at_100], 228));
+    let (second, next) = client.due_refunds(&next, &300, &10);
+    assert_eq!((second, next), (vec![&env, at_300], 301));
+}
+
+#[test]
+fn test_expiry_ledger_holds_bounded_number_of_escrows() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    env.ledger().set_sequence_number(10);
+    let first = client.create_escrow_with_expiry(&from, &to, &1, &100);
+    for _ in 1..MAX_EXPIRY_BUCKET_LEN {
+        client.create_escrow_with_expiry(&from, &to, &1, &100);
+    }
+
+    let result = client.try_create_escrow_with_expiry(&from, &to, &1, &100);
+    assert_eq!(result, Err(Ok(QuickexError::ExpiryLedgerFull)));
+    client.create_escrow_with_expiry(&from, &to, &1, &101);
+
+    // Settling an escrow frees its slot.
+    client.cancel_escrow(&first, &to);
+    client.create_escrow_with_expiry(&from, &to, &1, &100);
+}
+
+#[test]
+fn test_sweep_expired_emits_events_only_for_refunded_escrows() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    env.ledger().set_sequence_number(10);
+    let at_100 = client.create_escrow_with_expiry(&from, &to, &100, &100);
+    let at_120 = client.create_escrow_with_expiry(&from, &to, &120, &120);
+    let not_due = client.create_escrow_with_expiry(&from, &to, &500, &500);
+    let cancelled = client.create_escrow_with_expiry(&from, &to, &90, &90);
+    client.cancel_escrow(&cancelled, &to);
+
+    env.ledger().set_sequence_number(150);
+    assert_eq!(client.sweep_expired(&10), vec![&env, at_100, at_120]);
+
+    let expired: std::vec::Vec<_> = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            let name: Symbol = topics.get(0).unwrap().into_val(&env);
+            name == Symbol::new(&env, "EscrowExpired")
+        })
+        .collect();
+    assert_eq!(expired.len(), 2);
+    for ((_, topics, data), (id, amount)) in expired.iter().zip([(at_100, 100u64), (at_120, 120)]) {
+        let expected = EscrowExpiredEvent {
+            id,
+            refunded_amount: amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        assert_eq!(*topics, expected.topics(&env));
+        let data: Map<Symbol, Val> = data.into_val(&env);
+        let refunded: u64 = data
+            .get(Symbol::new(&env, "refunded_amount"))
+            .unwrap()
+            .into_val(&env);
+        assert_eq!(refunded, amount);
+    }
+
+    assert_eq!(
+        client.get_escrow(&not_due).unwrap().status,
+        EscrowStatus::Pending
+    );
+    assert_eq!(client.sweep_expired(&10), vec![&env]);
+    assert!(env.events().all().is_empty());
+}
+
+#[test]
+fn test_sweep_expired_resumes_from_cursor() {
+    let (env, client) = setup();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    env.ledger().set_sequence_number(10);
+    assert_eq!(client.sweep_expired(&10), vec![&env]);
+    let at_100 = client.create_escrow_with_expiry(&from, &to, &1, &100);
+    let at_400 = client.create_escrow_with_expiry(&from, &to, &1, &400);
+
+    env.ledger().set_sequence_number(500);
+    // The first call reads ledgers 100..=227, the next two walk the gap up to 400.
+    assert_eq!(client.sweep_expired(&10), vec![&env, at_100]);
+    assert_eq!(client.sweep_expired(&10), vec![&env]);
+    assert_eq!(client.sweep_expired(&10), vec![&env, at_400]);
+    assert_eq!(
+        client.get_escrow(&at_400).unwrap().status,
+        EscrowStatus::Refunded
+    );
+
+    // Escrows created after the cursor passed are still picked up.
+    let at_600 = client.create_escrow_with_expiry(&from, &to, &1, &600);
+    env.ledger().set_sequence_number(600);
+    assert_eq!(client.sweep_expired(&10), vec![&env, at_600]);
+}
+
+// ============================================================================
+// Campaigns
+// ============================================================================
+
+#[test]
+fn test_campaign_groups_escrows() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let to = Address::generate(&env);
+    let backer_a = Address::generate(&env);
+    let backer_b = Address::generate(&env);
+
+    let campaign_id = client.create_campaign(&owner);
+    let first = client.create_unfunded_escrow(&backer_a, &to, &100);
+    let second = client.create_unfunded_escrow(&backer_b, &to, &200);
+    let outside = client.create_unfunded_escrow(&backer_a, &to, &300);
+
+    assert_eq!(client.campaign_escrows(&campaign_id), vec![&env]);
+
+    client.add_escrow_to_campaign(&campaign_id, &first);
+    client.add_escrow_to_campaign(&campaign_id, &second);
+
+    assert_eq!(
+        client.campaign_escrows(&campaign_id),
+        vec![&env, first, second]
+    );
+    assert_eq!(client.get_escrow(&first).unwrap().campaign_id, campaign_id);
+    assert_eq!(client.get_escrow(&outside).unwrap().campaign_id, 0);
+}
+
+#[test]
+fn test_campaign_ids_are_sequential() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+
+    assert_eq!(client.create_campaign(&owner), 1);
+    assert_eq!(client.create_campaign(&owner), 2);
+    assert_eq!(client.campaign_escrows(&99), vec![&env]);
+}
+
+#[test]
+fn test_add_escrow_to_campaign_rejects_invalid_targets() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let campaign_id = client.create_campaign(&owner);
+    let other_campaign = client.create_campaign(&owner);
+    let escrow_id = client.create_unfunded_escrow(&from, &to, &100);
+
+    let unknown_campaign = client.try_add_escrow_to_campaign(&99, &escrow_id);
+    assert_eq!(unknown_campaign, Err(Ok(QuickexError::CampaignNotFound)));
+
+    let unknown_escrow = client.try_add_escrow_to_campaign(&campaign_id, &99);
+    assert_eq!(unknown_escrow, Err(Ok(QuickexError::EscrowNotFound)));
+
+    client.add_escrow_to_campaign(&campaign_id, &escrow_id);
+    let moved = client.try_add_escrow_to_campaign(&other_campaign, &escrow_id);
+    assert_eq!(moved, Err(Ok(QuickexError::AlreadyInCampaign)));
+}
+
+#[test]
+fn test_release_campaign_skips_terminal_escrows() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let to = Address::generate(&env);
+    let backer = Address::generate(&env);
+    let campaign_id = client.create_campaign(&owner);
+
+    let first = client.create_unfunded_escrow(&backer, &to, &100);
+    let cancelled = client.create_unfunded_escrow(&backer, &to, &200);
+    let third = client.create_unfunded_escrow(&backer, &to, &300);
+    for id in [first, cancelled, third] {
+        client.add_escrow_to_campaign(&campaign_id, &id);
+    }
+    client.cancel_escrow(&cancelled, &to);
+
+    assert_eq!(client.release_campaign(&campaign_id, &owner), 2);
+
+    assert_eq!(
+        client.get_escrow(&first).unwrap().status,
+        EscrowStatus::Released
+    );
+    assert_eq!(
+        client.get_escrow(&cancelled).unwrap().status,
+        EscrowStatus::Cancelled
+    );
+    assert_eq!(
+        client.get_escrow(&third).unwrap().status,
+        EscrowStatus::Released
+    );
+
+    // Nothing left to release.
+    assert_eq!(client.release_campaign(&campaign_id, &owner), 0);
+}
+
+#[test]
+fn test_release_campaign_by_admin() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let backer = Address::generate(&env);
+    client.initialize(&admin);
+    let campaign_id = client.create_campaign(&owner);
+    let id = client.create_unfunded_escrow(&backer, &owner, &100);
+    client.add_escrow_to_campaign(&campaign_id, &id);
+
+    assert_eq!(client.release_campaign(&campaign_id, &admin), 1);
+}
+
+#[test]
+fn test_release_campaign_by_stranger_fails() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let campaign_id = client.create_campaign(&owner);
+
+    let result = client.try_release_campaign(&campaign_id, &stranger);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+
+    let unknown = client.try_release_campaign(&99, &owner);
+    assert_eq!(unknown, Err(Ok(QuickexError::CampaignNotFound)));
+}
+
+// ============================================================================
+// export_escrows
+// ============================================================================
+
+#[test]
+fn test_export_escrows_returns_sub_range() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    client.initialize(&admin);
+    for amount in 1..=5u64 {
+        client.create_unfunded_escrow(&from, &to, &(amount * 100));
+    }
+
+    let exported = client.export_escrows(&admin, &2, &4);
+
+    assert_eq!(exported.len(), 2);
+    assert_eq!(exported.get(0).unwrap(), client.get_escrow(&2).unwrap());
+    assert_eq!(exported.get(1).unwrap(), client.get_escrow(&3).unwrap());
+    assert!(exported.iter().all(|escrow| (2..4).contains(&escrow.id)));
+}
+
+#[test]
+fn test_export_escrows_skips_missing_ids() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    client.initialize(&admin);
+    client.create_unfunded_escrow(&from, &to, &100);
+
+    let exported = client.export_escrows(&admin, &0, &50);
+    assert_eq!(exported.len(), 1);
+    assert_eq!(exported.get(0).unwrap().id, 1);
+}
+
+#[test]
+fn test_export_escrows_rejects_bad_range_and_non_admin() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.initialize(&admin);
+
+    let inverted = client.try_export_escrows(&admin, &5, &1);
+    assert_eq!(inverted, Err(Ok(QuickexError::InvalidRange)));
+
+    let too_wide = client.try_export_escrows(&admin, &1, &102);
+    assert_eq!(too_wide, Err(Ok(QuickexError::InvalidRange)));
+
+    let result = client.try_export_escrows(&stranger, &1, &2);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+}
@@ -1,5 +1,5 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Vec};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Map, Symbol, Vec};
 
 mod admin;
 mod commitment;
@@ -8,17 +8,36 @@ mod commitment_test;
 mod errors;
 mod escrow;
 mod events;
+mod id_escrow;
+#[cfg(test)]
+mod id_escrow_test;
+mod merkle;
+#[cfg(test)]
+mod merkle_test;
+mod nullifier;
+#[cfg(test)]
+mod nullifier_test;
 mod privacy;
+#[cfg(test)]
+mod privacy_test;
+mod receiver;
+mod registry;
 mod storage;
 #[cfg(test)]
 mod storage_test;
 #[cfg(test)]
 mod test;
 mod types;
+mod voting;
+#[cfg(test)]
+mod voting_test;
 
 use errors::QuickexError;
 use storage::*;
-use types::{EscrowEntry, EscrowStatus, PrivacyAwareEscrowView};
+use types::{
+    AccountPrivacy, CommitmentParams, ContractConfig, EscrowData, EscrowEntry, EscrowStatus,
+    PrivacyAwareEscrowView,
+};
 
 /// QuickEx Privacy Contract
 ///
@@ -35,195 +54,2866 @@ use types::{EscrowEntry, EscrowStatus, PrivacyAwareEscrowView};
 #[contract]
 pub struct QuickexContract;
 
-#[contractimpl]
-impl QuickexContract {
-    /// Withdraw escrowed funds by proving commitment ownership.
+#[contractimpl]
+impl QuickexContract {
+    /// Withdraw escrowed funds by proving commitment ownership.
+    ///
+    /// The caller (`to`) must authorize; the commitment is recomputed from `to`, `amount`, and `salt`
+    /// and must match an existing pending escrow entry.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `_token` - Reserved; token is stored in the escrow entry
+    /// * `amount` - Amount to withdraw; must be positive and match the escrow amount
+    /// * `commitment` - Commitment hash for the escrow being withdrawn
+    /// * `to` - Recipient address (must authorize the call)
+    /// * `salt` - Salt used when creating the original deposit commitment
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - Amount is zero or negative
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `CommitmentMismatch` - Provided commitment does not match (`to`, `amount`, `salt`)
+    /// * `CommitmentNotFound` - No escrow exists for the provided commitment
+    /// * `EscrowExpired` - Escrow has passed its expiry timestamp
+    /// * `AlreadySpent` - Escrow has already been withdrawn or refunded
+    /// * `InvalidCommitment` - Escrow amount does not match the requested amount
+    pub fn withdraw(
+        env: Env,
+        _token: &Address,
+        amount: i128,
+        _commitment: BytesN<32>,
+        to: Address,
+        salt: Bytes,
+    ) -> Result<bool, QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        escrow::withdraw(&env, amount, to, salt)
+    }
+
+    /// Withdraw a commitment-keyed escrow if its opened amount reaches `minimum`.
+    ///
+    /// Checks `amount >= minimum`, then pays out exactly like
+    /// [`withdraw`](QuickexContract::withdraw), which locates the escrow by
+    /// recomputing its commitment from (`to`, `amount`, `salt`).
+    ///
+    /// This gives the recipient a floor, not amount privacy: `amount` is a plain
+    /// argument of the transaction and the token transfer event carries it. Only the
+    /// QuickEx withdrawal event omits it.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `_commitment` - Reserved; the escrow is located from the opening
+    /// * `to` - Recipient address (must authorize the call)
+    /// * `amount` - Opened amount
+    /// * `salt` - Salt used when creating the deposit commitment
+    /// * `minimum` - Smallest acceptable amount
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `AmountBelowMinimum` - `amount` is below `minimum`
+    /// * `CommitmentNotFound` - No escrow exists for the opening
+    /// * `EscrowExpired` - Escrow has passed its expiry timestamp
+    /// * `AlreadySpent` - Escrow has already been withdrawn or refunded
+    pub fn settle_at_least(
+        env: Env,
+        _commitment: BytesN<32>,
+        to: Address,
+        amount: i128,
+        salt: Bytes,
+        minimum: i128,
+    ) -> Result<(), QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        escrow::settle_at_least(&env, to, amount, salt, minimum)
+    }
+
+    /// Set a numeric privacy level for an account (legacy/level-based API).
+    ///
+    /// Records the level in storage and appends it to the account's privacy history.
+    /// For boolean on/off privacy, prefer [`set_privacy`](QuickexContract::set_privacy).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `account` - The account to configure (must authorize unless `operator` is given)
+    /// * `privacy_level` - Numeric level (0 = off, higher = more privacy; interpretation is application-specific)
+    /// * `operator` - Operator acting for `account` (must authorize and be registered via
+    ///   [`set_operator`](QuickexContract::set_operator))
+    ///
+    /// Emits `PrivacyLevelSet` with the level when the account enabled verbose events via
+    /// [`set_event_verbosity`](QuickexContract::set_event_verbosity), or an account-only
+    /// `PrivacyLevelSetRedacted` event otherwise.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `operator` is not the account's registered operator
+    /// * `InvalidPrivacyLevel` - `privacy_level` is `u32::MAX - 1` or `u32::MAX`, which the
+    ///   history reserves for redactions and revocations
+    /// * `PrivacySealed` - The account sealed its privacy settings
+    /// * `PrivacyFrozen` - The admin froze the account's privacy settings
+    pub fn enable_privacy(
+        env: Env,
+        account: Address,
+        privacy_level: u32,
+        operator: Option<Address>,
+    ) -> Result<(), QuickexError> {
+        privacy::enable_privacy(&env, &account, privacy_level, operator)
+    }
+
+    /// Register an operator allowed to change an account's privacy settings for it.
+    ///
+    /// Pass `None` to remove the current operator.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `account` - The account delegating (must authorize)
+    /// * `operator` - The operator to register, or `None`
+    pub fn set_operator(env: Env, account: Address, operator: Option<Address>) {
+        admin::set_operator(&env, &account, operator)
+    }
+
+    /// Get the operator registered for an account (read-only).
+    pub fn operator_of(env: Env, account: Address) -> Option<Address> {
+        admin::operator_of(&env, &account)
+    }
+
+    /// Choose how much detail an account's privacy level events carry.
+    ///
+    /// Accounts start redacted: their events name the account but not the level.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `account` - The account to configure (must authorize)
+    /// * `verbose` - Include the level in events if `true`
+    pub fn set_event_verbosity(env: Env, account: Address, verbose: bool) {
+        privacy::set_event_verbosity(&env, &account, verbose)
+    }
+
+    /// Check whether an account's privacy level events include the level (read-only).
+    pub fn event_verbosity(env: Env, account: Address) -> bool {
+        privacy::event_verbosity(&env, &account)
+    }
+
+    /// Set a numeric privacy level at most once per idempotency key.
+    ///
+    /// The first call with `idem_key` behaves like
+    /// [`enable_privacy`](QuickexContract::enable_privacy) and records its result; later
+    /// calls with the same key return that result without re-applying the level or
+    /// adding a history entry.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `account` - The account to configure (must authorize)
+    /// * `privacy_level` - Numeric level to set
+    /// * `idem_key` - Client-chosen key identifying this logical request
+    pub fn enable_privacy_idempotent(
+        env: Env,
+        account: Address,
+        privacy_level: u32,
+        idem_key: BytesN<32>,
+    ) -> bool {
+        privacy::enable_privacy_idempotent(&env, &account, privacy_level, &idem_key)
+    }
+
+    /// Register the ed25519 key allowed to sign privacy changes for an account.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `account` - The account to configure (must authorize)
+    /// * `pubkey` - Ed25519 public key accepted by [`enable_privacy_sig`](QuickexContract::enable_privacy_sig)
+    pub fn set_privacy_signer(env: Env, account: Address, pubkey: BytesN<32>) {
+        privacy::set_privacy_signer_key(&env, &account, pubkey)
+    }
+
+    /// Get the nonce the next signed privacy change for an account must carry.
+    pub fn privacy_nonce(env: Env, account: Address) -> u64 {
+        privacy::privacy_nonce(&env, &account)
+    }
+
+    /// Build the canonical message to sign for setting `account`'s privacy level to `level`.
+    ///
+    /// The message is `XDR(account) || BE32(level) || BE64(nonce)` with the account's
+    /// current nonce.
+    pub fn privacy_sig_message(env: Env, account: Address, level: u32) -> Bytes {
+        let nonce = privacy::privacy_nonce(&env, &account);
+        privacy::privacy_sig_message(&env, &account, level, nonce)
+    }
+
+    /// Set a numeric privacy level authorized by an ed25519 signature.
+    ///
+    /// For smart-wallet accounts that do not use native `require_auth`. The signature
+    /// must cover the canonical message from
+    /// [`privacy_sig_message`](QuickexContract::privacy_sig_message); the account's nonce
+    /// is bumped on success, so each signature applies once.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `account` - The account to configure
+    /// * `level` - Numeric privacy level
+    /// * `message` - Canonical message that was signed
+    /// * `signature` - 64-byte ed25519 signature over `message`
+    /// * `pubkey` - The account's registered privacy signer
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `pubkey` is not the account's registered signer
+    /// * `InvalidSignature` - `message` is not canonical or `signature` is malformed
+    /// * `InvalidPrivacyLevel` - `level` is `u32::MAX - 1` or `u32::MAX`
+    /// * `PrivacySealed` - The account sealed its privacy settings
+    /// * `PrivacyFrozen` - The admin froze the account's privacy settings
+    ///
+    /// A well-formed but invalid signature reverts the call.
+    pub fn enable_privacy_sig(
+        env: Env,
+        account: Address,
+        level: u32,
+        message: Bytes,
+        signature: Bytes,
+        pubkey: BytesN<32>,
+    ) -> Result<(), QuickexError> {
+        privacy::enable_privacy_sig(&env, &account, level, message, signature, pubkey)
+    }
+
+    /// Let `viewer` see `owner`'s private data.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The data owner (must authorize)
+    /// * `viewer` - The address granted access
+    pub fn grant_viewer(env: Env, owner: Address, viewer: Address) {
+        privacy::grant_viewer(&env, &owner, &viewer)
+    }
+
+    /// Withdraw a viewer grant made with [`grant_viewer`](QuickexContract::grant_viewer).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The data owner (must authorize)
+    /// * `viewer` - The address losing access
+    pub fn revoke_viewer(env: Env, owner: Address, viewer: Address) {
+        privacy::revoke_viewer(&env, &owner, &viewer)
+    }
+
+    /// Check whether `viewer` may see `owner`'s private data (read-only).
+    ///
+    /// Requires a grant from `owner`; a viewer on the admin blocklist is always denied.
+    pub fn can_view(env: Env, owner: Address, viewer: Address) -> bool {
+        privacy::can_view(&env, &owner, &viewer)
+    }
+
+    /// Block a viewer for every owner (**Admin only**).
+    ///
+    /// Overrides all per-owner grants until [`unblock_viewer`](QuickexContract::unblock_viewer).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Caller address (must equal admin; must authorize)
+    /// * `viewer` - The viewer to block
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    pub fn block_viewer(env: Env, caller: Address, viewer: Address) -> Result<(), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        privacy::set_blocked(&env, &viewer, true);
+        Ok(())
+    }
+
+    /// Remove a viewer from the admin blocklist (**Admin only**).
+    ///
+    /// Existing per-owner grants take effect again.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Caller address (must equal admin; must authorize)
+    /// * `viewer` - The viewer to unblock
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    pub fn unblock_viewer(env: Env, caller: Address, viewer: Address) -> Result<(), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        privacy::set_blocked(&env, &viewer, false);
+        Ok(())
+    }
+
+    /// Revoke the numeric privacy level of an account.
+    ///
+    /// Clears the level so [`privacy_status`](QuickexContract::privacy_status) returns `None`,
+    /// and appends a revoke sentinel (`u32::MAX`) to the account's privacy history.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `account` - The account to revoke (must authorize unless `operator` is given)
+    /// * `operator` - Registered operator acting for `account` (must authorize)
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `operator` is not the account's registered operator
+    /// * `PrivacySealed` - The account sealed its privacy settings
+    /// * `PrivacyNotConfigured` - The account has no privacy level set
+    /// * `PrivacyFrozen` - The admin froze the account's privacy settings
+    pub fn revoke_privacy(
+        env: Env,
+        account: Address,
+        operator: Option<Address>,
+    ) -> Result<(), QuickexError> {
+        privacy::revoke_privacy(&env, &account, operator)
+    }
+
+    /// Revoke the numeric privacy level of several accounts (**Admin only**).
+    ///
+    /// Each revoked account is handled as by
+    /// [`revoke_privacy`](QuickexContract::revoke_privacy) without needing its own
+    /// authorization. Unconfigured, sealed, and frozen accounts are skipped without error.
+    /// Returns the number of accounts revoked.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the contract admin
+    /// * `accounts` - Accounts to revoke
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    pub fn revoke_privacy_bulk(
+        env: Env,
+        caller: Address,
+        accounts: Vec<Address>,
+    ) -> Result<u32, QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        Ok(privacy::revoke_privacy_bulk(&env, accounts))
+    }
+
+    /// Set a distinct numeric privacy level on each of several accounts (**Admin only**).
+    ///
+    /// `levels[i]` is applied to `accounts[i]` as by
+    /// [`enable_privacy`](QuickexContract::enable_privacy), without needing each
+    /// account's authorization. Nothing is applied unless every entry is valid.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the contract admin
+    /// * `accounts` - Accounts to configure
+    /// * `levels` - Level for each account, in the same order
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    /// * `LengthMismatch` - `accounts` and `levels` differ in length
+    /// * `InvalidPrivacyLevel` - A level is a reserved history sentinel
+    /// * `PrivacySealed` - An account sealed its privacy settings
+    /// * `PrivacyFrozen` - An account's privacy settings are frozen
+    pub fn enable_privacy_batch_levels(
+        env: Env,
+        caller: Address,
+        accounts: Vec<Address>,
+        levels: Vec<u32>,
+    ) -> Result<(), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        privacy::enable_privacy_batch_levels(&env, accounts, levels)
+    }
+
+    /// Import an account's full privacy state from an external source (**Admin only**).
+    ///
+    /// Atomically sets the level, the history (newest first, as returned by
+    /// [`privacy_history`](QuickexContract::privacy_history)), and the ledger of the
+    /// latest change. The history hash chain is rebuilt over the imported history.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the contract admin
+    /// * `account` - The account to import
+    /// * `level` - Current privacy level
+    /// * `history` - Level history, newest first; the newest entry must equal `level`
+    /// * `last_change` - Ledger sequence of the latest level change
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    /// * `PrivacySealed` - The account sealed its privacy settings
+    /// * `InvalidPrivacyLevel` - `level` is the revoke sentinel or disagrees with `history`
+    /// * `InvalidHistoryLimit` - `history` is longer than the account's history limit
+    pub fn import_privacy(
+        env: Env,
+        caller: Address,
+        account: Address,
+        level: u32,
+        history: Vec<u32>,
+        last_change: u32,
+    ) -> Result<(), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        privacy::import_privacy(&env, &account, level, history, last_change)
+    }
+
+    /// Redact a single entry of an account's privacy history (**Admin only**).
+    ///
+    /// The entry at `index` (newest first, as in
+    /// [`privacy_history`](QuickexContract::privacy_history)) reads as `u32::MAX - 1`
+    /// afterwards; the other entries and the history length are unchanged.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the contract admin
+    /// * `account` - The account whose history to redact
+    /// * `index` - Position of the entry to redact
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    /// * `InvalidRange` - `index` is past the end of the history
+    pub fn redact_history_entry(
+        env: Env,
+        caller: Address,
+        account: Address,
+        index: u32,
+    ) -> Result<(), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        privacy::redact_history_entry(&env, &account, index)
+    }
+
+    /// Get the ledger sequence of an account's latest privacy level change (read-only).
+    ///
+    /// Returns `None` if the account's level never changed.
+    pub fn last_privacy_change(env: Env, account: Address) -> Option<u32> {
+        privacy::last_privacy_change(&env, &account)
+    }
+
+    /// Set how many privacy history entries are retained for an account.
+    ///
+    /// Overrides the global cap of 32 entries for this account. Older entries beyond
+    /// the limit are dropped immediately and on every later change.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `account` - The account to configure (must authorize)
+    /// * `limit` - Number of entries to keep (1–32)
+    ///
+    /// # Errors
+    /// * `InvalidHistoryLimit` - `limit` is zero or above the global maximum
+    pub fn set_history_limit(env: Env, account: Address, limit: u32) -> Result<(), QuickexError> {
+        privacy::set_account_history_limit(&env, &account, limit)
+    }
+
+    /// Rotate the seed an account derives its commitment salts from.
+    ///
+    /// Stores a commitment to the new seed under an incremented version so wallets can
+    /// signal that salts derived from earlier seeds are retired. Returns the new version.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `account` - The account rotating its seed (must authorize)
+    /// * `new_seed_hash` - Hash of the new seed; the seed itself never goes on-chain
+    pub fn rotate_seed(env: Env, account: Address, new_seed_hash: BytesN<32>) -> u32 {
+        privacy::rotate_seed(&env, &account, new_seed_hash)
+    }
+
+    /// Get the current salt seed version of an account (read-only).
+    ///
+    /// Returns `0` if the account never rotated a seed.
+    pub fn current_seed_version(env: Env, account: Address) -> u32 {
+        privacy::current_seed_version(&env, &account)
+    }
+
+    /// Get the number of accounts that currently have a privacy level set.
+    ///
+    /// Counts an account once when it is first configured via
+    /// [`enable_privacy`](QuickexContract::enable_privacy), and drops it again on
+    /// [`revoke_privacy`](QuickexContract::revoke_privacy).
+    pub fn configured_account_count(env: Env) -> u32 {
+        get_configured_account_count(&env)
+    }
+
+    /// Get the number of accounts currently at each privacy level.
+    ///
+    /// Maintained incrementally on every level change, so reading it does not scan
+    /// accounts. Levels with no accounts are omitted.
+    pub fn privacy_histogram(env: Env) -> Map<u32, u32> {
+        privacy::privacy_histogram(&env)
+    }
+
+    /// Get the current numeric privacy level for an account.
+    ///
+    /// Unconfigured accounts report the default set by
+    /// [`set_default_privacy_level`](QuickexContract::set_default_privacy_level), or
+    /// `None` if no default is set.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `account` - The account to query
+    pub fn privacy_status(env: Env, account: Address) -> Option<u32> {
+        privacy::privacy_status(&env, &account)
+    }
+
+    /// Check whether account `a`'s privacy level is at least account `b`'s (read-only).
+    ///
+    /// Returns `None` if either account is unconfigured; the deployment default set by
+    /// [`set_default_privacy_level`](QuickexContract::set_default_privacy_level) is not
+    /// used.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `a` - Account compared on the left
+    /// * `b` - Account compared on the right
+    pub fn privacy_level_gte(env: Env, a: Address, b: Address) -> Option<bool> {
+        privacy::privacy_level_gte(&env, &a, &b)
+    }
+
+    /// Set the baseline privacy level reported for unconfigured accounts (**Admin only**).
+    ///
+    /// Only affects [`privacy_status`](QuickexContract::privacy_status); accounts with
+    /// their own level are unchanged.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the contract admin
+    /// * `level` - Default privacy level
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    pub fn set_default_privacy_level(
+        env: Env,
+        caller: Address,
+        level: u32,
+    ) -> Result<(), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        set_default_privacy_level(&env, level);
+        Ok(())
+    }
+
+    /// Get the history of privacy level changes for an account.
+    ///
+    /// Returns a vector of levels, newest first. Only the most recent entries up to the
+    /// account's history limit are retained.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `account` - The account to query
+    pub fn privacy_history(env: Env, account: Address) -> Vec<u32> {
+        get_privacy_history(&env, &account)
+    }
+
+    /// Permanently seal an account's privacy settings.
+    ///
+    /// Afterwards [`enable_privacy`](QuickexContract::enable_privacy) returns `false`,
+    /// and [`revoke_privacy`](QuickexContract::revoke_privacy) and
+    /// [`import_privacy`](QuickexContract::import_privacy) fail with `PrivacySealed`.
+    /// A seal cannot be lifted, not even by the admin.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `account` - The account to seal (must authorize)
+    pub fn seal_privacy(env: Env, account: Address) {
+        privacy::seal_privacy(&env, &account)
+    }
+
+    /// Check whether an account has sealed its privacy settings (read-only).
+    pub fn is_privacy_sealed(env: Env, account: Address) -> bool {
+        privacy::is_sealed(&env, &account)
+    }
+
+    /// Freeze or unfreeze an account's privacy settings (**Admin only**).
+    ///
+    /// While frozen, [`enable_privacy`](QuickexContract::enable_privacy) returns `false` and
+    /// [`revoke_privacy`](QuickexContract::revoke_privacy) fails with `PrivacyFrozen`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Caller address (must equal admin; must authorize)
+    /// * `account` - The account to freeze or unfreeze
+    /// * `frozen` - `true` to freeze, `false` to unfreeze
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    pub fn set_privacy_frozen(
+        env: Env,
+        caller: Address,
+        account: Address,
+        frozen: bool,
+    ) -> Result<(), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        privacy::set_frozen(&env, &account, frozen);
+        Ok(())
+    }
+
+    /// Get the privacy summary of several accounts in one call (read-only).
+    ///
+    /// Each entry carries the account's level, history length, frozen flag, and whether
+    /// it is configured, in the order of `accounts`.
+    pub fn account_summary_many(env: Env, accounts: Vec<Address>) -> Vec<AccountPrivacy> {
+        privacy::account_summary_many(&env, accounts)
+    }
+
+    /// Check that an account's newest privacy history entry matches its current level.
+    ///
+    /// Revoked accounts are consistent when their newest entry is the revoke sentinel.
+    /// Returns `false` if storage has drifted out of sync.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `account` - The account to check
+    pub fn verify_history_consistency(env: Env, account: Address) -> bool {
+        privacy::verify_history_consistency(&env, &account)
+    }
+
+    /// Get the head of an account's tamper-evident privacy history chain (read-only).
+    ///
+    /// Every recorded level extends the chain as `sha256(prev_head || BE32(level))`,
+    /// starting from 32 zero bytes. Trimming the retained history does not affect it.
+    pub fn privacy_history_head(env: Env, account: Address) -> BytesN<32> {
+        privacy::privacy_history_head(&env, &account)
+    }
+
+    /// Checkpoint the current head of an account's privacy history chain.
+    ///
+    /// Returns the checkpointed head. Clients can prune the levels recorded before it
+    /// and keep the checkpoint as a verifiable anchor.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The account whose chain is checkpointed (must authorize)
+    pub fn checkpoint_chain(env: Env, owner: Address) -> BytesN<32> {
+        privacy::checkpoint_chain(&env, &owner)
+    }
+
+    /// Get the latest checkpointed privacy history chain head of an account (read-only).
+    ///
+    /// Returns `None` until [`checkpoint_chain`](QuickexContract::checkpoint_chain) is called.
+    pub fn latest_checkpoint(env: Env, owner: Address) -> Option<BytesN<32>> {
+        privacy::latest_checkpoint(&env, &owner)
+    }
+
+    /// Check a full level sequence against an account's history chain (read-only).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `account` - The account to check
+    /// * `levels` - Every recorded level, oldest first (revocations as `u32::MAX`)
+    pub fn verify_privacy_chain(env: Env, account: Address, levels: Vec<u32>) -> bool {
+        privacy::verify_privacy_chain(&env, &account, levels)
+    }
+
+    /// Enable or disable privacy for an account.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The account address to configure
+    /// * `enabled` - `true` to enable privacy, `false` to disable
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `PrivacyAlreadySet` - Privacy state is already at the requested value
+    pub fn set_privacy(env: Env, owner: Address, enabled: bool) -> Result<(), QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        privacy::set_privacy(&env, owner, enabled)
+    }
+
+    /// Check the current privacy status of an account
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The account address to query
+    ///
+    /// # Returns
+    /// * `bool` - Current privacy status (true = enabled)
+    pub fn get_privacy(env: Env, owner: Address) -> bool {
+        privacy::get_privacy(&env, owner)
+    }
+
+    /// Deposit funds and create an escrow entry keyed by `SHA256(owner || amount || salt)`.
+    ///
+    /// Transfers `amount` from `owner` to the contract and stores an escrow entry.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token` - The token contract address
+    /// * `amount` - Amount to deposit; must be positive
+    /// * `owner` - Owner of the funds (must authorize)
+    /// * `salt` - Random salt (within the salt policy) for uniqueness
+    /// * `timeout_secs` - Seconds from now until the escrow expires (0 = no expiry)
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - Amount is zero or negative
+    /// * `InvalidSalt` - Salt length violates the salt policy (default 0–1024 bytes)
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `CommitmentAlreadyExists` - An escrow for this commitment already exists
+    pub fn deposit(
+        env: Env,
+        token: Address,
+        amount: i128,
+        owner: Address,
+        salt: Bytes,
+        timeout_secs: u64,
+    ) -> Result<BytesN<32>, QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        escrow::deposit(&env, token, amount, owner, salt, timeout_secs)
+    }
+
+    /// Create a deterministic commitment hash for an amount (off-chain / pre-deposit use).
+    ///
+    /// Computes `SHA256(owner || amount || salt)`. Not a zero-knowledge proof; same inputs
+    /// always yield the same hash. Use for API shape validation and audit trails.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The owner address
+    /// * `amount` - Non-negative amount in token base units
+    /// * `salt` - Random bytes (within the salt policy) for uniqueness
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - Amount is negative
+    /// * `InvalidSalt` - Salt length violates the salt policy (default 0–1024 bytes)
+    ///
+    /// Errors are returned as typed results rather than host traps, so SDK clients can
+    /// recover from them through the generated `try_create_amount_commitment`.
+    pub fn create_amount_commitment(
+        env: Env,
+        owner: Address,
+        amount: i128,
+        salt: Bytes,
+    ) -> Result<BytesN<32>, QuickexError> {
+        commitment::create_amount_commitment(&env, owner, amount, salt)
+    }
+
+    /// Create a v2 amount commitment with a length-prefixed salt.
+    ///
+    /// The preimage carries a version byte and the salt's 4-byte big-endian length, so
+    /// it stays unambiguous if variable-length fields are appended later. Never equal
+    /// to the v1 [`create_amount_commitment`](QuickexContract::create_amount_commitment)
+    /// hash for the same opening.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The owner address
+    /// * `amount` - The amount to commit (must be non-negative)
+    /// * `salt` - Random bytes (within the salt policy) for uniqueness
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - Amount is negative
+    /// * `InvalidSalt` - Salt length violates the salt policy
+    pub fn create_amount_commitment_v2(
+        env: Env,
+        owner: Address,
+        amount: i128,
+        salt: Bytes,
+    ) -> Result<BytesN<32>, QuickexError> {
+        commitment::create_amount_commitment_v2(&env, owner, amount, salt)
+    }
+
+    /// Verify a v2 commitment against `owner`, `amount`, and `salt`.
+    ///
+    /// Returns `false` if inputs are invalid or don't match.
+    pub fn verify_amount_commitment_v2(
+        env: Env,
+        commitment: BytesN<32>,
+        owner: Address,
+        amount: i128,
+        salt: Bytes,
+    ) -> bool {
+        commitment::verify_amount_commitment_v2(&env, commitment, owner, amount, salt)
+    }
+
+    /// Create an amount commitment from an ASCII-hex encoded salt.
+    ///
+    /// Decodes `hex_salt` to raw bytes before hashing, so the commitment equals
+    /// [`create_amount_commitment`](QuickexContract::create_amount_commitment) over the
+    /// decoded salt. Guards against clients hashing the hex text by mistake.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The owner address
+    /// * `amount` - The amount to commit (must be non-negative)
+    /// * `hex_salt` - Salt as ASCII hex (even length, `0-9a-fA-F`)
+    ///
+    /// # Errors
+    /// * `InvalidHex` - `hex_salt` has odd length or a non-hex character
+    /// * `InvalidAmount` - Amount is negative
+    /// * `InvalidSalt` - Decoded salt length violates the salt policy, or `hex_salt`
+    ///   is longer than twice the 1024-byte hard cap
+    pub fn create_amount_commitment_hex(
+        env: Env,
+        owner: Address,
+        amount: i128,
+        hex_salt: Bytes,
+    ) -> Result<BytesN<32>, QuickexError> {
+        commitment::create_amount_commitment_hex(&env, owner, amount, hex_salt)
+    }
+
+    /// Verify that a commitment hash matches the given `owner`, `amount`, and `salt`.
+    ///
+    /// Recomputes the commitment and compares. Returns `false` if inputs are invalid or don't match.
+    /// The salt is checked against the hard 1024-byte cap only, so commitments created
+    /// under an earlier salt policy still verify; see
+    /// [`verify_commitment_strict`](QuickexContract::verify_commitment_strict).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `commitment` - 32-byte commitment hash to verify
+    /// * `owner` - Claimed owner
+    /// * `amount` - Claimed amount (must be non-negative)
+    /// * `salt` - Salt used when creating the commitment
+    pub fn verify_amount_commitment(
+        env: Env,
+        commitment: BytesN<32>,
+        owner: Address,
+        amount: i128,
+        salt: Bytes,
+    ) -> bool {
+        commitment::verify_amount_commitment(&env, commitment, owner, amount, salt)
+    }
+
+    /// Check that a raw preimage has the amount commitment layout (read-only).
+    ///
+    /// Lets clients sanity-check a preimage before verifying it: it must start with the
+    /// domain tag and `XDR(expected_owner)`, carry a 16-byte amount, and end with a salt
+    /// that satisfies the current salt policy.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `preimage` - Raw preimage bytes
+    /// * `expected_owner` - Owner the preimage should be bound to
+    pub fn validate_preimage_structure(env: Env, preimage: Bytes, expected_owner: Address) -> bool {
+        commitment::validate_preimage_structure(&env, preimage, expected_owner)
+    }
+
+    /// Verify a commitment and require its salt to satisfy the current salt policy.
+    ///
+    /// Returns `false` when the salt is too short or too long for the policy, even if
+    /// the hash matches.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `commitment` - 32-byte commitment hash to verify
+    /// * `owner` - Claimed owner
+    /// * `amount` - Claimed amount (must be non-negative)
+    /// * `salt` - Salt used when creating the commitment
+    pub fn verify_commitment_strict(
+        env: Env,
+        commitment: BytesN<32>,
+        owner: Address,
+        amount: i128,
+        salt: Bytes,
+    ) -> bool {
+        commitment::verify_commitment_strict(&env, commitment, owner, amount, salt)
+    }
+
+    /// Check whether a commitment opens to a zero amount for `owner` and `salt`.
+    ///
+    /// Same as [`verify_amount_commitment`](QuickexContract::verify_amount_commitment)
+    /// with `amount = 0`; useful for proving an empty or nullified position.
+    pub fn is_zero_commitment(
+        env: Env,
+        commitment: BytesN<32>,
+        owner: Address,
+        salt: Bytes,
+    ) -> bool {
+        commitment::is_zero_commitment(&env, commitment, owner, salt)
+    }
+
+    /// Create an amount commitment anchored to the current ledger sequence.
+    ///
+    /// Folds `env.ledger().sequence()` into the preimage and returns it alongside the
+    /// hash, so the same opening made at another ledger yields a different commitment.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The owner address
+    /// * `amount` - The amount to commit (must be non-negative)
+    /// * `salt` - Random bytes (within the salt policy) for uniqueness
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - Amount is negative
+    /// * `InvalidSalt` - Salt length violates the salt policy (default 0–1024 bytes)
+    pub fn create_anchored_commitment(
+        env: Env,
+        owner: Address,
+        amount: i128,
+        salt: Bytes,
+    ) -> Result<(BytesN<32>, u32), QuickexError> {
+        commitment::create_anchored_commitment(&env, owner, amount, salt)
+    }
+
+    /// Verify an anchored commitment against its opening and anchoring ledger sequence.
+    pub fn verify_anchored_commitment(
+        env: Env,
+        commitment: BytesN<32>,
+        owner: Address,
+        amount: i128,
+        salt: Bytes,
+        sequence: u32,
+    ) -> bool {
+        commitment::verify_anchored_commitment(&env, commitment, owner, amount, salt, sequence)
+    }
+
+    /// Create an amount commitment and record the ledger it was created at.
+    ///
+    /// Same hash as [`create_amount_commitment`](QuickexContract::create_amount_commitment);
+    /// the creation ledger can later be read with
+    /// [`commitment_created_at`](QuickexContract::commitment_created_at) for freshness checks.
+    /// An owner may track at most five commitments per ledger.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The owner address (must authorize)
+    /// * `amount` - The amount to commit (must be non-negative)
+    /// * `salt` - Random bytes (within the salt policy) for uniqueness
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - Amount is negative
+    /// * `InvalidSalt` - Salt length violates the salt policy (default 0–1024 bytes)
+    /// * `CommitmentAlreadyExists` - The commitment is already tracked
+    /// * `RateLimited` - The owner already tracked five commitments this ledger
+    pub fn create_tracked_commitment(
+        env: Env,
+        owner: Address,
+        amount: i128,
+        salt: Bytes,
+    ) -> Result<BytesN<32>, QuickexError> {
+        commitment::create_tracked_commitment(&env, owner, amount, salt)
+    }
+
+    /// Get the ledger sequence a commitment was tracked at (read-only).
+    ///
+    /// Returns `None` for commitments not created via
+    /// [`create_tracked_commitment`](QuickexContract::create_tracked_commitment).
+    pub fn commitment_created_at(env: Env, commitment: BytesN<32>) -> Option<u32> {
+        commitment::commitment_created_at(&env, &commitment)
+    }
+
+    /// List tracked commitments created within a ledger range (read-only).
+    ///
+    /// Intended for indexers backfilling. Returns the hashes oldest first, together
+    /// with the ledger to pass as `start_ledger` to continue. Each call reads at most
+    /// [`commitment::MAX_TRACKED_SCAN_LEDGERS`] ledgers and returns whole ledgers, so
+    /// it may return a few more than `limit` hashes.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `start_ledger` - First ledger of the range (inclusive)
+    /// * `end_ledger` - Last ledger of the range (inclusive)
+    /// * `limit` - Number of commitments after which to stop reading ledgers
+    ///
+    /// # Errors
+    /// * `InvalidRange` - `start_ledger` is after `end_ledger`
+    pub fn commitments_in_range(
+        env: Env,
+        start_ledger: u32,
+        end_ledger: u32,
+        limit: u32,
+    ) -> Result<(Vec<BytesN<32>>, u32), QuickexError> {
+        commitment::commitments_in_range(&env, start_ledger, end_ledger, limit)
+    }
+
+    /// Create a salt-free commitment to a public amount (read-only).
+    ///
+    /// Provides **no hiding at all**: anyone who knows or guesses `owner` and `amount`
+    /// can recompute it. Intended as a stable content identifier for amounts that are
+    /// deliberately public. Domain-separated from salted amount commitments.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The owner address
+    /// * `amount` - Non-negative amount in token base units
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - Amount is negative
+    pub fn create_public_commitment(
+        env: Env,
+        owner: Address,
+        amount: i128,
+    ) -> Result<BytesN<32>, QuickexError> {
+        commitment::create_public_commitment(&env, owner, amount)
+    }
+
+    /// Create an amount commitment with a contract-generated 32-byte salt.
+    ///
+    /// **For simulation only.** The salt comes from the host PRNG and is returned in
+    /// the result, so once this runs in a transaction the salt is public and anyone can
+    /// brute-force the amount from the commitment. Use it to preview commitments in a
+    /// simulated call; for real commitments generate the salt off-chain and call
+    /// [`create_amount_commitment`](QuickexContract::create_amount_commitment).
+    ///
+    /// Returns `(commitment, salt)`. The PRNG is deterministic within a test environment.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The owner address
+    /// * `amount` - Non-negative amount in token base units
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - Amount is negative
+    /// * `InvalidSalt` - The salt policy does not admit 32-byte salts
+    pub fn create_commitment_auto_salt(
+        env: Env,
+        owner: Address,
+        amount: i128,
+    ) -> Result<(BytesN<32>, Bytes), QuickexError> {
+        commitment::create_commitment_auto_salt(&env, owner, amount)
+    }
+
+    /// Verify an amount commitment embedded in a larger buffer (read-only).
+    ///
+    /// Reads the 32 bytes at `offset` in `blob` and verifies them as by
+    /// [`verify_amount_commitment`](QuickexContract::verify_amount_commitment).
+    /// Returns `false` when the read would run past the end of `blob`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `blob` - Buffer containing the commitment, e.g. a concatenated proof
+    /// * `offset` - Byte offset of the commitment in `blob`
+    /// * `owner` / `amount` / `salt` - The claimed opening
+    pub fn verify_commitment_at(
+        env: Env,
+        blob: Bytes,
+        offset: u32,
+        owner: Address,
+        amount: i128,
+        salt: Bytes,
+    ) -> bool {
+        commitment::verify_commitment_at(&env, blob, offset, owner, amount, salt)
+    }
+
+    /// Get the ledger timestamp after which a timed commitment is stale (read-only).
+    ///
+    /// Returns `timestamp + max_age`, saturating at `u64::MAX`, so clients can display
+    /// when a commitment made at `timestamp` stops being accepted.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `timestamp` - Ledger timestamp the commitment was made at
+    /// * `max_age` - Maximum accepted age in seconds
+    pub fn commitment_expiry(_env: Env, timestamp: u64, max_age: u64) -> u64 {
+        commitment::commitment_expiry(timestamp, max_age)
+    }
+
+    /// List the expected commitments that are not tracked (read-only).
+    ///
+    /// For reconciling a client's expected set against commitments recorded via
+    /// [`create_tracked_commitment`](QuickexContract::create_tracked_commitment).
+    /// Missing commitments are returned in input order.
+    pub fn commitments_missing(env: Env, expected: Vec<BytesN<32>>) -> Vec<BytesN<32>> {
+        commitment::commitments_missing(&env, expected)
+    }
+
+    /// Verify an amount commitment opening and record who revealed it.
+    ///
+    /// Stores the revealer and the current ledger for accountability. Only the first
+    /// recorded opening of a commitment is kept.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `revealer` - The address revealing the opening (must authorize)
+    /// * `commitment` - The commitment being opened
+    /// * `owner` / `amount` / `salt` - The claimed opening
+    ///
+    /// # Errors
+    /// * `CommitmentMismatch` - The opening does not match the commitment
+    /// * `CommitmentAlreadyExists` - An opening is already recorded for the commitment
+    pub fn record_opening_by(
+        env: Env,
+        revealer: Address,
+        commitment: BytesN<32>,
+        owner: Address,
+        amount: i128,
+        salt: Bytes,
+    ) -> Result<(), QuickexError> {
+        commitment::record_opening_by(&env, revealer, commitment, owner, amount, salt)
+    }
+
+    /// Merge two amount commitments of `owner` into one commitment to their sum (read-only).
+    ///
+    /// Verifies both openings, then commits to `amount_a + amount_b` under `merged_salt`,
+    /// e.g. to combine two positions without revealing them on-chain afterwards.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `commitment_a` / `amount_a` / `salt_a` - Opening of the first commitment
+    /// * `commitment_b` / `amount_b` / `salt_b` - Opening of the second commitment
+    /// * `merged_salt` - Salt for the merged commitment
+    /// * `owner` - Owner of both commitments
+    ///
+    /// # Errors
+    /// * `CommitmentMismatch` - Either opening does not match its commitment
+    /// * `InvalidAmount` - The summed amount overflows
+    /// * `InvalidSalt` - `merged_salt` violates the salt policy
+    #[allow(clippy::too_many_arguments)]
+    pub fn merge_commitments(
+        env: Env,
+        commitment_a: BytesN<32>,
+        amount_a: i128,
+        salt_a: Bytes,
+        commitment_b: BytesN<32>,
+        amount_b: i128,
+        salt_b: Bytes,
+        merged_salt: Bytes,
+        owner: Address,
+    ) -> Result<BytesN<32>, QuickexError> {
+        commitment::merge_commitments(
+            &env,
+            commitment_a,
+            amount_a,
+            salt_a,
+            commitment_b,
+            amount_b,
+            salt_b,
+            merged_salt,
+            owner,
+        )
+    }
+
+    /// Get the address that revealed a commitment's opening (read-only).
+    ///
+    /// Returns `None` until an opening is recorded via
+    /// [`record_opening_by`](QuickexContract::record_opening_by).
+    pub fn opening_revealer(env: Env, commitment: BytesN<32>) -> Option<Address> {
+        commitment::opening_revealer(&env, &commitment)
+    }
+
+    /// Check whether two amount commitments both open to `owner` (read-only).
+    ///
+    /// Returns `false` unless each commitment verifies against `owner` with its own
+    /// amount and salt.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `commitment_a` / `amount_a` / `salt_a` - Opening of the first commitment
+    /// * `commitment_b` / `amount_b` / `salt_b` - Opening of the second commitment
+    /// * `owner` - Candidate owner of both commitments
+    #[allow(clippy::too_many_arguments)]
+    pub fn shared_owner(
+        env: Env,
+        commitment_a: BytesN<32>,
+        amount_a: i128,
+        salt_a: Bytes,
+        commitment_b: BytesN<32>,
+        amount_b: i128,
+        salt_b: Bytes,
+        owner: Address,
+    ) -> bool {
+        commitment::shared_owner(
+            &env,
+            commitment_a,
+            amount_a,
+            salt_a,
+            commitment_b,
+            amount_b,
+            salt_b,
+            owner,
+        )
+    }
+
+    /// Create a deterministic commitment hash for an unsigned `u128` amount.
+    ///
+    /// For token standards that use `u128` amounts. Every value is accepted, so there
+    /// is no negative-amount error path.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The owner address
+    /// * `amount` - Unsigned amount
+    /// * `salt` - Random bytes (within the salt policy) for uniqueness
+    ///
+    /// # Errors
+    /// * `InvalidSalt` - Salt length violates the salt policy (default 0–1024 bytes)
+    pub fn create_u128_commitment(
+        env: Env,
+        owner: Address,
+        amount: u128,
+        salt: Bytes,
+    ) -> Result<BytesN<32>, QuickexError> {
+        commitment::create_u128_commitment(&env, owner, amount, salt)
+    }
+
+    /// Verify that a commitment hash matches the given `owner`, `u128` amount, and `salt`.
+    pub fn verify_u128_commitment(
+        env: Env,
+        commitment: BytesN<32>,
+        owner: Address,
+        amount: u128,
+        salt: Bytes,
+    ) -> bool {
+        commitment::verify_u128_commitment(&env, commitment, owner, amount, salt)
+    }
+
+    /// Set the domain tag prefixed to every commitment preimage (**Admin only**).
+    ///
+    /// Isolates this deployment's commitments from other QuickEx instances. Until set, the
+    /// built-in [`DEFAULT_DOMAIN_TAG`](commitment::DEFAULT_DOMAIN_TAG) is used. The tag can
+    /// be set once, before the first deposit, so no deposit is ever keyed under a tag
+    /// that later changes. Deposits made before domain tags existed stay withdrawable.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Caller address (must equal admin; must authorize)
+    /// * `tag` - New domain tag (1–64 bytes)
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    /// * `InvalidDomainTag` - Tag is empty or longer than 64 bytes
+    /// * `DomainTagLocked` - The tag was already set, or a deposit was already made
+    pub fn set_domain_tag(env: Env, caller: Address, tag: Bytes) -> Result<(), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        commitment::set_domain_tag(&env, tag)
+    }
+
+    /// Get the domain tag currently prefixed to commitment preimages.
+    pub fn get_domain_tag(env: Env) -> Bytes {
+        commitment::domain_tag(&env)
+    }
+
+    /// Set the salt length policy enforced by the commitment constructors (admin only).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the contract admin
+    /// * `min_len` - Minimum salt length in bytes
+    /// * `max_len` - Maximum salt length in bytes (at most 1024)
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    /// * `InvalidSaltPolicy` - `min_len > max_len` or `max_len` exceeds 1024
+    pub fn set_salt_policy(
+        env: Env,
+        caller: Address,
+        min_len: u32,
+        max_len: u32,
+    ) -> Result<(), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        commitment::set_salt_policy(&env, min_len, max_len)
+    }
+
+    /// Check whether a salt satisfies the current salt length policy (read-only).
+    ///
+    /// Lets clients validate a salt before submitting a commitment transaction.
+    pub fn is_valid_salt(env: Env, salt: Bytes) -> bool {
+        commitment::is_valid_salt(&env, &salt)
+    }
+
+    /// Get the parameters of the commitment scheme (read-only).
+    ///
+    /// Returns the preimage layout version, hash algorithm, salt length policy, and
+    /// domain tag in one call so off-chain provers can configure themselves.
+    pub fn commitment_params(env: Env) -> CommitmentParams {
+        commitment::commitment_params(&env)
+    }
+
+    /// Get the hash algorithm applied to commitment preimages (read-only).
+    ///
+    /// A lighter read than [`commitment_params`](QuickexContract::commitment_params)
+    /// for clients that only need the algorithm name. Currently always `sha256`.
+    pub fn hash_algorithm(_env: Env) -> Symbol {
+        commitment::hash_algorithm()
+    }
+
+    /// Get the collision-resistance bits of the commitment digest (read-only).
+    ///
+    /// Half the digest size by the birthday bound: 128 for the full SHA-256 output.
+    pub fn commitment_bit_security(_env: Env) -> u32 {
+        commitment::commitment_bit_security()
+    }
+
+    /// Create an amount commitment bound to a viewing key.
+    ///
+    /// The 32-byte `viewkey` is folded into the preimage, so only parties holding it
+    /// can reconstruct and verify the commitment. Share it for selective disclosure.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The owner address
+    /// * `amount` - The amount to commit (must be non-negative)
+    /// * `salt` - Random bytes (within the salt policy) for uniqueness
+    /// * `viewkey` - Viewing key shared with the intended verifier
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - Amount is negative
+    /// * `InvalidSalt` - Salt length violates the salt policy
+    pub fn create_viewkey_commitment(
+        env: Env,
+        owner: Address,
+        amount: i128,
+        salt: Bytes,
+        viewkey: BytesN<32>,
+    ) -> Result<BytesN<32>, QuickexError> {
+        commitment::create_viewkey_commitment(&env, owner, amount, salt, viewkey)
+    }
+
+    /// Verify a viewing-key commitment against its opening and `viewkey`.
+    ///
+    /// Returns `false` if inputs are invalid, don't match, or the viewing key is wrong.
+    pub fn verify_viewkey_commitment(
+        env: Env,
+        commitment: BytesN<32>,
+        owner: Address,
+        amount: i128,
+        salt: Bytes,
+        viewkey: BytesN<32>,
+    ) -> bool {
+        commitment::verify_viewkey_commitment(&env, commitment, owner, amount, salt, viewkey)
+    }
+
+    /// Create a deterministic commitment hash for an arbitrary 32-byte payload.
+    ///
+    /// Generalizes [`create_amount_commitment`](QuickexContract::create_amount_commitment)
+    /// to use-cases that commit to a fixed-size payload (e.g. a document hash) rather than
+    /// an `i128` amount. Payload and amount commitments never collide.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The owner address
+    /// * `payload` - 32-byte payload to commit to
+    /// * `salt` - Random bytes (within the salt policy) for uniqueness
+    ///
+    /// # Errors
+    /// * `InvalidSalt` - Salt length violates the salt policy (default 0–1024 bytes)
+    pub fn create_payload_commitment(
+        env: Env,
+        owner: Address,
+        payload: BytesN<32>,
+        salt: Bytes,
+    ) -> Result<BytesN<32>, QuickexError> {
+        commitment::create_payload_commitment(&env, owner, payload, salt)
+    }
+
+    /// Verify that a commitment hash matches the given `owner`, `payload`, and `salt`.
+    ///
+    /// Returns `false` if inputs are invalid or don't match.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `commitment` - 32-byte commitment hash to verify
+    /// * `owner` - Claimed owner
+    /// * `payload` - Claimed 32-byte payload
+    /// * `salt` - Salt used when creating the commitment
+    pub fn verify_payload_commitment(
+        env: Env,
+        commitment: BytesN<32>,
+        owner: Address,
+        payload: BytesN<32>,
+        salt: Bytes,
+    ) -> bool {
+        commitment::verify_payload_commitment(&env, commitment, owner, payload, salt)
+    }
+
+    /// Combine an ordered batch of commitments into one aggregate root (read-only).
+    ///
+    /// Useful for anchoring a batch with a single hash. The root depends on the order
+    /// of `parts`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `parts` - Commitments to aggregate, in order
+    pub fn aggregate_commitments(env: Env, parts: Vec<BytesN<32>>) -> BytesN<32> {
+        commitment::aggregate_commitments(&env, parts)
+    }
+
+    /// Check an aggregate root against its ordered parts (read-only).
+    ///
+    /// Returns `true` only if `parts` reproduce `root` via
+    /// [`aggregate_commitments`](QuickexContract::aggregate_commitments).
+    pub fn verify_aggregate(env: Env, root: BytesN<32>, parts: Vec<BytesN<32>>) -> bool {
+        commitment::verify_aggregate(&env, root, parts)
+    }
+
+    /// Create a commitment hash over a map of named amounts (e.g. a fee breakdown).
+    ///
+    /// Entries are serialized in key order, so maps with the same contents produce the
+    /// same hash regardless of insertion order.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `owner` - The owner address
+    /// * `entries` - Named amounts to commit to (each must be non-negative)
+    /// * `salt` - Random bytes (within the salt policy) for uniqueness
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - Any amount is negative
+    /// * `InvalidSalt` - Salt length violates the salt policy
+    pub fn create_map_commitment(
+        env: Env,
+        owner: Address,
+        entries: Map<Symbol, i128>,
+        salt: Bytes,
+    ) -> Result<BytesN<32>, QuickexError> {
+        commitment::create_map_commitment(&env, owner, entries, salt)
+    }
+
+    /// Verify a map commitment against its `owner`, `entries`, and `salt`.
+    ///
+    /// Returns `false` if inputs are invalid or don't match.
+    pub fn verify_map_commitment(
+        env: Env,
+        commitment: BytesN<32>,
+        owner: Address,
+        entries: Map<Symbol, i128>,
+        salt: Bytes,
+    ) -> bool {
+        commitment::verify_map_commitment(&env, commitment, owner, entries, salt)
+    }
+
+    /// Create a token-backed id-based escrow and return its id.
+    ///
+    /// Allocates the next value of the global escrow counter, stores a `Pending`
+    /// [`EscrowData`] record under it and transfers `amount` of `token` from `from` to
+    /// this contract. Release pays the recipient in `token`; refunds and cancellations
+    /// return it to `from`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from` - Sender of the escrow (must authorize)
+    /// * `to` - Recipient paid on release
+    /// * `amount` - Amount in token base units
+    /// * `token` - Token contract holding the funds
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `InvalidAmount` - `amount` is zero
+    /// * `SelfEscrow` - `from` and `to` are the same address
+    /// * `AmountTooLarge` - `amount` exceeds the ceiling set by `set_max_escrow_amount`
+    ///
+    /// Fails with the token's error if `from`'s balance is insufficient.
+    pub fn create_escrow(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: u64,
+        token: Address,
+    ) -> Result<u64, QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        id_escrow::create_escrow(&env, from, to, amount, token)
+    }
+
+    /// Create an id-based escrow record that holds no funds and return its id.
+    ///
+    /// Stores a `Pending` [`EscrowData`] record like
+    /// [`create_escrow`](QuickexContract::create_escrow), but no tokens move on
+    /// creation, release or refund. The sender must authorize.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from` - Sender of the escrow (must authorize)
+    /// * `to` - Recipient of the escrow
+    /// * `amount` - Amount recorded on the escrow
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - `amount` is zero
+    /// * `SelfEscrow` - `from` and `to` are the same address
+    /// * `AmountTooLarge` - `amount` exceeds the ceiling set by `set_max_escrow_amount`
+    pub fn create_unfunded_escrow(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: u64,
+    ) -> Result<u64, QuickexError> {
+        id_escrow::create_unfunded_escrow(&env, from, to, amount)
+    }
+
+    /// Create a token-backed id-based escrow that compensates its arbiter.
+    ///
+    /// Funded by a direct transfer as in
+    /// [`create_escrow`](QuickexContract::create_escrow). If a dispute over
+    /// the escrow is settled by [`resolve_dispute`](QuickexContract::resolve_dispute),
+    /// `arbiter_fee` is deducted from the escrowed amount and paid to the arbiter; a
+    /// normal release pays the recipient in full.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from` - Sender of the escrow (must authorize)
+    /// * `to` - Recipient paid on release
+    /// * `amount` - Amount in token base units
+    /// * `token` - Token contract holding the funds
+    /// * `arbiter_fee` - Fee paid to the arbiter on dispute resolution
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `InvalidAmount` - `amount` is zero or `arbiter_fee` exceeds it
+    /// * `InvalidAmount` - `amount` is zero
+    /// * `SelfEscrow` - `from` and `to` are the same address
+    /// * `AmountTooLarge` - `amount` exceeds the ceiling set by `set_max_escrow_amount`
+    pub fn create_escrow_with_arbiter_fee(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: u64,
+        token: Address,
+        arbiter_fee: u64,
+    ) -> Result<u64, QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        id_escrow::create_escrow_with_arbiter_fee(&env, from, to, amount, token, arbiter_fee)
+    }
+
+    /// Get the fee an id-based escrow pays its arbiter on dispute resolution (read-only).
+    pub fn arbiter_fee(env: Env, id: u64) -> u64 {
+        id_escrow::arbiter_fee(&env, id)
+    }
+
+    /// Create a token-backed id-based escrow funded from an allowance.
+    ///
+    /// Pulls `amount` of `token` from `from` with `transfer_from`, relying on `from`
+    /// having approved this contract as spender beforehand. Release pays the recipient
+    /// in `token`; refunds and cancellations return it to `from`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from` - Sender of the escrow (must authorize)
+    /// * `to` - Recipient paid on release
+    /// * `amount` - Amount in token base units
+    /// * `token` - Token contract to pull the funds from
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `InvalidAmount` - `amount` is zero
+    /// * `SelfEscrow` - `from` and `to` are the same address
+    /// * `AmountTooLarge` - `amount` exceeds the ceiling set by `set_max_escrow_amount`
+    ///
+    /// Fails with the token's error if the allowance or balance is insufficient.
+    pub fn create_escrow_from_allowance(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: u64,
+        token: Address,
+    ) -> Result<u64, QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        id_escrow::create_escrow_from_allowance(&env, from, to, amount, token)
+    }
+
+    /// Create an id-based escrow and return its id together with a creation receipt.
+    ///
+    /// The receipt is `sha256(BE64(id) || XDR(from) || XDR(to) || BE64(amount) || BE32(ledger))`
+    /// so the client can re-derive it later and check it with
+    /// [`verify_escrow_receipt`](QuickexContract::verify_escrow_receipt).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from` - Sender of the escrow (must authorize)
+    /// * `to` - Recipient paid on release
+    /// * `amount` - Amount in token base units
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - `amount` is zero
+    /// * `SelfEscrow` - `from` and `to` are the same address
+    /// * `AmountTooLarge` - `amount` exceeds the ceiling set by `set_max_escrow_amount`
+    pub fn create_escrow_with_receipt(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: u64,
+    ) -> Result<(u64, BytesN<32>), QuickexError> {
+        id_escrow::create_escrow_with_receipt(&env, from, to, amount)
+    }
+
+    /// Check a creation receipt against the stored escrow (read-only).
+    ///
+    /// Returns `false` for an unknown escrow or a receipt over different values.
+    pub fn verify_escrow_receipt(env: Env, id: u64, receipt: BytesN<32>) -> bool {
+        id_escrow::verify_escrow_receipt(&env, id, receipt)
+    }
+
+    /// Create an id-based escrow that accrues simple interest until release.
+    ///
+    /// On release the recipient is paid the principal plus
+    /// `amount * interest_bps_per_ledger * elapsed_ledgers / 10000`. Total interest is
+    /// capped at 100% of the principal.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from` - Sender of the escrow (must authorize)
+    /// * `to` - Recipient paid on release
+    /// * `amount` - Principal in token base units
+    /// * `interest_bps_per_ledger` - Interest per elapsed ledger, in basis points
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - `amount` is zero
+    /// * `SelfEscrow` - `from` and `to` are the same address
+    /// * `AmountTooLarge` - `amount` exceeds the ceiling set by `set_max_escrow_amount`
+    pub fn create_escrow_with_interest(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: u64,
+        interest_bps_per_ledger: u32,
+    ) -> Result<u64, QuickexError> {
+        id_escrow::create_escrow_with_interest(&env, from, to, amount, interest_bps_per_ledger)
+    }
+
+    /// Create an id-based escrow that cannot be released for `min_lock_ledgers` ledgers.
+    ///
+    /// Every release path fails with `LockNotElapsed` until the ledger sequence reaches
+    /// `created_ledger + min_lock_ledgers`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from` - Sender of the escrow (must authorize)
+    /// * `to` - Recipient paid on release
+    /// * `amount` - Amount in token base units
+    /// * `min_lock_ledgers` - Minimum number of ledgers the funds stay locked
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - `amount` is zero
+    /// * `SelfEscrow` - `from` and `to` are the same address
+    /// * `AmountTooLarge` - `amount` exceeds the ceiling set by `set_max_escrow_amount`
+    pub fn create_escrow_with_min_lock(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: u64,
+        min_lock_ledgers: u32,
+    ) -> Result<u64, QuickexError> {
+        id_escrow::create_escrow_with_min_lock(&env, from, to, amount, min_lock_ledgers)
+    }
+
+    /// Create an id-based escrow with a memo encrypted to the recipient.
+    ///
+    /// The memo is an opaque blob the contract stores without decrypting, readable
+    /// only by the recipient via [`get_encrypted_memo`](QuickexContract::get_encrypted_memo).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from` - Sender of the escrow (must authorize)
+    /// * `to` - Recipient paid on release
+    /// * `amount` - Amount in token base units
+    /// * `encrypted_memo` - Memo encrypted to `to`, at most 256 bytes
+    ///
+    /// # Errors
+    /// * `MemoTooLong` - `encrypted_memo` is longer than 256 bytes
+    /// * `InvalidAmount` - `amount` is zero
+    /// * `SelfEscrow` - `from` and `to` are the same address
+    /// * `AmountTooLarge` - `amount` exceeds the ceiling set by `set_max_escrow_amount`
+    pub fn create_escrow_encrypted_memo(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: u64,
+        encrypted_memo: Bytes,
+    ) -> Result<u64, QuickexError> {
+        id_escrow::create_escrow_encrypted_memo(&env, from, to, amount, encrypted_memo)
+    }
+
+    /// Get the encrypted memo of an id-based escrow. The recipient must authorize.
+    ///
+    /// Returns `None` if the escrow was created without a memo.
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    pub fn get_encrypted_memo(env: Env, id: u64) -> Result<Option<Bytes>, QuickexError> {
+        id_escrow::get_encrypted_memo(&env, id)
+    }
+
+    /// Get the total amount held by all pending id-based escrows (read-only).
+    ///
+    /// Kept as a running total updated on every create and settlement, so the
+    /// call is constant cost. Accrued interest is not included.
+    pub fn total_escrowed(env: Env) -> i128 {
+        id_escrow::total_escrowed(&env)
+    }
+
+    /// Count the pending id-based escrows older than `older_than_ledgers` (read-only).
+    ///
+    /// Age is measured from each escrow's `created_ledger`. Meant for ops alerting on
+    /// escrows that have sat unsettled for too long. Reads at most `limit` ids (capped at
+    /// 100) from `start_id` on and returns `(count, next_id)`; sum the counts while
+    /// passing `next_id` back until it passes the last escrow id.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `older_than_ledgers` - Minimum age in ledgers of a counted escrow
+    /// * `start_id` - First escrow id to read (ids start at 1)
+    /// * `limit` - Maximum number of ids to read
+    pub fn stuck_escrow_count(
+        env: Env,
+        older_than_ledgers: u32,
+        start_id: u64,
+        limit: u32,
+    ) -> (u32, u64) {
+        id_escrow::stuck_escrow_count(&env, older_than_ledgers, start_id, limit)
+    }
+
+    /// Get the interest accrued so far by a pending id-based escrow (read-only).
+    ///
+    /// Returns `0` for unknown or settled escrows.
+    pub fn accrued_interest(env: Env, id: u64) -> u64 {
+        id_escrow::accrued_interest(&env, id)
+    }
+
+    /// Create an id-based escrow that needs `threshold` of `approvers` to approve its release.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from` - Sender of the escrow (must authorize)
+    /// * `to` - Recipient paid on release
+    /// * `amount` - Amount in token base units
+    /// * `approvers` - Addresses allowed to approve; duplicates are collapsed
+    /// * `threshold` - Number of distinct approvals required before release
+    ///
+    /// # Errors
+    /// * `InvalidThreshold` - `threshold` is zero or exceeds the number of distinct approvers
+    /// * `InvalidAmount` - `amount` is zero
+    /// * `SelfEscrow` - `from` and `to` are the same address
+    /// * `AmountTooLarge` - `amount` exceeds the ceiling set by `set_max_escrow_amount`
+    pub fn create_mofn_escrow(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: u64,
+        approvers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<u64, QuickexError> {
+        id_escrow::create_mofn_escrow(&env, from, to, amount, approvers, threshold)
+    }
+
+    /// Approve the release of a pending M-of-N escrow.
+    ///
+    /// Returns the number of distinct approvals collected. Repeat approvals from the
+    /// same approver are ignored.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Escrow id
+    /// * `approver` - One of the escrow's approvers (must authorize)
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `AlreadySpent` - Escrow is not `Pending`
+    /// * `Unauthorized` - `approver` is not an approver of the escrow
+    pub fn approve_escrow(env: Env, id: u64, approver: Address) -> Result<u32, QuickexError> {
+        id_escrow::approve_escrow(&env, id, approver)
+    }
+
+    /// Release a pending id-based escrow to its recipient.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Escrow id
+    /// * `caller` - Escrow sender (must authorize)
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `AlreadySpent` - Escrow is not `Pending`
+    /// * `Unauthorized` - Caller is not the sender
+    /// * `LockNotElapsed` - The escrow's minimum lock period has not passed
+    /// * `InsufficientApprovals` - An M-of-N escrow has not reached its threshold
+    pub fn release_escrow(env: Env, id: u64, caller: Address) -> Result<(), QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        id_escrow::release_escrow(&env, id, caller)
+    }
+
+    /// Opt a pending id-based escrow in or out of the release callback.
+    ///
+    /// When enabled and the recipient is a contract, releasing the escrow calls the
+    /// recipient's `on_escrow_received(id, amount)` after paying it. Recipients that do
+    /// not implement the callback, or whose callback fails, are still paid.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Escrow id; its sender must authorize
+    /// * `enabled` - Whether to invoke the callback on release
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `AlreadySpent` - Escrow is not `Pending`
+    pub fn set_release_callback(env: Env, id: u64, enabled: bool) -> Result<(), QuickexError> {
+        id_escrow::set_release_callback(&env, id, enabled)
+    }
+
+    /// Release a pending id-based escrow split across several recipients.
+    ///
+    /// `amounts[i]` is paid to `recipients[i]`; the amounts must add up to the escrow's
+    /// current payout (principal plus accrued interest, minus the escrow fee). The
+    /// escrow's `to` is not paid separately. The sender must authorize. When the escrow
+    /// opted in with [`set_release_callback`](QuickexContract::set_release_callback),
+    /// each contract recipient's `on_escrow_received` is called with its own amount.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Escrow id
+    /// * `recipients` - Addresses to pay
+    /// * `amounts` - Amount for each recipient, in the same order
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `AlreadySpent` - Escrow is not `Pending`
+    /// * `LengthMismatch` - `recipients` and `amounts` differ in length
+    /// * `InvalidAmount` - The amounts do not add up to the payout
+    /// * `LockNotElapsed` - The escrow's minimum lock period has not passed
+    /// * `InsufficientApprovals` - An M-of-N escrow has not reached its threshold
+    pub fn release_to_many(
+        env: Env,
+        id: u64,
+        recipients: Vec<Address>,
+        amounts: Vec<u64>,
+    ) -> Result<(), QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        id_escrow::release_to_many(&env, id, recipients, amounts)
+    }
+
+    /// Set the escrow fee withheld from every release (**Admin only**).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the contract admin
+    /// * `fee_bps` - Fee in basis points of the escrow principal
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    /// * `InvalidFeeBps` - `fee_bps` exceeds 10000
+    pub fn set_escrow_fee_bps(env: Env, caller: Address, fee_bps: u32) -> Result<(), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        id_escrow::set_escrow_fee_bps(&env, fee_bps)
+    }
+
+    /// Send the escrow fees withheld from token-backed releases in `token` to `to`
+    /// (**Admin only**).
+    ///
+    /// Returns the amount transferred; `0` when nothing was owed.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the contract admin
+    /// * `token` - Token whose withheld fees to collect
+    /// * `to` - Address receiving the fees
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    pub fn collect_escrow_fees(
+        env: Env,
+        caller: Address,
+        token: Address,
+        to: Address,
+    ) -> Result<i128, QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        Ok(id_escrow::collect_escrow_fees(&env, token, to))
+    }
+
+    /// Escrow fees withheld in `token` that the admin has not collected yet (read-only).
+    pub fn collected_fees(env: Env, token: Address) -> i128 {
+        id_escrow::collected_fees(&env, &token)
+    }
+
+    /// Set the ceiling on id-based escrow amounts (**Admin only**).
+    ///
+    /// Every escrow creation path rejects amounts above `max` with `AmountTooLarge`.
+    /// Without a ceiling any `u64` amount is accepted.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the contract admin
+    /// * `max` - Largest accepted escrow amount, in token base units
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    pub fn set_max_escrow_amount(env: Env, caller: Address, max: u64) -> Result<(), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        set_max_escrow_amount(&env, max);
+        Ok(())
+    }
+
+    /// Get the ceiling on id-based escrow amounts (read-only).
+    ///
+    /// Returns `u64::MAX` when no ceiling is set.
+    pub fn max_escrow_amount(env: Env) -> u64 {
+        get_max_escrow_amount(&env)
+    }
+
+    /// Set the escrow fee discount for senders at a privacy level (**Admin only**).
+    ///
+    /// On release the sender's stored level (0 if unconfigured) selects the discount,
+    /// which is subtracted from the base fee.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the contract admin
+    /// * `level` - Sender privacy level the discount applies to
+    /// * `discount_bps` - Discount in basis points
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    /// * `InvalidFeeBps` - `discount_bps` exceeds 10000
+    pub fn set_fee_discount_bps(
+        env: Env,
+        caller: Address,
+        level: u32,
+        discount_bps: u32,
+    ) -> Result<(), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        id_escrow::set_fee_discount_bps(&env, level, discount_bps)
+    }
+
+    /// Get the fee discount for senders at a privacy level, in basis points (read-only).
+    pub fn fee_discount_bps(env: Env, level: u32) -> u32 {
+        id_escrow::fee_discount_bps(&env, level)
+    }
+
+    /// Get the escrow fee charged to senders at a privacy level, in basis points (read-only).
+    ///
+    /// Equals the base fee minus the level's discount, floored at zero.
+    pub fn effective_fee_bps(env: Env, level: u32) -> u32 {
+        id_escrow::effective_fee_bps(&env, level)
+    }
+
+    /// Refund a pending id-based escrow to its sender, at the recipient's request.
+    ///
+    /// Token-backed escrows return their principal to the sender. No cancel rebate is
+    /// credited.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Escrow id
+    /// * `caller` - Escrow recipient (must authorize)
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `AlreadySpent` - Escrow is not `Pending`
+    /// * `Unauthorized` - Caller is not the recipient
+    pub fn refund_escrow(env: Env, id: u64, caller: Address) -> Result<(), QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        id_escrow::refund_escrow(&env, id, caller)
+    }
+
+    /// Cancel a pending id-based escrow.
+    ///
+    /// The recipient may cancel at any time. The sender may only cancel once the
+    /// escrow's expiry ledger is reached, so escrows without expiry cannot be
+    /// cancelled by the sender. Cancelling a token-backed escrow within the grace window
+    /// configured by [`set_cancel_rebate`](QuickexContract::set_cancel_rebate) pays the
+    /// sender a rebate from the token's [`rebate_pool`](QuickexContract::rebate_pool).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Escrow id
+    /// * `caller` - Escrow sender or recipient (must authorize)
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `AlreadySpent` - Escrow is not `Pending`
+    /// * `Unauthorized` - Caller is neither the sender nor the recipient
+    /// * `EscrowNotExpired` - The sender cancels before the expiry ledger
+    pub fn cancel_escrow(env: Env, id: u64, caller: Address) -> Result<(), QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        id_escrow::cancel_escrow(&env, id, caller)
+    }
+
+    /// Configure the rebate for quickly cancelled id-based escrows (**Admin only**).
+    ///
+    /// A token-backed escrow cancelled fewer than `grace_ledgers` ledgers after creation
+    /// pays `amount` of its token to its sender, taken from the pool filled by
+    /// [`fund_rebate_pool`](QuickexContract::fund_rebate_pool). The rebate is capped at
+    /// 10% of the escrowed amount and an empty pool pays nothing. No rebate applies
+    /// when unset.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the contract admin
+    /// * `amount` - Flat rebate per qualifying cancellation
+    /// * `grace_ledgers` - Ledgers after creation during which a cancellation qualifies
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    pub fn set_cancel_rebate(
+        env: Env,
+        caller: Address,
+        amount: u64,
+        grace_ledgers: u32,
+    ) -> Result<(), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        id_escrow::set_cancel_rebate(&env, amount, grace_ledgers);
+        Ok(())
+    }
+
+    /// Get the number of a sender's id-based escrows that are still pending or disputed
+    /// (read-only).
+    pub fn open_escrow_count(env: Env, sender: Address) -> u32 {
+        id_escrow::open_escrow_count(&env, &sender)
+    }
+
+    /// Rebuild a sender's cached open-escrow count from their escrow index (**Admin only**).
+    ///
+    /// Each call reads the next 2 index pages (128 escrows) and returns the count so far
+    /// and the number of pages left. Call again until no pages are left; that final call
+    /// overwrites the cached count with the total.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the contract admin
+    /// * `sender` - The sender whose count to recompute
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    pub fn repair_open_count(
+        env: Env,
+        caller: Address,
+        sender: Address,
+    ) -> Result<(u32, u32), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        Ok(id_escrow::repair_open_count(&env, &sender))
+    }
+
+    /// Add funds to the quick-cancellation rebate pool of `token`.
+    ///
+    /// Rebates are only ever paid from this pool, so the total paid out is bounded by
+    /// what has been funded.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `funder` - Account supplying the funds (must authorize)
+    /// * `token` - Token of the pool
+    /// * `amount` - Amount to add
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `InvalidAmount` - `amount` is not positive
+    pub fn fund_rebate_pool(
+        env: Env,
+        funder: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        id_escrow::fund_rebate_pool(&env, funder, token, amount)
+    }
+
+    /// Get the funds left in the quick-cancellation rebate pool of `token` (read-only).
+    pub fn rebate_pool(env: Env, token: Address) -> i128 {
+        id_escrow::rebate_pool(&env, &token)
+    }
+
+    /// Dispute a pending id-based escrow, freezing it until the admin resolves it.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Escrow id
+    /// * `caller` - Sender or recipient of the escrow (must authorize)
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `AlreadySpent` - Escrow is not `Pending`
+    /// * `Unauthorized` - Caller is neither the sender nor the recipient
+    pub fn dispute_escrow(env: Env, id: u64, caller: Address) -> Result<(), QuickexError> {
+        id_escrow::dispute_escrow(&env, id, caller)
+    }
+
+    /// Refund the sender of an id-based escrow whose dispute went unresolved.
+    ///
+    /// Either party may call once `dispute_deadline_ledgers` ledgers have passed since
+    /// [`dispute_escrow`](QuickexContract::dispute_escrow).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Escrow id
+    /// * `caller` - Sender or recipient of the escrow (must authorize)
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `EscrowNotDisputed` - Escrow is not `Disputed`
+    /// * `Unauthorized` - Caller is neither the sender nor the recipient
+    /// * `DisputeWindowOpen` - The dispute deadline has not been reached
+    pub fn resolve_dispute_timeout(env: Env, id: u64, caller: Address) -> Result<(), QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        id_escrow::resolve_dispute_timeout(&env, id, caller)
+    }
+
+    /// Set how many ledgers a dispute may stay unresolved (**Admin only**).
+    ///
+    /// After this window either party can call
+    /// [`resolve_dispute_timeout`](QuickexContract::resolve_dispute_timeout).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the contract admin
+    /// * `ledgers` - Dispute window in ledgers
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    pub fn set_dispute_deadline_ledgers(
+        env: Env,
+        caller: Address,
+        ledgers: u32,
+    ) -> Result<(), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        set_dispute_deadline_ledgers(&env, ledgers);
+        Ok(())
+    }
+
+    /// Get the dispute window in ledgers (read-only).
+    ///
+    /// Defaults to 120,960 ledgers (about a week) when unset.
+    pub fn dispute_deadline_ledgers(env: Env) -> u32 {
+        id_escrow::dispute_deadline_ledgers(&env)
+    }
+
+    /// Get the arbiter of an id-based escrow (read-only).
+    ///
+    /// The arbiter appointed via [`change_arbiter`](QuickexContract::change_arbiter), or
+    /// the admin when none was appointed.
+    pub fn arbiter_of(env: Env, id: u64) -> Option<Address> {
+        id_escrow::arbiter_of(&env, id)
+    }
+
+    /// Appoint a new arbiter for an id-based escrow that has not been resolved.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Escrow id
+    /// * `new_arbiter` - Address that will resolve disputes on the escrow
+    ///
+    /// Both the sender and the recipient must authorize.
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `AlreadySpent` - Escrow is already in a terminal state
+    pub fn change_arbiter(env: Env, id: u64, new_arbiter: Address) -> Result<(), QuickexError> {
+        id_escrow::change_arbiter(&env, id, new_arbiter)
+    }
+
+    /// Resolve a disputed id-based escrow as its arbiter.
+    ///
+    /// The escrow's arbiter fee, if any, is withheld from the refund or payout and paid
+    /// to the arbiter. Emits the same `EscrowForceResolved` event as
+    /// [`admin_force_resolve`](QuickexContract::admin_force_resolve), naming the arbiter.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - The escrow's arbiter (must authorize)
+    /// * `id` - Escrow id
+    /// * `refund_to_sender` - Refund the sender if `true`, otherwise pay the recipient
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `EscrowNotDisputed` - Escrow is not `Disputed`
+    /// * `Unauthorized` - Caller is not the escrow's arbiter
+    pub fn resolve_dispute(
+        env: Env,
+        caller: Address,
+        id: u64,
+        refund_to_sender: bool,
+    ) -> Result<(), QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        id_escrow::resolve_dispute(&env, id, caller, refund_to_sender)
+    }
+
+    /// Forcibly resolve a stuck id-based escrow (**Admin only**).
+    ///
+    /// Break-glass recovery tool: resolves a `Pending` or `Disputed` escrow without
+    /// checking its lock, approvals, or expiry, and emits an `EscrowForceResolved`
+    /// governance event.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the contract admin
+    /// * `id` - Escrow id
+    /// * `refund_to_sender` - Refund the sender if `true`, otherwise pay the recipient
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `AlreadySpent` - Escrow is already in a terminal state
+    pub fn admin_force_resolve(
+        env: Env,
+        caller: Address,
+        id: u64,
+        refund_to_sender: bool,
+    ) -> Result<(), QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        id_escrow::admin_force_resolve(&env, id, caller, refund_to_sender)
+    }
+
+    /// Create an id-based escrow that becomes refundable at `expiry_ledger`.
+    ///
+    /// Like [`create_unfunded_escrow`](QuickexContract::create_unfunded_escrow), but once
+    /// the ledger sequence reaches `expiry_ledger` anyone may settle it for the sender via
+    /// [`claim_expired`](QuickexContract::claim_expired).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from` - Sender of the escrow (must authorize)
+    /// * `to` - Recipient paid on release
+    /// * `amount` - Amount in token base units
+    /// * `expiry_ledger` - Ledger sequence from which the escrow is refundable
+    ///
+    /// # Errors
+    /// * `InvalidExpiry` - `expiry_ledger` is not after the current ledger sequence
+    /// * `ExpiryLedgerFull` - 50 pending escrows already expire at `expiry_ledger`
+    /// * `InvalidAmount` - `amount` is zero
+    /// * `SelfEscrow` - `from` and `to` are the same address
+    /// * `AmountTooLarge` - `amount` exceeds the ceiling set by `set_max_escrow_amount`
+    pub fn create_escrow_with_expiry(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: u64,
+        expiry_ledger: u32,
+    ) -> Result<u64, QuickexError> {
+        id_escrow::create_escrow_with_expiry(&env, from, to, amount, expiry_ledger)
+    }
+
+    /// Create an id-based escrow to a named identity resolved through a registry.
+    ///
+    /// Cross-calls `registry.resolve(name)` and stores the resolved address as the
+    /// recipient; later registry changes do not affect the escrow.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from` - Sender of the escrow (must authorize)
+    /// * `name` - Registered name of the recipient
+    /// * `amount` - Amount in token base units
+    /// * `registry` - Name registry contract exposing `resolve(name) -> Option<Address>`
+    ///
+    /// # Errors
+    /// * `NameNotResolved` - The registry has no address for `name` or the call failed
+    /// * `InvalidAmount` - `amount` is zero
+    /// * `SelfEscrow` - `name` resolves to `from`
+    /// * `AmountTooLarge` - `amount` exceeds the ceiling set by `set_max_escrow_amount`
+    pub fn create_escrow_to_name(
+        env: Env,
+        from: Address,
+        name: Symbol,
+        amount: u64,
+        registry: Address,
+    ) -> Result<u64, QuickexError> {
+        id_escrow::create_escrow_to_name(&env, from, name, amount, registry)
+    }
+
+    /// Compute a commitment over the full terms of an escrow (read-only).
+    ///
+    /// Lets both parties agree on the terms off-chain before posting them with
+    /// [`open_escrow_terms`](QuickexContract::open_escrow_terms).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from` - Sender of the escrow
+    /// * `to` - Recipient paid on release
+    /// * `amount` - Amount in token base units
+    /// * `expiry_ledger` - Ledger sequence from which the escrow is refundable (0 = never)
+    /// * `salt` - Random bytes (within the salt policy) for uniqueness
+    ///
+    /// # Errors
+    /// * `InvalidSalt` - Salt length violates the salt policy
+    pub fn create_escrow_term_commitment(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: u64,
+        expiry_ledger: u32,
+        salt: Bytes,
+    ) -> Result<BytesN<32>, QuickexError> {
+        id_escrow::create_escrow_term_commitment(&env, from, to, amount, expiry_ledger, salt)
+    }
+
+    /// Create an escrow with exactly the terms bound by `term_commitment`.
+    ///
+    /// The terms are re-hashed and must match the commitment; the escrow is then created
+    /// as by [`create_unfunded_escrow`](QuickexContract::create_unfunded_escrow), or by
+    /// [`create_escrow_with_expiry`](QuickexContract::create_escrow_with_expiry) when
+    /// `expiry_ledger` is non-zero.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `term_commitment` - Commitment from `create_escrow_term_commitment`
+    /// * `from` / `to` / `amount` / `expiry_ledger` / `salt` - The committed terms (`from` must authorize)
+    ///
+    /// # Errors
+    /// * `InvalidSalt` - Salt length violates the salt policy
+    /// * `CommitmentMismatch` - The terms do not match the commitment
+    /// * `InvalidExpiry` - `expiry_ledger` is non-zero and not after the current ledger
+    /// * `InvalidAmount` - `amount` is zero
+    /// * `SelfEscrow` - `from` and `to` are the same address
+    /// * `AmountTooLarge` - `amount` exceeds the ceiling set by `set_max_escrow_amount`
+    pub fn open_escrow_terms(
+        env: Env,
+        term_commitment: BytesN<32>,
+        from: Address,
+        to: Address,
+        amount: u64,
+        expiry_ledger: u32,
+        salt: Bytes,
+    ) -> Result<u64, QuickexError> {
+        id_escrow::open_escrow_terms(&env, term_commitment, from, to, amount, expiry_ledger, salt)
+    }
+
+    /// Refund an expired id-based escrow to its sender.
+    ///
+    /// Callable by anyone once the escrow's expiry ledger is reached.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Escrow id
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `AlreadySpent` - Escrow is not `Pending`
+    /// * `EscrowNotExpired` - Escrow has no expiry or has not reached it yet
+    pub fn claim_expired(env: Env, id: u64) -> Result<(), QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        id_escrow::claim_expired(&env, id)
+    }
+
+    /// Mark an id-based escrow that has reached its expiry ledger as `Expired`.
+    ///
+    /// Callable by anyone. The funds stay in escrow until the sender pulls them with
+    /// [`claim_expired_funds`](QuickexContract::claim_expired_funds).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Escrow id
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `AlreadySpent` - Escrow is not `Pending`
+    /// * `EscrowNotExpired` - Escrow has no expiry or has not reached it yet
+    pub fn mark_expired(env: Env, id: u64) -> Result<(), QuickexError> {
+        id_escrow::mark_expired(&env, id)
+    }
+
+    /// Refund an `Expired` id-based escrow to its sender.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Escrow id; its sender must authorize
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `EscrowNotExpired` - Escrow has not been marked expired yet
+    /// * `AlreadySpent` - Escrow is already settled
+    pub fn claim_expired_funds(env: Env, id: u64) -> Result<(), QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        id_escrow::claim_expired_funds(&env, id)
+    }
+
+    /// Refund escrows that have reached their expiry ledger, about `limit` at a time.
+    ///
+    /// Callable by anyone. Resumes from a stored cursor, refunds the ids
+    /// [`due_refunds`](QuickexContract::due_refunds) reports from it up to the current
+    /// ledger, emitting an `EscrowExpired` event for each, and returns them. Reads at most
+    /// 128 expiry ledgers per call, so keepers call it repeatedly to catch up.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `limit` - Maximum number of escrows to refund
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    pub fn sweep_expired(env: Env, limit: u32) -> Result<Vec<u64>, QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        Ok(id_escrow::sweep_expired(&env, limit))
+    }
+
+    /// List escrows that are due for an expiry refund (read-only).
+    ///
+    /// Returns the ids of `Pending` escrows whose expiry ledger lies in
+    /// `from_ledger..=up_to_ledger`, earliest first, and the ledger to resume from. At
+    /// most 128 expiry ledgers are read per call, and the scan stops after the ledger
+    /// that brings the result to `limit` ids. Keepers page through the range and call
+    /// [`claim_expired`](QuickexContract::claim_expired) for each id.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from_ledger` - First expiry ledger to read
+    /// * `up_to_ledger` - Inclusive upper bound on the expiry ledger
+    /// * `limit` - Number of ids after which to stop
+    pub fn due_refunds(
+        env: Env,
+        from_ledger: u32,
+        up_to_ledger: u32,
+        limit: u32,
+    ) -> (Vec<u64>, u32) {
+        id_escrow::due_refunds(&env, from_ledger, up_to_ledger, limit)
+    }
+
+    /// Create an empty escrow campaign and return its id.
     ///
-    /// The caller (`to`) must authorize; the commitment is recomputed from `to`, `amount`, and `salt`
-    /// and must match an existing pending escrow entry.
+    /// Campaigns group id-based escrows, e.g. for crowdfunding-style batches.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `_token` - Reserved; token is stored in the escrow entry
-    /// * `amount` - Amount to withdraw; must be positive and match the escrow amount
-    /// * `commitment` - Commitment hash for the escrow being withdrawn
-    /// * `to` - Recipient address (must authorize the call)
-    /// * `salt` - Salt used when creating the original deposit commitment
+    /// * `owner` - Campaign owner (must authorize)
+    pub fn create_campaign(env: Env, owner: Address) -> u64 {
+        id_escrow::create_campaign(&env, owner)
+    }
+
+    /// Attach a pending id-based escrow to a campaign.
+    ///
+    /// Both the campaign owner and the escrow sender must authorize.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `campaign_id` - Campaign id returned by [`create_campaign`](QuickexContract::create_campaign)
+    /// * `escrow_id` - Escrow id to attach
+    ///
+    /// # Errors
+    /// * `CampaignNotFound` - No campaign exists for `campaign_id`
+    /// * `EscrowNotFound` - No escrow exists for `escrow_id`
+    /// * `AlreadySpent` - Escrow is not `Pending`
+    /// * `AlreadyInCampaign` - Escrow already belongs to a campaign
+    pub fn add_escrow_to_campaign(
+        env: Env,
+        campaign_id: u64,
+        escrow_id: u64,
+    ) -> Result<(), QuickexError> {
+        id_escrow::add_escrow_to_campaign(&env, campaign_id, escrow_id)
+    }
+
+    /// Release every pending escrow of a campaign in one call.
+    ///
+    /// Escrows already in a terminal state (released, refunded, cancelled), escrows still
+    /// in their minimum lock period, and M-of-N escrows short of approvals are skipped.
+    /// Returns the number of escrows released.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `campaign_id` - Campaign id
+    /// * `caller` - Campaign owner or contract admin (must authorize)
     ///
     /// # Errors
-    /// * `InvalidAmount` - Amount is zero or negative
     /// * `ContractPaused` - Contract is currently paused
-    /// * `CommitmentMismatch` - Provided commitment does not match (`to`, `amount`, `salt`)
-    /// * `CommitmentNotFound` - No escrow exists for the provided commitment
-    /// * `EscrowExpired` - Escrow has passed its expiry timestamp
-    /// * `AlreadySpent` - Escrow has already been withdrawn or refunded
-    /// * `InvalidCommitment` - Escrow amount does not match the requested amount
-    pub fn withdraw(
+    /// * `CampaignNotFound` - No campaign exists for `campaign_id`
+    /// * `Unauthorized` - Caller is neither the campaign owner nor the admin
+    pub fn release_campaign(
         env: Env,
-        _token: &Address,
-        amount: i128,
-        _commitment: BytesN<32>,
-        to: Address,
-        salt: Bytes,
-    ) -> Result<bool, QuickexError> {
+        campaign_id: u64,
+        caller: Address,
+    ) -> Result<u32, QuickexError> {
         if is_paused(&env) {
             return Err(QuickexError::ContractPaused);
         }
-        escrow::withdraw(&env, amount, to, salt)
+        id_escrow::release_campaign(&env, campaign_id, caller)
     }
 
-    /// Set a numeric privacy level for an account (legacy/level-based API).
+    /// List the escrow ids attached to a campaign, in attachment order (read-only).
     ///
-    /// Records the level in storage and appends it to the account's privacy history.
-    /// For boolean on/off privacy, prefer [`set_privacy`](QuickexContract::set_privacy).
+    /// Returns an empty vector for an unknown campaign.
+    pub fn campaign_escrows(env: Env, campaign_id: u64) -> Vec<u64> {
+        id_escrow::campaign_escrows(&env, campaign_id)
+    }
+
+    /// Append a commitment to the contract's commitment Merkle tree.
+    ///
+    /// Returns the leaf index. The root is updated incrementally from a stored frontier,
+    /// hashing one path per insertion.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `account` - The account to configure
-    /// * `privacy_level` - Numeric level (0 = off, higher = more privacy; interpretation is application-specific)
-    pub fn enable_privacy(env: Env, account: Address, privacy_level: u32) -> bool {
-        set_privacy_level(&env, &account, privacy_level);
-        add_privacy_history(&env, &account, privacy_level);
-        true
+    /// * `commitment` - Leaf to append
+    ///
+    /// # Errors
+    /// * `MerkleTreeFull` - Every leaf of the tree is taken
+    pub fn insert_commitment(env: Env, commitment: BytesN<32>) -> Result<u32, QuickexError> {
+        merkle::insert_commitment(&env, commitment)
     }
 
-    /// Get the current numeric privacy level for an account.
+    /// Get the current root of the commitment Merkle tree (read-only).
+    pub fn merkle_root(env: Env) -> BytesN<32> {
+        merkle::merkle_root(&env)
+    }
+
+    /// Get the number of leaves in the commitment Merkle tree (read-only).
+    pub fn tree_size(env: Env) -> u32 {
+        merkle::tree_size(&env)
+    }
+
+    /// Check that a list of leaves rebuilds to `root` (read-only).
+    ///
+    /// Recomputes the whole tree from `leaves` in order; use it to audit
+    /// [`merkle_root`](QuickexContract::merkle_root).
+    pub fn verify_root_from_leaves(env: Env, leaves: Vec<BytesN<32>>, root: BytesN<32>) -> bool {
+        merkle::verify_root_from_leaves(&env, leaves, root)
+    }
+
+    /// Derive the nullifier of a note (read-only).
     ///
-    /// Returns `None` if no level has been set.
+    /// Returns `sha256(owner_xdr || commitment || secret)`, the value
+    /// [`spend_nullifier`](QuickexContract::spend_nullifier) adds to the spent set when
+    /// the note is consumed.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `account` - The account to query
-    pub fn privacy_status(env: Env, account: Address) -> Option<u32> {
-        get_privacy_level(&env, &account)
+    /// * `owner` - Owner of the note
+    /// * `commitment` - Commitment of the note
+    /// * `secret` - Secret known only to the owner
+    pub fn compute_nullifier(
+        env: Env,
+        owner: Address,
+        commitment: Bytes,
+        secret: Bytes,
+    ) -> BytesN<32> {
+        nullifier::compute_nullifier(&env, &owner, &commitment, &secret)
     }
 
-    /// Get the history of privacy level changes for an account.
+    /// Consume a note, adding its nullifier to the contract's spent set.
     ///
-    /// Returns a vector of levels in chronological order (oldest first).
+    /// The nullifier is derived as by
+    /// [`compute_nullifier`](QuickexContract::compute_nullifier) and returned. Only the
+    /// note's owner can spend it.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `account` - The account to query
-    pub fn privacy_history(env: Env, account: Address) -> Vec<u32> {
-        get_privacy_history(&env, &account)
+    /// * `owner` - Owner of the note (must authorize)
+    /// * `commitment` - Commitment of the note
+    /// * `secret` - Secret known only to the owner
+    ///
+    /// # Errors
+    /// * `AlreadySpent` - The note was spent before
+    pub fn spend_nullifier(
+        env: Env,
+        owner: Address,
+        commitment: Bytes,
+        secret: Bytes,
+    ) -> Result<BytesN<32>, QuickexError> {
+        nullifier::spend_nullifier(&env, &owner, &commitment, &secret)
     }
 
-    /// Enable or disable privacy for an account.
+    /// Register a commitment to a voter's governance weight.
+    ///
+    /// The commitment is an amount commitment owned by `voter`, as produced by
+    /// [`create_amount_commitment`](QuickexContract::create_amount_commitment). The
+    /// weight stays hidden until revealed at tally time.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `owner` - The account address to configure
-    /// * `enabled` - `true` to enable privacy, `false` to disable
+    /// * `voter` - The voter (must authorize)
+    /// * `weight_commitment` - Commitment to the voter's weight
     ///
     /// # Errors
-    /// * `ContractPaused` - Contract is currently paused
-    /// * `PrivacyAlreadySet` - Privacy state is already at the requested value
-    pub fn set_privacy(env: Env, owner: Address, enabled: bool) -> Result<(), QuickexError> {
-        if is_paused(&env) {
-            return Err(QuickexError::ContractPaused);
-        }
-        privacy::set_privacy(&env, owner, enabled)
+    /// * `CommitmentAlreadyExists` - The voter already registered a weight
+    pub fn register_vote_weight(
+        env: Env,
+        voter: Address,
+        weight_commitment: BytesN<32>,
+    ) -> Result<(), QuickexError> {
+        voting::register_vote_weight(&env, &voter, weight_commitment)
     }
 
-    /// Check the current privacy status of an account
+    /// Reveal a voter's registered weight, recording it for the tally.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `owner` - The account address to query
+    /// * `voter` - The voter (must authorize)
+    /// * `weight` - Committed weight
+    /// * `salt` - Salt used for the commitment
     ///
-    /// # Returns
-    /// * `bool` - Current privacy status (true = enabled)
-    pub fn get_privacy(env: Env, owner: Address) -> bool {
-        privacy::get_privacy(&env, owner)
+    /// # Errors
+    /// * `CommitmentNotFound` - The voter never registered a weight
+    /// * `AlreadySpent` - The weight was already revealed
+    /// * `CommitmentMismatch` - `weight` and `salt` do not open the registered commitment
+    pub fn reveal_vote_weight(
+        env: Env,
+        voter: Address,
+        weight: i128,
+        salt: Bytes,
+    ) -> Result<(), QuickexError> {
+        voting::reveal_vote_weight(&env, &voter, weight, salt)
     }
 
-    /// Deposit funds and create an escrow entry keyed by `SHA256(owner || amount || salt)`.
+    /// Get a voter's revealed weight (read-only). `None` until revealed.
+    pub fn vote_weight(env: Env, voter: Address) -> Option<i128> {
+        voting::vote_weight(&env, &voter)
+    }
+
+    /// Check whether a nullifier has been spent (read-only).
+    pub fn is_spent(env: Env, nullifier: BytesN<32>) -> bool {
+        nullifier::is_spent(&env, &nullifier)
+    }
+
+    /// Check the spent status of several nullifiers at once (read-only).
     ///
-    /// Transfers `amount` from `owner` to the contract and stores an escrow entry.
+    /// Returns one flag per input nullifier, in order.
+    pub fn are_spent(env: Env, nullifiers: Vec<BytesN<32>>) -> Vec<bool> {
+        nullifier::are_spent(&env, nullifiers)
+    }
+
+    /// Succeed only if a nullifier has not been spent.
+    ///
+    /// A guard that callers, including other contracts, can compose before acting
+    /// on a note.
+    ///
+    /// # Errors
+    /// * `AlreadySpent` - The nullifier is in the spent set
+    pub fn assert_unspent(env: Env, nullifier: BytesN<32>) -> Result<(), QuickexError> {
+        nullifier::assert_unspent(&env, &nullifier)
+    }
+
+    /// Get an id-based escrow record (read-only).
+    ///
+    /// Returns `None` if no escrow exists for `id`.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `token` - The token contract address
-    /// * `amount` - Amount to deposit; must be positive
-    /// * `owner` - Owner of the funds (must authorize)
-    /// * `salt` - Random salt (0–1024 bytes) for uniqueness
-    /// * `timeout_secs` - Seconds from now until the escrow expires (0 = no expiry)
+    /// * `id` - Escrow id returned by [`create_escrow`](QuickexContract::create_escrow)
+    pub fn get_escrow(env: Env, id: u64) -> Option<EscrowData> {
+        get_escrow_data(&env, id)
+    }
+
+    /// Get the status of several id-based escrows in one call (read-only).
+    ///
+    /// Returns one entry per id, in order, with `None` for unknown ids.
+    pub fn get_escrows_status(env: Env, ids: Vec<u64>) -> Vec<Option<EscrowStatus>> {
+        id_escrow::get_escrows_status(&env, ids)
+    }
+
+    /// Check whether `addr` is the sender of an id-based escrow (read-only).
+    ///
+    /// Returns `false` for an unknown escrow.
+    pub fn is_escrow_sender(env: Env, id: u64, addr: Address) -> bool {
+        id_escrow::is_escrow_sender(&env, id, &addr)
+    }
+
+    /// Check whether `addr` is the recipient of an id-based escrow (read-only).
+    ///
+    /// Returns `false` for an unknown escrow.
+    pub fn is_escrow_recipient(env: Env, id: u64, addr: Address) -> bool {
+        id_escrow::is_escrow_recipient(&env, id, &addr)
+    }
+
+    /// Check whether two id-based escrows share the same sender (read-only).
+    ///
+    /// Returns `false` if either escrow is unknown.
+    pub fn escrows_same_sender(env: Env, id_a: u64, id_b: u64) -> bool {
+        id_escrow::escrows_same_sender(&env, id_a, id_b)
+    }
+
+    /// Check whether an id-based escrow was created before ledger `ledger` (read-only).
+    ///
+    /// Compares against the stored `created_ledger`, strictly. Returns `false` for an
+    /// unknown escrow.
+    pub fn escrow_created_before(env: Env, id: u64, ledger: u32) -> bool {
+        id_escrow::escrow_created_before(&env, id, ledger)
+    }
+
+    /// Ids of the id-based escrows sent by `sender` on index page `page`, in creation
+    /// order (read-only).
+    ///
+    /// Each page covers [`id_escrow::ESCROW_INDEX_PAGE_LEN`] slots; escrows migrated to
+    /// another sender leave a gap, so a page may hold fewer ids. Pages run from `0` to
+    /// [`sender_escrow_pages`](Self::sender_escrow_pages) exclusive.
+    pub fn escrows_by_sender(env: Env, sender: Address, page: u32) -> Vec<u64> {
+        id_escrow::escrows_by_sender(&env, &sender, page)
+    }
+
+    /// Number of pages in the index of id-based escrows sent by `sender` (read-only).
+    pub fn sender_escrow_pages(env: Env, sender: Address) -> u32 {
+        id_escrow::sender_escrow_pages(&env, &sender)
+    }
+
+    /// Ids of the id-based escrows sent by `from` on index page `page` that are in
+    /// `status`, in creation order (read-only).
+    ///
+    /// Filters one page of [`escrows_by_sender`](Self::escrows_by_sender) by status, as
+    /// there is no per-status index. An empty page does not mean no matches are left:
+    /// walk pages `0` to [`sender_escrow_pages`](Self::sender_escrow_pages) exclusive to
+    /// collect every match.
+    pub fn escrows_by_sender_and_status(
+        env: Env,
+        from: Address,
+        status: EscrowStatus,
+        page: u32,
+    ) -> Vec<u64> {
+        id_escrow::escrows_by_sender_and_status(&env, &from, status, page)
+    }
+
+    /// Ids of the id-based escrows received by `recipient` on index page `page`, in
+    /// creation order (read-only). Paged like [`escrows_by_sender`](Self::escrows_by_sender).
+    pub fn escrows_by_recipient(env: Env, recipient: Address, page: u32) -> Vec<u64> {
+        id_escrow::escrows_by_recipient(&env, &recipient, page)
+    }
+
+    /// Number of pages in the index of id-based escrows received by `recipient`
+    /// (read-only).
+    pub fn recipient_escrow_pages(env: Env, recipient: Address) -> u32 {
+        id_escrow::recipient_escrow_pages(&env, &recipient)
+    }
+
+    /// Move a pending id-based escrow from party `old` to address `new`.
+    ///
+    /// Replaces `from` or `to`, whichever equals `old`, and updates the
+    /// per-party indexes.
+    ///
+    /// # Arguments
+    /// * `caller` - Must authorize; either `old` or, for tokenless escrows only,
+    ///   the contract admin
+    /// * `id` - Escrow id
+    /// * `old` - Current sender or recipient
+    /// * `new` - Replacement address
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - No escrow for `id`
+    /// * `AlreadySpent` - Escrow is not pending
+    /// * `Unauthorized` - Caller is neither `old` nor the admin, or is the admin
+    ///   and the escrow is token-backed
+    /// * `InvalidOwner` - `old` is not a party to the escrow
+    /// * `SelfEscrow` - `new` is the escrow's other party
+    pub fn migrate_escrow_party(
+        env: Env,
+        caller: Address,
+        id: u64,
+        old: Address,
+        new: Address,
+    ) -> Result<(), QuickexError> {
+        id_escrow::migrate_escrow_party(&env, id, caller, old, new)
+    }
+
+    /// Export the id-based escrows with ids in `start..end` (**Admin only**).
+    ///
+    /// Intended for backups and migrations. Ids without a record are skipped.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the contract admin
+    /// * `start` - First id of the range (inclusive)
+    /// * `end` - End of the range (exclusive)
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin, or admin not set
+    /// * `InvalidRange` - `start > end` or the range spans more than 100 ids
+    pub fn export_escrows(
+        env: Env,
+        caller: Address,
+        start: u64,
+        end: u64,
+    ) -> Result<Vec<EscrowData>, QuickexError> {
+        admin::require_admin(&env, &caller)?;
+        id_escrow::export_escrows(&env, start, end)
+    }
+
+    /// Release an escrow against an off-chain delivery receipt.
+    ///
+    /// Verifies that `signer` — which must be the escrow sender — signed
+    /// [`receipt_message`](QuickexContract::receipt_message) for `id` and `receipt_hash`
+    /// with its ed25519 account key, then releases the escrow to the recipient.
+    /// An invalid signature reverts the call; each receipt of a sender is accepted once.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Escrow id
+    /// * `receipt_hash` - 32-byte hash of the delivery receipt
+    /// * `signature` - 64-byte ed25519 signature over the receipt message
+    /// * `signer` - Sender account whose key produced `signature`
     ///
     /// # Errors
-    /// * `InvalidAmount` - Amount is zero or negative
-    /// * `InvalidSalt` - Salt length exceeds 1024 bytes
     /// * `ContractPaused` - Contract is currently paused
-    /// * `CommitmentAlreadyExists` - An escrow for this commitment already exists
-    pub fn deposit(
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `AlreadySpent` - Escrow is not `Pending`, or `receipt_hash` was already used
+    /// * `Unauthorized` - `signer` is not the escrow sender
+    /// * `InvalidSignature` - Signature is malformed or `signer` has no ed25519 key
+    /// * `LockNotElapsed` - The escrow's minimum lock period has not passed
+    /// * `InsufficientApprovals` - An M-of-N escrow has not reached its threshold
+    pub fn release_with_receipt(
         env: Env,
-        token: Address,
-        amount: i128,
-        owner: Address,
-        salt: Bytes,
-        timeout_secs: u64,
-    ) -> Result<BytesN<32>, QuickexError> {
+        id: u64,
+        receipt_hash: BytesN<32>,
+        signature: Bytes,
+        signer: Address,
+    ) -> Result<(), QuickexError> {
         if is_paused(&env) {
             return Err(QuickexError::ContractPaused);
         }
-        escrow::deposit(&env, token, amount, owner, salt, timeout_secs)
+        id_escrow::release_with_receipt(&env, id, receipt_hash, signature, signer)
     }
 
-    /// Create a deterministic commitment hash for an amount (off-chain / pre-deposit use).
+    /// Message the sender signs for [`release_with_receipt`](QuickexContract::release_with_receipt) (read-only).
     ///
-    /// Computes `SHA256(owner || amount || salt)`. Not a zero-knowledge proof; same inputs
-    /// always yield the same hash. Use for API shape validation and audit trails.
+    /// Message = `sha256(XDR(contract) || BE64(id) || receipt_hash)`, binding the
+    /// signature to one escrow of this contract.
+    pub fn receipt_message(env: Env, id: u64, receipt_hash: BytesN<32>) -> BytesN<32> {
+        id_escrow::receipt_message(&env, id, &receipt_hash)
+    }
+
+    /// Create an id-based escrow released by revealing the preimage of a hash lock.
+    ///
+    /// Enables HTLC-style atomic swaps: the counterparty claims with
+    /// [`claim_hashlock`](QuickexContract::claim_hashlock), disclosing the preimage,
+    /// until `timeout_ledger`; after that the sender is refunded with
+    /// [`refund_hashlock`](QuickexContract::refund_hashlock).
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `owner` - The owner address
-    /// * `amount` - Non-negative amount in token base units
-    /// * `salt` - Random bytes (0–1024 bytes) for uniqueness
+    /// * `from` - Sender of the escrow (must authorize)
+    /// * `to` - Recipient paid on claim
+    /// * `amount` - Amount in token base units
+    /// * `hashlock` - SHA-256 hash of the secret preimage
+    /// * `timeout_ledger` - Ledger from which the claim closes and the sender can be refunded
     ///
     /// # Errors
-    /// * `InvalidAmount` - Amount is negative
-    /// * `InvalidSalt` - Salt length exceeds 1024 bytes
-    pub fn create_amount_commitment(
+    /// * `InvalidExpiry` - `timeout_ledger` is not in the future
+    /// * `ExpiryLedgerFull` - 50 pending escrows already expire at `timeout_ledger`
+    /// * `InvalidAmount` - `amount` is zero
+    /// * `SelfEscrow` - `from` and `to` are the same address
+    /// * `AmountTooLarge` - `amount` exceeds the ceiling set by `set_max_escrow_amount`
+    pub fn create_hashlock_escrow(
         env: Env,
-        owner: Address,
-        amount: i128,
-        salt: Bytes,
-    ) -> Result<BytesN<32>, QuickexError> {
-        commitment::create_amount_commitment(&env, owner, amount, salt)
+        from: Address,
+        to: Address,
+        amount: u64,
+        hashlock: BytesN<32>,
+        timeout_ledger: u32,
+    ) -> Result<u64, QuickexError> {
+        id_escrow::create_hashlock_escrow(&env, from, to, amount, hashlock, timeout_ledger)
     }
 
-    /// Verify that a commitment hash matches the given `owner`, `amount`, and `salt`.
+    /// Release a hashlock escrow to its recipient by revealing the preimage.
     ///
-    /// Recomputes the commitment and compares. Returns `false` if inputs are invalid or don't match.
+    /// Anyone may call; the funds always go to the escrow's recipient.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `commitment` - 32-byte commitment hash to verify
-    /// * `owner` - Claimed owner
-    /// * `amount` - Claimed amount (must be non-negative)
-    /// * `salt` - Salt used when creating the commitment
-    pub fn verify_amount_commitment(
-        env: Env,
-        commitment: BytesN<32>,
-        owner: Address,
-        amount: i128,
-        salt: Bytes,
-    ) -> bool {
-        commitment::verify_amount_commitment(&env, commitment, owner, amount, salt)
+    /// * `id` - Escrow id
+    /// * `preimage` - Bytes whose SHA-256 hash equals the escrow's hash lock
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is currently paused
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `AlreadySpent` - Escrow is not `Pending`
+    /// * `HashlockMismatch` - Escrow has no hash lock or the preimage does not match it
+    /// * `EscrowExpired` - The escrow's timeout ledger has been reached
+    pub fn claim_hashlock(env: Env, id: u64, preimage: Bytes) -> Result<(), QuickexError> {
+        if is_paused(&env) {
+            return Err(QuickexError::ContractPaused);
+        }
+        id_escrow::claim_hashlock(&env, id, preimage)
     }
 
-    /// Create an escrow record and increment the global escrow counter.
+    /// Refund an unclaimed hashlock escrow to its sender after its timeout.
     ///
-    /// Returns the new counter value. Parameters `_from`, `_to`, `_amount` are reserved for
-    /// future use; the implementation only increments the counter.
+    /// Anyone may call.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `_from` - Reserved (depositor address for future use)
-    /// * `_to` - Reserved (recipient address for future use)
-    /// * `_amount` - Reserved (amount for future use)
-    pub fn create_escrow(env: Env, _from: Address, _to: Address, _amount: u64) -> u64 {
-        increment_escrow_counter(&env)
+    /// * `id` - Escrow id
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - No escrow exists for `id`
+    /// * `HashlockMismatch` - Escrow has no hash lock
+    /// * `AlreadySpent` - Escrow was already claimed or refunded
+    /// * `EscrowNotExpired` - The timeout ledger has not been reached
+    pub fn refund_hashlock(env: Env, id: u64) -> Result<(), QuickexError> {
+        id_escrow::refund_hashlock(&env, id)
     }
 
     /// Health check for deployment and monitoring.
@@ -284,6 +2974,44 @@ impl QuickexContract {
         escrow::refund(&env, commitment, caller)
     }
 
+    /// Get the amount of `token` held by open escrows (read-only).
+    ///
+    /// Tracked incrementally as escrows open and settle, so it excludes direct
+    /// transfers and any other funds the contract holds in `token`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token` - Token contract address
+    pub fn locked_balance(env: Env, token: Address) -> i128 {
+        escrow::locked_balance(&env, &token)
+    }
+
+    /// Reconcile the contract's token holdings against its open escrows (read-only).
+    ///
+    /// Returns the contract's on-chain balance of `token` minus the sum of amounts held by
+    /// open escrows in that token. Zero in the healthy case; a nonzero result signals
+    /// accounting drift: positive for unaccounted surplus, negative for a shortfall.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token` - Token contract address to reconcile
+    pub fn reconcile(env: Env, token: Address) -> i128 {
+        escrow::reconcile(&env, token)
+    }
+
+    /// Get the contract's `token` balance in basis points of its obligations (read-only).
+    ///
+    /// Obligations are open escrow principals. `10_000` means exactly covered; lower
+    /// values signal under-collateralization.
+    /// Returns `u32::MAX` when nothing is owed.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token` - Token contract address
+    pub fn solvency_ratio_bps(env: Env, token: Address) -> u32 {
+        escrow::solvency_ratio_bps(&env, token)
+    }
+
     /// Initialize the contract with an admin address (one-time only).
     ///
     /// Sets the admin who can pause/unpause, transfer admin, and upgrade the contract.
@@ -349,6 +3077,14 @@ impl QuickexContract {
         is_paused(&env)
     }
 
+    /// Get all admin-configurable settings in one call (read-only).
+    ///
+    /// Reports the escrow fee, escrow amount ceiling, maximum salt length, pause flag,
+    /// default privacy level, and dispute deadline. Unset settings show their defaults.
+    pub fn get_config(env: Env) -> ContractConfig {
+        admin::get_config(&env)
+    }
+
     /// Get the current admin address.
     ///
     /// Returns `None` if the contract has not been initialized.
@@ -356,6 +3092,14 @@ impl QuickexContract {
         get_admin(&env)
     }
 
+    /// Check whether the contract has been initialized.
+    ///
+    /// Returns `true` once [`initialize`](QuickexContract::initialize) has stored an admin,
+    /// `false` otherwise. Lets clients and keepers check setup state before calling admin functions.
+    pub fn is_initialized(env: Env) -> bool {
+        get_admin(&env).is_some()
+    }
+
     /// Get the status of an escrow by its commitment hash (read-only).
     ///
     /// Returns `Pending`, `Spent`, `Expired`, or `Refunded` if an escrow exists; `None` otherwise.
@@ -380,19 +3124,8 @@ impl QuickexContract {
     /// * `salt` - Salt used when creating the deposit
     /// * `owner` - Owner of the escrow
     pub fn verify_proof_view(env: Env, amount: i128, salt: Bytes, owner: Address) -> bool {
-        let commitment_result =
-            commitment::create_amount_commitment(&env, owner.clone(), amount, salt);
-
-        let commitment = match commitment_result {
-            Ok(c) => c,
-            Err(_) => return false,
-        };
-
-        let commitment_bytes: Bytes = commitment.into();
-        let entry: Option<EscrowEntry> = get_escrow(&env, &commitment_bytes);
-
-        match entry {
-            Some(e) => {
+        match escrow::find_escrow(&env, &owner, amount, salt) {
+            Ok((_, e)) => {
                 if e.status != EscrowStatus::Pending {
                     return false;
                 }
@@ -401,7 +3134,7 @@ impl QuickexContract {
                 }
                 e.amount == amount
             }
-            None => false,
+            Err(_) => false,
         }
     }
 
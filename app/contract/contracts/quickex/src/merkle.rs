@@ -0,0 +1,109 @@
+//! Append-only Merkle tree of commitments with an incrementally maintained root.
+//!
+//! The tree has a fixed depth of [`MERKLE_DEPTH`]; unfilled leaves are zero. Only the
+//! frontier is stored: for every level, the last left-hand node still waiting for its
+//! right sibling. Inserting a leaf walks one path to the root, so it hashes
+//! `MERKLE_DEPTH` nodes however large the tree grows.
+//!
+//! Node = SHA256(left || right). An empty subtree of height `h` hashes to
+//! `zero[h]`, with `zero[0] = 0^32` and `zero[h + 1] = SHA256(zero[h] || zero[h])`.
+
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+use crate::{
+    errors::QuickexError,
+    storage::{
+        get_merkle_frontier, get_merkle_root, get_merkle_size, set_merkle_frontier,
+        set_merkle_root, set_merkle_size,
+    },
+};
+
+/// Number of levels between the leaves and the root.
+pub const MERKLE_DEPTH: u32 = 20;
+
+fn hash_pair(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &left.to_array());
+    preimage.extend_from_array(&right.to_array());
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Root of a tree with no leaves.
+fn empty_root(env: &Env) -> BytesN<32> {
+    let mut zero = BytesN::from_array(env, &[0u8; 32]);
+    for _ in 0..MERKLE_DEPTH {
+        zero = hash_pair(env, &zero, &zero);
+    }
+    zero
+}
+
+/// Append `commitment` as the next leaf and return its index.
+///
+/// # Errors
+/// - [`QuickexError::MerkleTreeFull`] – all `2^MERKLE_DEPTH` leaves are taken.
+pub fn insert_commitment(env: &Env, commitment: BytesN<32>) -> Result<u32, QuickexError> {
+    let index = get_merkle_size(env);
+    if index >= 1 << MERKLE_DEPTH {
+        return Err(QuickexError::MerkleTreeFull);
+    }
+
+    let mut frontier = get_merkle_frontier(env);
+    let mut node = commitment;
+    let mut zero = BytesN::from_array(env, &[0u8; 32]);
+    let mut position = index;
+    for level in 0..MERKLE_DEPTH {
+        if position & 1 == 0 {
+            // Left child: it becomes the pending node of this level.
+            if level < frontier.len() {
+                frontier.set(level, node.clone());
+            } else {
+                frontier.push_back(node.clone());
+            }
+            node = hash_pair(env, &node, &zero);
+        } else {
+            node = hash_pair(env, &frontier.get_unchecked(level), &node);
+        }
+        zero = hash_pair(env, &zero, &zero);
+        position >>= 1;
+    }
+
+    set_merkle_frontier(env, &frontier);
+    set_merkle_root(env, &node);
+    set_merkle_size(env, index + 1);
+    Ok(index)
+}
+
+/// Current root of the tree.
+pub fn merkle_root(env: &Env) -> BytesN<32> {
+    get_merkle_root(env).unwrap_or_else(|| empty_root(env))
+}
+
+/// Number of leaves inserted so far.
+pub fn tree_size(env: &Env) -> u32 {
+    get_merkle_size(env)
+}
+
+/// Returns `true` when `leaves`, in order, rebuild to `root`.
+///
+/// Recomputes the tree level by level from scratch, as a cross-check of the
+/// incremental root. `false` if there are more leaves than the tree can hold.
+pub fn verify_root_from_leaves(env: &Env, leaves: Vec<BytesN<32>>, root: BytesN<32>) -> bool {
+    if leaves.len() > 1 << MERKLE_DEPTH {
+        return false;
+    }
+
+    let mut layer = leaves;
+    let mut zero = BytesN::from_array(env, &[0u8; 32]);
+    for _ in 0..MERKLE_DEPTH {
+        let mut parents = Vec::new(env);
+        let mut i = 0;
+        while i < layer.len() {
+            let left = layer.get_unchecked(i);
+            let right = layer.get(i + 1).unwrap_or(zero.clone());
+            parents.push_back(hash_pair(env, &left, &right));
+            i += 2;
+        }
+        layer = parents;
+        zero = hash_pair(env, &zero, &zero);
+    }
+    layer.first().unwrap_or(zero) == root
+}
@@ -0,0 +1,60 @@
+//! Commitment Merkle tree tests.
+//!
+//! Covers incremental insertion and cross-checks the root against a full rebuild.
+
+use crate::{QuickexContract, QuickexContractClient};
+use soroban_sdk::{vec, BytesN, Env, Vec};
+
+fn setup<'a>() -> (Env, QuickexContractClient<'a>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(QuickexContract, ());
+    let client = QuickexContractClient::new(&env, &contract_id);
+    (env, client)
+}
+
+fn leaf(env: &Env, n: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[n; 32])
+}
+
+#[test]
+fn test_empty_tree_root_matches_rebuild() {
+    let (env, client) = setup();
+
+    assert_eq!(client.tree_size(), 0);
+    assert!(client.verify_root_from_leaves(&Vec::new(&env), &client.merkle_root()));
+}
+
+#[test]
+fn test_incremental_root_matches_rebuild() {
+    let (env, client) = setup();
+    let mut leaves = Vec::new(&env);
+
+    for n in 1..=17u8 {
+        let index = client.insert_commitment(&leaf(&env, n));
+        leaves.push_back(leaf(&env, n));
+
+        assert_eq!(index, n as u32 - 1);
+        assert_eq!(client.tree_size(), n as u32);
+        assert!(client.verify_root_from_leaves(&leaves, &client.merkle_root()));
+    }
+}
+
+#[test]
+fn test_verify_root_from_leaves_rejects_wrong_leaves() {
+    let (env, client) = setup();
+    client.insert_commitment(&leaf(&env, 1));
+    client.insert_commitment(&leaf(&env, 2));
+    client.insert_commitment(&leaf(&env, 3));
+    let root = client.merkle_root();
+
+    assert!(client.verify_root_from_leaves(
+        &vec![&env, leaf(&env, 1), leaf(&env, 2), leaf(&env, 3)],
+        &root
+    ));
+    assert!(!client.verify_root_from_leaves(
+        &vec![&env, leaf(&env, 2), leaf(&env, 1), leaf(&env, 3)],
+        &root
+    ));
+    assert!(!client.verify_root_from_leaves(&vec![&env, leaf(&env, 1), leaf(&env, 2)], &root));
+}
@@ -0,0 +1,75 @@
+//! Nullifier set: one-time tags that mark a private note as consumed.
+//!
+//! A nullifier is a 32-byte value derived from a note with [`compute_nullifier`]. Once
+//! spent it stays in the set forever, so the same note cannot be consumed twice.
+//! Nothing about the note itself is stored. Because the owner is part of the
+//! derivation and must authorize the spend, nobody else can burn their nullifier.
+
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+use crate::{
+    errors::QuickexError,
+    storage::{is_nullifier_spent, set_nullifier_spent},
+};
+
+/// Derive the nullifier of a note as `sha256(owner_xdr || commitment || secret)`.
+///
+/// Deterministic, so the spend flow can recompute it from the note and its secret.
+pub fn compute_nullifier(
+    env: &Env,
+    owner: &Address,
+    commitment: &Bytes,
+    secret: &Bytes,
+) -> BytesN<32> {
+    let mut preimage = owner.clone().to_xdr(env);
+    preimage.append(commitment);
+    preimage.append(secret);
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Succeed only if `nullifier` has not been spent.
+///
+/// Composable guard for flows that must prove non-membership before acting.
+///
+/// # Errors
+/// - [`QuickexError::AlreadySpent`] – the nullifier is in the spent set.
+pub fn assert_unspent(env: &Env, nullifier: &BytesN<32>) -> Result<(), QuickexError> {
+    if is_nullifier_spent(env, nullifier) {
+        return Err(QuickexError::AlreadySpent);
+    }
+    Ok(())
+}
+
+/// Derive the nullifier of `owner`'s note and add it to the spent set.
+///
+/// `owner` must authorize. Returns the spent nullifier.
+///
+/// # Errors
+/// - [`QuickexError::AlreadySpent`] – the nullifier was spent before.
+pub fn spend_nullifier(
+    env: &Env,
+    owner: &Address,
+    commitment: &Bytes,
+    secret: &Bytes,
+) -> Result<BytesN<32>, QuickexError> {
+    owner.require_auth();
+
+    let nullifier = compute_nullifier(env, owner, commitment, secret);
+    assert_unspent(env, &nullifier)?;
+    set_nullifier_spent(env, &nullifier);
+    Ok(nullifier)
+}
+
+/// Returns `true` when `nullifier` is in the spent set.
+pub fn is_spent(env: &Env, nullifier: &BytesN<32>) -> bool {
+    is_nullifier_spent(env, nullifier)
+}
+
+/// Spent status of each of `nullifiers`, in input order.
+pub fn are_spent(env: &Env, nullifiers: Vec<BytesN<32>>) -> Vec<bool> {
+    let mut statuses = Vec::new(env);
+    for nullifier in nullifiers.iter() {
+        statuses.push_back(is_nullifier_spent(env, &nullifier));
+    }
+    statuses
+}
@@ -0,0 +1,149 @@
+//! Nullifier set tests.
+//!
+//! Covers nullifier derivation, owner-bound spending, the `assert_unspent` guard, and
+//! batch status queries.
+
+use crate::{errors::QuickexError, QuickexContract, QuickexContractClient};
+use soroban_sdk::{testutils::Address as _, vec, xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+fn setup<'a>() -> (Env, QuickexContractClient<'a>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(QuickexContract, ());
+    let client = QuickexContractClient::new(&env, &contract_id);
+    (env, client)
+}
+
+/// Spend a note of `owner` identified by `tag` and return its nullifier.
+fn spend(env: &Env, client: &QuickexContractClient, owner: &Address, tag: u8) -> BytesN<32> {
+    let commitment = Bytes::from_array(env, &[tag; 32]);
+    let secret = Bytes::from_slice(env, b"note-secret");
+    client.spend_nullifier(owner, &commitment, &secret)
+}
+
+// ============================================================================
+// compute_nullifier
+// ============================================================================
+
+#[test]
+fn test_compute_nullifier_is_deterministic() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let commitment = Bytes::from_array(&env, &[7u8; 32]);
+    let secret = Bytes::from_slice(&env, b"note-secret");
+
+    let nullifier = client.compute_nullifier(&owner, &commitment, &secret);
+    assert_eq!(
+        client.compute_nullifier(&owner, &commitment, &secret),
+        nullifier
+    );
+
+    let mut preimage = owner.clone().to_xdr(&env);
+    preimage.append(&commitment);
+    preimage.append(&secret);
+    let expected: BytesN<32> = env.crypto().sha256(&preimage).into();
+    assert_eq!(nullifier, expected);
+}
+
+#[test]
+fn test_compute_nullifier_changes_with_secret() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let commitment = Bytes::from_array(&env, &[7u8; 32]);
+
+    let first = client.compute_nullifier(&owner, &commitment, &Bytes::from_slice(&env, b"a"));
+    let second = client.compute_nullifier(&owner, &commitment, &Bytes::from_slice(&env, b"b"));
+    assert_ne!(first, second);
+
+    client.spend_nullifier(&owner, &commitment, &Bytes::from_slice(&env, b"a"));
+    assert!(client.is_spent(&first));
+    assert!(!client.is_spent(&second));
+}
+
+// ============================================================================
+// spend_nullifier
+// ============================================================================
+
+#[test]
+fn test_spend_nullifier_derives_and_requires_owner() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let commitment = Bytes::from_array(&env, &[8u8; 32]);
+    let secret = Bytes::from_slice(&env, b"note-secret");
+
+    let spent = client.spend_nullifier(&owner, &commitment, &secret);
+    assert_eq!(env.auths()[0].0, owner);
+
+    assert_eq!(
+        spent,
+        client.compute_nullifier(&owner, &commitment, &secret)
+    );
+    assert!(client.is_spent(&spent));
+}
+
+#[test]
+fn test_spend_nullifier_by_other_owner_leaves_note_unspent() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let commitment = Bytes::from_array(&env, &[9u8; 32]);
+    let secret = Bytes::from_slice(&env, b"note-secret");
+
+    client.spend_nullifier(&attacker, &commitment, &secret);
+
+    assert!(!client.is_spent(&client.compute_nullifier(&owner, &commitment, &secret)));
+    client.spend_nullifier(&owner, &commitment, &secret);
+}
+
+// ============================================================================
+// assert_unspent
+// ============================================================================
+
+#[test]
+fn test_assert_unspent_passes_for_fresh_nullifier() {
+    let (env, client) = setup();
+    let nullifier = BytesN::from_array(&env, &[1u8; 32]);
+
+    assert_eq!(client.try_assert_unspent(&nullifier), Ok(Ok(())));
+    assert!(!client.is_spent(&nullifier));
+}
+
+#[test]
+fn test_assert_unspent_reverts_after_spend() {
+    let (env, client) = setup();
+    let spender = Address::generate(&env);
+    let nullifier = spend(&env, &client, &spender, 2);
+
+    assert!(client.is_spent(&nullifier));
+    let result = client.try_assert_unspent(&nullifier);
+    assert_eq!(result, Err(Ok(QuickexError::AlreadySpent)));
+}
+
+#[test]
+fn test_spend_nullifier_twice_fails() {
+    let (env, client) = setup();
+    let spender = Address::generate(&env);
+    spend(&env, &client, &spender, 3);
+
+    let commitment = Bytes::from_array(&env, &[3u8; 32]);
+    let secret = Bytes::from_slice(&env, b"note-secret");
+    let result = client.try_spend_nullifier(&spender, &commitment, &secret);
+    assert_eq!(result, Err(Ok(QuickexError::AlreadySpent)));
+}
+
+// ============================================================================
+// are_spent
+// ============================================================================
+
+#[test]
+fn test_are_spent_reports_each_nullifier_in_order() {
+    let (env, client) = setup();
+    let spender = Address::generate(&env);
+    let spent_a = spend(&env, &client, &spender, 4);
+    let spent_b = spend(&env, &client, &spender, 5);
+    let fresh = BytesN::from_array(&env, &[6u8; 32]);
+
+    let statuses = client.are_spent(&vec![&env, fresh.clone(), spent_a, fresh, spent_b]);
+    assert_eq!(statuses, vec![&env, false, true, false, true]);
+    assert_eq!(client.are_spent(&vec![&env]), vec![&env]);
+}
@@ -1,7 +1,41 @@
+use crate::admin::authorize;
 use crate::errors::QuickexError;
-use crate::events::publish_privacy_toggled;
-use crate::storage::PRIVACY_ENABLED_KEY;
-use soroban_sdk::{Address, Env, Symbol};
+use crate::events::{
+    publish_privacy_level_set, publish_privacy_level_set_redacted, publish_privacy_toggled,
+};
+use crate::storage::{
+    add_privacy_history, get_configured_account_count, get_default_privacy_level,
+    get_history_limit, get_last_privacy_change, get_level_histogram, get_privacy_checkpoint,
+    get_privacy_history, get_privacy_history_head, get_privacy_idem_result, get_privacy_level,
+    get_privacy_nonce, get_privacy_signer, get_salt_seed, has_viewer_grant, is_event_verbose,
+    is_privacy_frozen, is_privacy_sealed, is_viewer_blocked, remove_privacy_level,
+    set_configured_account_count, set_event_verbose, set_history_limit, set_last_privacy_change,
+    set_level_histogram, set_privacy_checkpoint, set_privacy_frozen, set_privacy_history,
+    set_privacy_history_head, set_privacy_idem_result, set_privacy_level, set_privacy_nonce,
+    set_privacy_sealed, set_privacy_signer, set_salt_seed, set_viewer_blocked, set_viewer_grant,
+    PRIVACY_ENABLED_KEY,
+};
+use crate::types::AccountPrivacy;
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Map, Symbol, Vec};
+
+/// Sentinel pushed to an account's privacy history when its level is revoked.
+pub const REVOKED_PRIVACY_LEVEL: u32 = u32::MAX;
+
+/// Sentinel that replaces a privacy history entry redacted by the admin.
+pub const REDACTED_PRIVACY_LEVEL: u32 = u32::MAX - 1;
+
+/// Privacy level from which an account's escrow creation events are redacted to the id.
+pub const HIGH_PRIVACY_LEVEL: u32 = 2;
+
+/// Returns `true` when `account` has a privacy level of at least [`HIGH_PRIVACY_LEVEL`].
+pub fn is_high_privacy(env: &Env, account: &Address) -> bool {
+    get_privacy_level(env, account).is_some_and(|level| level >= HIGH_PRIVACY_LEVEL)
+}
+
+/// Global maximum number of entries kept in an account's privacy history.
+///
+/// Applies to accounts without their own limit and bounds per-account limits.
+pub const MAX_PRIVACY_HISTORY: u32 = 32;
 
 /// Enable or disable privacy for an account.
 ///
@@ -39,3 +73,528 @@ pub fn get_privacy(env: &Env, owner: Address) -> bool {
         .get(&(key, owner))
         .unwrap_or(false)
 }
+
+/// Set a numeric privacy level for an account and record it in the history.
+///
+/// The first level set on an unconfigured account increments the configured-account
+/// counter; later changes leave it untouched.
+///
+/// Publishes a `PrivacyLevelSet` event with the level if the account opted into
+/// verbose events, and an account-only `PrivacyLevelSetRedacted` event otherwise.
+///
+/// The account, or its registered `operator`, must authorize.
+///
+/// # Errors
+/// - [`QuickexError::Unauthorized`] – `operator` is not the account's registered operator.
+/// - [`QuickexError::InvalidPrivacyLevel`] – `level` is a history sentinel.
+/// - [`QuickexError::PrivacySealed`] – the account sealed its settings.
+/// - [`QuickexError::PrivacyFrozen`] – the admin froze the account's settings.
+pub fn enable_privacy(
+    env: &Env,
+    account: &Address,
+    level: u32,
+    operator: Option<Address>,
+) -> Result<(), QuickexError> {
+    authorize(env, account, operator)?;
+    apply_level(env, account, level)
+}
+
+/// Set the level without checking authorization; see [`enable_privacy`].
+///
+/// Levels from [`REDACTED_PRIVACY_LEVEL`] up are history sentinels and are rejected.
+fn apply_level(env: &Env, account: &Address, level: u32) -> Result<(), QuickexError> {
+    if level >= REDACTED_PRIVACY_LEVEL {
+        return Err(QuickexError::InvalidPrivacyLevel);
+    }
+    if is_privacy_sealed(env, account) {
+        return Err(QuickexError::PrivacySealed);
+    }
+    if is_privacy_frozen(env, account) {
+        return Err(QuickexError::PrivacyFrozen);
+    }
+    let previous = get_privacy_level(env, account);
+    if previous.is_none() {
+        set_configured_account_count(env, get_configured_account_count(env) + 1);
+    }
+    move_level_count(env, previous, Some(level));
+    set_privacy_level(env, account, level);
+    record_history(env, account, level);
+    if is_event_verbose(env, account) {
+        publish_privacy_level_set(env, account.clone(), level);
+    } else {
+        publish_privacy_level_set_redacted(env, account.clone());
+    }
+    Ok(())
+}
+
+/// Choose whether the account's privacy level events include the level.
+pub fn set_event_verbosity(env: &Env, account: &Address, verbose: bool) {
+    account.require_auth();
+    set_event_verbose(env, account, verbose);
+}
+
+/// Returns `true` when the account's privacy level events include the level.
+pub fn event_verbosity(env: &Env, account: &Address) -> bool {
+    is_event_verbose(env, account)
+}
+
+/// Apply [`enable_privacy`] at most once per `idem_key` and return its result.
+///
+/// A repeated key returns the recorded result without touching the level or the
+/// history, so retried client transactions are safe.
+pub fn enable_privacy_idempotent(
+    env: &Env,
+    account: &Address,
+    level: u32,
+    idem_key: &BytesN<32>,
+) -> bool {
+    account.require_auth();
+
+    if let Some(result) = get_privacy_idem_result(env, account, idem_key) {
+        return result;
+    }
+    let result = apply_level(env, account, level).is_ok();
+    set_privacy_idem_result(env, account, idem_key, result);
+    result
+}
+
+/// Clear an account's numeric privacy level.
+///
+/// Pushes [`REVOKED_PRIVACY_LEVEL`] to the history and decrements the
+/// configured-account counter. The account, or its registered `operator`, must authorize.
+///
+/// # Errors
+/// - [`QuickexError::Unauthorized`] – `operator` is not the account's registered operator.
+/// - [`QuickexError::PrivacySealed`] – the account sealed its settings.
+/// - [`QuickexError::PrivacyNotConfigured`] – the account has no level set.
+/// - [`QuickexError::PrivacyFrozen`] – the admin froze the account's settings.
+pub fn revoke_privacy(
+    env: &Env,
+    account: &Address,
+    operator: Option<Address>,
+) -> Result<(), QuickexError> {
+    authorize(env, account, operator)?;
+    clear_privacy(env, account)
+}
+
+/// Revoke the privacy level of every listed account. The caller must already be
+/// checked as admin.
+///
+/// Unconfigured, sealed, and frozen accounts are skipped. Returns the number of
+/// accounts revoked.
+pub fn revoke_privacy_bulk(env: &Env, accounts: Vec<Address>) -> u32 {
+    let mut revoked = 0;
+    for account in accounts.iter() {
+        if clear_privacy(env, &account).is_ok() {
+            revoked += 1;
+        }
+    }
+    revoked
+}
+
+/// Set a distinct privacy level on each listed account, `levels[i]` for `accounts[i]`.
+/// The caller must already be checked as admin.
+///
+/// Both lists and every level are validated before any account is changed.
+///
+/// # Errors
+/// - [`QuickexError::LengthMismatch`] – `accounts` and `levels` differ in length.
+/// - [`QuickexError::InvalidPrivacyLevel`] – a level is one of the reserved sentinels.
+/// - [`QuickexError::PrivacySealed`] – an account sealed its settings.
+/// - [`QuickexError::PrivacyFrozen`] – the admin froze an account's settings.
+pub fn enable_privacy_batch_levels(
+    env: &Env,
+    accounts: Vec<Address>,
+    levels: Vec<u32>,
+) -> Result<(), QuickexError> {
+    if accounts.len() != levels.len() {
+        return Err(QuickexError::LengthMismatch);
+    }
+    if levels.iter().any(|level| level >= REDACTED_PRIVACY_LEVEL) {
+        return Err(QuickexError::InvalidPrivacyLevel);
+    }
+    for (account, level) in accounts.iter().zip(levels.iter()) {
+        apply_level(env, &account, level)?;
+    }
+    Ok(())
+}
+
+fn clear_privacy(env: &Env, account: &Address) -> Result<(), QuickexError> {
+    if is_privacy_sealed(env, account) {
+        return Err(QuickexError::PrivacySealed);
+    }
+    if is_privacy_frozen(env, account) {
+        return Err(QuickexError::PrivacyFrozen);
+    }
+    let Some(previous) = get_privacy_level(env, account) else {
+        return Err(QuickexError::PrivacyNotConfigured);
+    };
+    move_level_count(env, Some(previous), None);
+    remove_privacy_level(env, account);
+    record_history(env, account, REVOKED_PRIVACY_LEVEL);
+    set_configured_account_count(env, get_configured_account_count(env) - 1);
+    Ok(())
+}
+
+/// Return the number of history entries retained for an account.
+pub fn history_limit(env: &Env, account: &Address) -> u32 {
+    get_history_limit(env, account).unwrap_or(MAX_PRIVACY_HISTORY)
+}
+
+/// Override the global history cap for an account.
+///
+/// Existing history beyond the new limit is dropped immediately, oldest first.
+///
+/// # Errors
+/// - [`QuickexError::InvalidHistoryLimit`] – `limit` is zero or above [`MAX_PRIVACY_HISTORY`].
+pub fn set_account_history_limit(
+    env: &Env,
+    account: &Address,
+    limit: u32,
+) -> Result<(), QuickexError> {
+    account.require_auth();
+
+    if limit == 0 || limit > MAX_PRIVACY_HISTORY {
+        return Err(QuickexError::InvalidHistoryLimit);
+    }
+    set_history_limit(env, account, limit);
+    trim_history(env, account, limit);
+    Ok(())
+}
+
+/// Push `level` to the front of the history, drop entries beyond the account's limit,
+/// and extend the history hash chain.
+fn record_history(env: &Env, account: &Address, level: u32) {
+    add_privacy_history(env, account, level);
+    trim_history(env, account, history_limit(env, account));
+    let head = chain_link(env, &privacy_history_head(env, account), level);
+    set_privacy_history_head(env, account, &head);
+    set_last_privacy_change(env, account, env.ledger().sequence());
+}
+
+/// Return the ledger sequence of the account's latest privacy level change.
+///
+/// `None` if the account's level never changed.
+pub fn last_privacy_change(env: &Env, account: &Address) -> Option<u32> {
+    get_last_privacy_change(env, account)
+}
+
+/// Overwrite all privacy state of `account` with values migrated from elsewhere.
+/// The caller must already be checked as admin.
+///
+/// `history` is newest first, as returned by `privacy_history`, and its newest entry
+/// must equal `level`. The history hash chain is rebuilt over `history` and
+/// `last_change` is stored as the latest change ledger. Frozen accounts are not
+/// exempt; sealed accounts are.
+///
+/// # Errors
+/// - [`QuickexError::PrivacySealed`] – the account sealed its settings.
+/// - [`QuickexError::InvalidPrivacyLevel`] – `level` is the revoke sentinel, or the
+///   newest history entry differs from `level`.
+/// - [`QuickexError::InvalidHistoryLimit`] – `history` exceeds the account's history limit.
+pub fn import_privacy(
+    env: &Env,
+    account: &Address,
+    level: u32,
+    history: Vec<u32>,
+    last_change: u32,
+) -> Result<(), QuickexError> {
+    if is_privacy_sealed(env, account) {
+        return Err(QuickexError::PrivacySealed);
+    }
+    if level == REVOKED_PRIVACY_LEVEL {
+        return Err(QuickexError::InvalidPrivacyLevel);
+    }
+    if history.first().is_some_and(|newest| newest != level) {
+        return Err(QuickexError::InvalidPrivacyLevel);
+    }
+    if history.len() > history_limit(env, account) {
+        return Err(QuickexError::InvalidHistoryLimit);
+    }
+
+    let previous = get_privacy_level(env, account);
+    if previous.is_none() {
+        set_configured_account_count(env, get_configured_account_count(env) + 1);
+    }
+    move_level_count(env, previous, Some(level));
+    set_privacy_level(env, account, level);
+    set_privacy_history(env, account, &history);
+
+    let mut head = BytesN::from_array(env, &[0u8; 32]);
+    for entry in history.iter().rev() {
+        head = chain_link(env, &head, entry);
+    }
+    set_privacy_history_head(env, account, &head);
+    set_last_privacy_change(env, account, last_change);
+    Ok(())
+}
+
+/// Replace the entry at `index` of the account's privacy history (newest first) with
+/// [`REDACTED_PRIVACY_LEVEL`]. The caller must already be checked as admin.
+///
+/// Other entries keep their positions and the history keeps its length. The hash chain
+/// head is left alone, so it still commits to the original levels; redacting the
+/// newest entry makes [`verify_history_consistency`] report `false`.
+///
+/// # Errors
+/// - [`QuickexError::InvalidRange`] – `index` is not within the retained history.
+pub fn redact_history_entry(env: &Env, account: &Address, index: u32) -> Result<(), QuickexError> {
+    let mut history = get_privacy_history(env, account);
+    if index >= history.len() {
+        return Err(QuickexError::InvalidRange);
+    }
+    history.set(index, REDACTED_PRIVACY_LEVEL);
+    set_privacy_history(env, account, &history);
+    Ok(())
+}
+
+/// Next link of the history hash chain: `sha256(prev || BE32(level))`.
+fn chain_link(env: &Env, prev: &BytesN<32>, level: u32) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &prev.to_array());
+    preimage.append(&Bytes::from_array(env, &level.to_be_bytes()));
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Return the head of the account's privacy history hash chain.
+///
+/// The chain starts from 32 zero bytes and covers every level ever recorded,
+/// including entries that were since trimmed from the retained history.
+pub fn privacy_history_head(env: &Env, account: &Address) -> BytesN<32> {
+    get_privacy_history_head(env, account).unwrap_or(BytesN::from_array(env, &[0u8; 32]))
+}
+
+/// Record the account's current history chain head as a checkpoint and return it.
+///
+/// Clients may prune the levels chained before a checkpoint and keep the checkpoint
+/// as their verifiable anchor. A later checkpoint supersedes the previous one.
+pub fn checkpoint_chain(env: &Env, account: &Address) -> BytesN<32> {
+    account.require_auth();
+
+    let head = privacy_history_head(env, account);
+    set_privacy_checkpoint(env, account, &head);
+    head
+}
+
+/// Return the account's latest checkpointed chain head, or `None` if it never checkpointed.
+pub fn latest_checkpoint(env: &Env, account: &Address) -> Option<BytesN<32>> {
+    get_privacy_checkpoint(env, account)
+}
+
+/// Returns `true` when chaining `levels`, oldest first, reproduces the stored head.
+pub fn verify_privacy_chain(env: &Env, account: &Address, levels: Vec<u32>) -> bool {
+    let mut head = BytesN::from_array(env, &[0u8; 32]);
+    for level in levels.iter() {
+        head = chain_link(env, &head, level);
+    }
+    head == privacy_history_head(env, account)
+}
+
+fn trim_history(env: &Env, account: &Address, limit: u32) {
+    let history = get_privacy_history(env, account);
+    if history.len() > limit {
+        set_privacy_history(env, account, &history.slice(..limit));
+    }
+}
+
+/// Record a new salt-derivation seed commitment for an account.
+///
+/// Bumps the account's seed version, signalling that salts derived from earlier
+/// seeds are retired. Returns the new version, starting at 1.
+pub fn rotate_seed(env: &Env, account: &Address, new_seed_hash: BytesN<32>) -> u32 {
+    account.require_auth();
+
+    let version = current_seed_version(env, account) + 1;
+    set_salt_seed(env, account, version, &new_seed_hash);
+    version
+}
+
+/// Return the account's current seed version, or `0` if it never rotated a seed.
+pub fn current_seed_version(env: &Env, account: &Address) -> u32 {
+    get_salt_seed(env, account).map_or(0, |(version, _)| version)
+}
+
+/// Register the ed25519 key allowed to change `account`'s privacy level by signature.
+///
+/// Intended for smart-wallet accounts that authorize with a custom signature scheme.
+pub fn set_privacy_signer_key(env: &Env, account: &Address, pubkey: BytesN<32>) {
+    account.require_auth();
+    set_privacy_signer(env, account, &pubkey);
+}
+
+/// Return the nonce the next signed privacy change for `account` must carry.
+pub fn privacy_nonce(env: &Env, account: &Address) -> u64 {
+    get_privacy_nonce(env, account)
+}
+
+/// Canonical message signed to set `account`'s privacy level to `level`.
+///
+/// Message = XDR(account) || BE32(level) || BE64(nonce)
+pub fn privacy_sig_message(env: &Env, account: &Address, level: u32, nonce: u64) -> Bytes {
+    let mut message = account.clone().to_xdr(env);
+    message.append(&Bytes::from_array(env, &level.to_be_bytes()));
+    message.append(&Bytes::from_array(env, &nonce.to_be_bytes()));
+    message
+}
+
+/// Set a privacy level authorized by an ed25519 signature instead of `require_auth`.
+///
+/// `message` must equal [`privacy_sig_message`] for the account's current nonce and
+/// `pubkey` must be the account's registered privacy signer. The nonce is bumped on
+/// success so a signature cannot be replayed.
+///
+/// # Errors
+/// - [`QuickexError::Unauthorized`] – `pubkey` is not the account's registered signer.
+/// - [`QuickexError::InvalidSignature`] – `message` is not the canonical message or
+///   `signature` is not 64 bytes.
+/// - [`QuickexError::InvalidPrivacyLevel`] – `level` is a history sentinel.
+/// - [`QuickexError::PrivacySealed`] – the account sealed its settings.
+/// - [`QuickexError::PrivacyFrozen`] – the admin froze the account's settings.
+///
+/// A well-formed but invalid signature traps in the host and reverts the call.
+pub fn enable_privacy_sig(
+    env: &Env,
+    account: &Address,
+    level: u32,
+    message: Bytes,
+    signature: Bytes,
+    pubkey: BytesN<32>,
+) -> Result<(), QuickexError> {
+    if get_privacy_signer(env, account) != Some(pubkey.clone()) {
+        return Err(QuickexError::Unauthorized);
+    }
+
+    let nonce = get_privacy_nonce(env, account);
+    if message != privacy_sig_message(env, account, level, nonce) {
+        return Err(QuickexError::InvalidSignature);
+    }
+    let signature: BytesN<64> = signature
+        .try_into()
+        .map_err(|_| QuickexError::InvalidSignature)?;
+    env.crypto().ed25519_verify(&pubkey, &message, &signature);
+
+    apply_level(env, account, level)?;
+    set_privacy_nonce(env, account, nonce + 1);
+    Ok(())
+}
+
+/// Let `viewer` see `owner`'s private data.
+pub fn grant_viewer(env: &Env, owner: &Address, viewer: &Address) {
+    owner.require_auth();
+    set_viewer_grant(env, owner, viewer, true);
+}
+
+/// Withdraw a grant made with [`grant_viewer`].
+pub fn revoke_viewer(env: &Env, owner: &Address, viewer: &Address) {
+    owner.require_auth();
+    set_viewer_grant(env, owner, viewer, false);
+}
+
+/// Add or remove `viewer` from the admin blocklist. The caller must already be
+/// checked as admin.
+pub fn set_blocked(env: &Env, viewer: &Address, blocked: bool) {
+    set_viewer_blocked(env, viewer, blocked);
+}
+
+/// Returns `true` when `owner` granted `viewer` access and the admin has not
+/// blocked `viewer`.
+///
+/// The blocklist overrides every per-owner grant.
+pub fn can_view(env: &Env, owner: &Address, viewer: &Address) -> bool {
+    !is_viewer_blocked(env, viewer) && has_viewer_grant(env, owner, viewer)
+}
+
+/// Returns `true` when the newest history entry agrees with the current level.
+///
+/// A configured account's newest entry must equal its level; an unconfigured account's
+/// history must be empty or start with [`REVOKED_PRIVACY_LEVEL`].
+pub fn verify_history_consistency(env: &Env, account: &Address) -> bool {
+    let newest = get_privacy_history(env, account).first();
+    match (get_privacy_level(env, account), newest) {
+        (Some(level), Some(entry)) => level == entry,
+        (None, None) => true,
+        (None, Some(entry)) => entry == REVOKED_PRIVACY_LEVEL,
+        (Some(_), None) => false,
+    }
+}
+
+/// Freeze or unfreeze an account's privacy settings. The caller must already be
+/// checked as admin.
+///
+/// While frozen, the account's level can neither be changed nor revoked.
+pub fn set_frozen(env: &Env, account: &Address, frozen: bool) {
+    set_privacy_frozen(env, account, frozen);
+}
+
+/// Permanently seal the account's privacy settings. The account must authorize.
+///
+/// Afterwards its level can neither be changed, revoked, nor imported. There is no
+/// way to unseal; sealing again is a no-op.
+pub fn seal_privacy(env: &Env, account: &Address) {
+    account.require_auth();
+    set_privacy_sealed(env, account);
+}
+
+/// Returns `true` once the account has sealed its privacy settings.
+pub fn is_sealed(env: &Env, account: &Address) -> bool {
+    is_privacy_sealed(env, account)
+}
+
+/// Return the privacy summary of each account, in input order.
+pub fn account_summary_many(env: &Env, accounts: Vec<Address>) -> Vec<AccountPrivacy> {
+    let mut summaries = Vec::new(env);
+    for account in accounts.iter() {
+        let level = get_privacy_level(env, &account);
+        summaries.push_back(AccountPrivacy {
+            level,
+            history_len: get_privacy_history(env, &account).len(),
+            frozen: is_privacy_frozen(env, &account),
+            configured: level.is_some(),
+            account,
+        });
+    }
+    summaries
+}
+
+/// Return the account's privacy level, falling back to the deployment default.
+///
+/// `None` only when the account is unconfigured and no default is set.
+pub fn privacy_status(env: &Env, account: &Address) -> Option<u32> {
+    get_privacy_level(env, account).or_else(|| get_default_privacy_level(env))
+}
+
+/// Returns whether `a`'s privacy level is at least `b`'s.
+///
+/// Compares configured levels only: `None` when either account has no level of its own,
+/// regardless of the deployment default.
+pub fn privacy_level_gte(env: &Env, a: &Address, b: &Address) -> Option<bool> {
+    Some(get_privacy_level(env, a)? >= get_privacy_level(env, b)?)
+}
+
+/// Return the number of accounts currently at each privacy level.
+///
+/// Only levels with at least one account appear.
+pub fn privacy_histogram(env: &Env) -> Map<u32, u32> {
+    get_level_histogram(env)
+}
+
+/// Move one account from level `from` to level `to` in the per-level counters.
+///
+/// `None` stands for "unconfigured". Counters that drop to zero are removed.
+fn move_level_count(env: &Env, from: Option<u32>, to: Option<u32>) {
+    if from == to {
+        return;
+    }
+    let mut histogram = get_level_histogram(env);
+    if let Some(level) = from {
+        match histogram.get(level).unwrap_or(0) {
+            0 | 1 => {
+                histogram.remove(level);
+            }
+            count => histogram.set(level, count - 1),
+        }
+    }
+    if let Some(level) = to {
+        histogram.set(level, histogram.get(level).unwrap_or(0) + 1);
+    }
+    set_level_histogram(env, &histogram);
+}
@@ -0,0 +1,944 @@
+//! Level-based privacy tests.
+//!
+//! Covers `enable_privacy` / `revoke_privacy` and the aggregate queries built on them.
+
+use crate::{
+    errors::QuickexError,
+    privacy::{REDACTED_PRIVACY_LEVEL, REVOKED_PRIVACY_LEVEL},
+    storage,
+    types::AccountPrivacy,
+    QuickexContract, QuickexContractClient,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Bytes, BytesN, Env, IntoVal, Map, Symbol, Val,
+};
+
+fn setup<'a>() -> (Env, QuickexContractClient<'a>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(QuickexContract, ());
+    let client = QuickexContractClient::new(&env, &contract_id);
+    (env, client)
+}
+
+/// Register `signing_key` as `account`'s privacy signer and return its public key.
+fn register_signer(
+    env: &Env,
+    client: &QuickexContractClient,
+    account: &Address,
+    signing_key: &SigningKey,
+) -> BytesN<32> {
+    let pubkey = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+    client.set_privacy_signer(account, &pubkey);
+    pubkey
+}
+
+fn sign(env: &Env, signing_key: &SigningKey, message: &Bytes) -> Bytes {
+    let mut buf = [0u8; 256];
+    let len = message.len() as usize;
+    message.copy_into_slice(&mut buf[..len]);
+    Bytes::from_array(env, &signing_key.sign(&buf[..len]).to_bytes())
+}
+
+// ============================================================================
+// Default privacy level
+// ============================================================================
+
+#[test]
+fn test_privacy_status_unconfigured_is_none_without_default() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+
+    assert_eq!(client.privacy_status(&account), None);
+}
+
+#[test]
+fn test_privacy_status_falls_back_to_default() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let unconfigured = Address::generate(&env);
+    let configured = Address::generate(&env);
+    client.enable_privacy(&configured, &3, &None);
+
+    let result = client.try_set_default_privacy_level(&unconfigured, &1);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+    client.set_default_privacy_level(&admin, &1);
+
+    assert_eq!(client.privacy_status(&unconfigured), Some(1));
+    assert_eq!(client.privacy_status(&configured), Some(3));
+}
+
+// ============================================================================
+// privacy_level_gte
+// ============================================================================
+
+#[test]
+fn test_privacy_level_gte_compares_configured_levels() {
+    let (env, client) = setup();
+    let low = Address::generate(&env);
+    let high = Address::generate(&env);
+    let peer = Address::generate(&env);
+    client.enable_privacy(&low, &1, &None);
+    client.enable_privacy(&high, &3, &None);
+    client.enable_privacy(&peer, &3, &None);
+
+    assert_eq!(client.privacy_level_gte(&high, &low), Some(true));
+    assert_eq!(client.privacy_level_gte(&low, &high), Some(false));
+    assert_eq!(client.privacy_level_gte(&high, &peer), Some(true));
+}
+
+#[test]
+fn test_privacy_level_gte_unconfigured_is_none() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_default_privacy_level(&admin, &1);
+    let configured = Address::generate(&env);
+    let unconfigured = Address::generate(&env);
+    client.enable_privacy(&configured, &2, &None);
+
+    assert_eq!(client.privacy_level_gte(&configured, &unconfigured), None);
+    assert_eq!(client.privacy_level_gte(&unconfigured, &configured), None);
+}
+
+// ============================================================================
+// revoke_privacy
+// ============================================================================
+
+#[test]
+fn test_revoke_privacy_clears_level_and_records_sentinel() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+
+    client.enable_privacy(&account, &2, &None);
+    client.revoke_privacy(&account, &None);
+
+    assert_eq!(client.privacy_status(&account), None);
+    assert_eq!(client.privacy_history(&account), vec![&env, u32::MAX, 2]);
+}
+
+#[test]
+fn test_revoke_privacy_unconfigured_fails() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+
+    let result = client.try_revoke_privacy(&account, &None);
+    assert_eq!(result, Err(Ok(QuickexError::PrivacyNotConfigured)));
+}
+
+// ============================================================================
+// Operator authorization
+// ============================================================================
+
+#[test]
+fn test_enable_privacy_authorized_by_account() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+
+    client.enable_privacy(&account, &2, &None);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, account);
+    assert_eq!(client.privacy_status(&account), Some(2));
+}
+
+#[test]
+fn test_privacy_changes_authorized_by_operator() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    let operator = Address::generate(&env);
+    client.set_operator(&account, &Some(operator.clone()));
+    assert_eq!(client.operator_of(&account), Some(operator.clone()));
+
+    client.enable_privacy(&account, &2, &Some(operator.clone()));
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, operator);
+
+    client.revoke_privacy(&account, &Some(operator.clone()));
+    assert_eq!(client.privacy_status(&account), None);
+
+    client.set_operator(&account, &None);
+    let result = client.try_enable_privacy(&account, &1, &Some(operator.clone()));
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+    let result = client.try_revoke_privacy(&account, &Some(operator));
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+}
+
+#[test]
+fn test_enable_privacy_with_unregistered_operator_fails() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    // Neither the account nor a registered operator authorizes the change.
+    let result = client.try_enable_privacy(&account, &2, &Some(stranger.clone()));
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+    let result = client.try_revoke_privacy(&account, &Some(stranger));
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+    assert_eq!(client.privacy_status(&account), None);
+    assert_eq!(client.privacy_history(&account).len(), 0);
+    assert_eq!(client.configured_account_count(), 0);
+}
+
+#[test]
+fn test_enable_privacy_without_any_auth_fails() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    let operator = Address::generate(&env);
+    client.set_operator(&account, &Some(operator.clone()));
+    env.set_auths(&[]);
+
+    assert!(client.try_enable_privacy(&account, &2, &None).is_err());
+    assert!(client
+        .try_enable_privacy(&account, &2, &Some(operator))
+        .is_err());
+    assert_eq!(client.privacy_status(&account), None);
+}
+
+// ============================================================================
+// configured_account_count
+// ============================================================================
+
+#[test]
+fn test_configured_account_count_counts_new_accounts() {
+    let (env, client) = setup();
+    assert_eq!(client.configured_account_count(), 0);
+
+    client.enable_privacy(&Address::generate(&env), &1, &None);
+    client.enable_privacy(&Address::generate(&env), &2, &None);
+
+    assert_eq!(client.configured_account_count(), 2);
+}
+
+#[test]
+fn test_configured_account_count_ignores_re_enable() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+
+    client.enable_privacy(&account, &1, &None);
+    client.enable_privacy(&account, &3, &None);
+    client.enable_privacy(&account, &1, &None);
+
+    assert_eq!(client.configured_account_count(), 1);
+}
+
+#[test]
+fn test_configured_account_count_decrements_on_revoke() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.enable_privacy(&account, &1, &None);
+    client.enable_privacy(&other, &1, &None);
+
+    client.revoke_privacy(&account, &None);
+    assert_eq!(client.configured_account_count(), 1);
+
+    // Re-configuring a revoked account counts it again.
+    client.enable_privacy(&account, &2, &None);
+    assert_eq!(client.configured_account_count(), 2);
+}
+
+#[test]
+fn test_revoke_privacy_bulk_skips_unconfigured_accounts() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let configured_a = Address::generate(&env);
+    let configured_b = Address::generate(&env);
+    let kept = Address::generate(&env);
+    let unconfigured = Address::generate(&env);
+    client.enable_privacy(&configured_a, &1, &None);
+    client.enable_privacy(&configured_b, &2, &None);
+    client.enable_privacy(&kept, &3, &None);
+    assert_eq!(client.configured_account_count(), 3);
+
+    let accounts = vec![
+        &env,
+        configured_a.clone(),
+        unconfigured,
+        configured_b.clone(),
+    ];
+    assert_eq!(client.revoke_privacy_bulk(&admin, &accounts), 2);
+
+    assert_eq!(client.configured_account_count(), 1);
+    assert_eq!(client.privacy_status(&configured_a), None);
+    assert_eq!(client.privacy_status(&configured_b), None);
+    assert_eq!(client.privacy_status(&kept), Some(3));
+    assert_eq!(client.privacy_histogram(), map![&env, (3, 1)]);
+
+    let result = client.try_revoke_privacy_bulk(&kept, &vec![&env, kept.clone()]);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+}
+
+#[test]
+fn test_enable_privacy_batch_levels_applies_each_level() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+
+    let accounts = vec![&env, a.clone(), b.clone(), c.clone()];
+    client.enable_privacy_batch_levels(&admin, &accounts, &vec![&env, 1, 2, 3]);
+
+    assert_eq!(client.privacy_status(&a), Some(1));
+    assert_eq!(client.privacy_status(&b), Some(2));
+    assert_eq!(client.privacy_status(&c), Some(3));
+    assert_eq!(client.configured_account_count(), 3);
+
+    let result = client.try_enable_privacy_batch_levels(&a, &accounts, &vec![&env, 1, 1, 1]);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+}
+
+#[test]
+fn test_enable_privacy_batch_levels_validates_before_applying() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let accounts = vec![&env, a.clone(), b.clone()];
+
+    let result = client.try_enable_privacy_batch_levels(&admin, &accounts, &vec![&env, 1]);
+    assert_eq!(result, Err(Ok(QuickexError::LengthMismatch)));
+
+    let levels = vec![&env, 1, REDACTED_PRIVACY_LEVEL];
+    let result = client.try_enable_privacy_batch_levels(&admin, &accounts, &levels);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidPrivacyLevel)));
+
+    assert_eq!(client.privacy_status(&a), None);
+    assert_eq!(client.configured_account_count(), 0);
+}
+
+#[test]
+fn test_enable_privacy_rejects_history_sentinels() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    client.enable_privacy(&account, &1, &None);
+
+    for level in [REDACTED_PRIVACY_LEVEL, REVOKED_PRIVACY_LEVEL] {
+        let result = client.try_enable_privacy(&account, &level, &None);
+        assert_eq!(result, Err(Ok(QuickexError::InvalidPrivacyLevel)));
+        let key = BytesN::from_array(&env, &[level as u8; 32]);
+        assert!(!client.enable_privacy_idempotent(&account, &level, &key));
+    }
+
+    assert_eq!(client.privacy_status(&account), Some(1));
+    assert_eq!(client.privacy_history(&account), vec![&env, 1]);
+    assert!(client.verify_history_consistency(&account));
+}
+
+// ============================================================================
+// enable_privacy_idempotent
+// ============================================================================
+
+#[test]
+fn test_enable_privacy_idempotent_ignores_repeated_key() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    let first_key = BytesN::from_array(&env, &[1u8; 32]);
+
+    assert!(client.enable_privacy_idempotent(&account, &2, &first_key));
+    assert!(client.enable_privacy_idempotent(&account, &2, &first_key));
+    assert_eq!(client.privacy_history(&account), vec![&env, 2]);
+
+    // A retry with the same key does not apply a different level either.
+    assert!(client.enable_privacy_idempotent(&account, &3, &first_key));
+    assert_eq!(client.privacy_status(&account), Some(2));
+
+    let second_key = BytesN::from_array(&env, &[2u8; 32]);
+    assert!(client.enable_privacy_idempotent(&account, &3, &second_key));
+    assert_eq!(client.privacy_history(&account), vec![&env, 3, 2]);
+}
+
+// ============================================================================
+// privacy_histogram
+// ============================================================================
+
+#[test]
+fn test_privacy_histogram_counts_accounts_per_level() {
+    let (env, client) = setup();
+    let accounts: [Address; 5] = core::array::from_fn(|_| Address::generate(&env));
+    assert_eq!(client.privacy_histogram(), map![&env]);
+
+    client.enable_privacy(&accounts[0], &1, &None);
+    client.enable_privacy(&accounts[1], &1, &None);
+    client.enable_privacy(&accounts[2], &2, &None);
+    client.enable_privacy(&accounts[3], &3, &None);
+    client.enable_privacy(&accounts[4], &3, &None);
+    assert_eq!(
+        client.privacy_histogram(),
+        map![&env, (1, 2), (2, 1), (3, 2)]
+    );
+
+    // Moving and revoking accounts keeps the histogram compact.
+    client.enable_privacy(&accounts[2], &3, &None);
+    client.revoke_privacy(&accounts[0], &None);
+    client.enable_privacy(&accounts[1], &1, &None);
+    assert_eq!(client.privacy_histogram(), map![&env, (1, 1), (3, 3)]);
+}
+
+// ============================================================================
+// History retention
+// ============================================================================
+
+#[test]
+fn test_history_limit_keeps_most_recent_entries() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    client.set_history_limit(&account, &2);
+
+    client.enable_privacy(&account, &1, &None);
+    client.enable_privacy(&account, &2, &None);
+    client.enable_privacy(&account, &3, &None);
+
+    assert_eq!(client.privacy_history(&account), vec![&env, 3, 2]);
+}
+
+#[test]
+fn test_history_limit_trims_existing_history() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    for level in 1..=4 {
+        client.enable_privacy(&account, &level, &None);
+    }
+
+    client.set_history_limit(&account, &1);
+
+    assert_eq!(client.privacy_history(&account), vec![&env, 4]);
+}
+
+#[test]
+fn test_history_defaults_to_global_cap() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    for level in 0..40 {
+        client.enable_privacy(&account, &level, &None);
+    }
+
+    let history = client.privacy_history(&account);
+    assert_eq!(history.len(), 32);
+    assert_eq!(history.first(), Some(39));
+}
+
+#[test]
+fn test_history_limit_out_of_bounds_fails() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+
+    let zero = client.try_set_history_limit(&account, &0);
+    assert_eq!(zero, Err(Ok(QuickexError::InvalidHistoryLimit)));
+
+    let too_large = client.try_set_history_limit(&account, &33);
+    assert_eq!(too_large, Err(Ok(QuickexError::InvalidHistoryLimit)));
+}
+
+// ============================================================================
+// Salt seed rotation
+// ============================================================================
+
+#[test]
+fn test_rotate_seed_increments_version() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    assert_eq!(client.current_seed_version(&account), 0);
+
+    assert_eq!(
+        client.rotate_seed(&account, &BytesN::from_array(&env, &[1; 32])),
+        1
+    );
+    assert_eq!(
+        client.rotate_seed(&account, &BytesN::from_array(&env, &[2; 32])),
+        2
+    );
+    assert_eq!(client.current_seed_version(&account), 2);
+}
+
+#[test]
+fn test_rotate_seed_is_per_account() {
+    let (env, client) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    for seed in 1..=3u8 {
+        client.rotate_seed(&alice, &BytesN::from_array(&env, &[seed; 32]));
+    }
+    client.rotate_seed(&bob, &BytesN::from_array(&env, &[9; 32]));
+
+    assert_eq!(client.current_seed_version(&alice), 3);
+    assert_eq!(client.current_seed_version(&bob), 1);
+}
+
+// ============================================================================
+// enable_privacy_sig
+// ============================================================================
+
+#[test]
+fn test_enable_privacy_sig_valid_signature_applies() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    let signing_key = SigningKey::from_bytes(&[5u8; 32]);
+    let pubkey = register_signer(&env, &client, &account, &signing_key);
+
+    let message = client.privacy_sig_message(&account, &3);
+    let signature = sign(&env, &signing_key, &message);
+    client.enable_privacy_sig(&account, &3, &message, &signature, &pubkey);
+
+    assert_eq!(client.privacy_status(&account), Some(3));
+    assert_eq!(client.privacy_nonce(&account), 1);
+
+    // The nonce moved on, so the same signature cannot be replayed.
+    let replay = client.try_enable_privacy_sig(&account, &3, &message, &signature, &pubkey);
+    assert_eq!(replay, Err(Ok(QuickexError::InvalidSignature)));
+}
+
+#[test]
+fn test_enable_privacy_sig_invalid_signature_reverts() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    let signing_key = SigningKey::from_bytes(&[5u8; 32]);
+    let forger_key = SigningKey::from_bytes(&[6u8; 32]);
+    let pubkey = register_signer(&env, &client, &account, &signing_key);
+
+    let message = client.privacy_sig_message(&account, &3);
+    let forged = sign(&env, &forger_key, &message);
+    let result = client.try_enable_privacy_sig(&account, &3, &message, &forged, &pubkey);
+
+    assert!(result.is_err());
+    assert_eq!(client.privacy_status(&account), None);
+    assert_eq!(client.privacy_nonce(&account), 0);
+}
+
+#[test]
+fn test_enable_privacy_sig_rejects_unregistered_key() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    let signing_key = SigningKey::from_bytes(&[5u8; 32]);
+    let pubkey = BytesN::from_array(&env, &signing_key.verifying_key().to_bytes());
+
+    let message = client.privacy_sig_message(&account, &1);
+    let signature = sign(&env, &signing_key, &message);
+    let result = client.try_enable_privacy_sig(&account, &1, &message, &signature, &pubkey);
+
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+}
+
+// ============================================================================
+// Viewer grants and blocklist
+// ============================================================================
+
+#[test]
+fn test_can_view_requires_owner_grant() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let viewer = Address::generate(&env);
+    assert!(!client.can_view(&owner, &viewer));
+
+    client.grant_viewer(&owner, &viewer);
+    assert!(client.can_view(&owner, &viewer));
+
+    client.revoke_viewer(&owner, &viewer);
+    assert!(!client.can_view(&owner, &viewer));
+}
+
+#[test]
+fn test_blocked_viewer_denied_despite_grant_until_unblocked() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let viewer = Address::generate(&env);
+    client.initialize(&admin);
+    client.grant_viewer(&alice, &viewer);
+    client.grant_viewer(&bob, &viewer);
+
+    client.block_viewer(&admin, &viewer);
+    assert!(!client.can_view(&alice, &viewer));
+    assert!(!client.can_view(&bob, &viewer));
+
+    client.unblock_viewer(&admin, &viewer);
+    assert!(client.can_view(&alice, &viewer));
+    assert!(client.can_view(&bob, &viewer));
+}
+
+#[test]
+fn test_block_viewer_by_non_admin_fails() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let viewer = Address::generate(&env);
+    client.initialize(&admin);
+
+    let result = client.try_block_viewer(&stranger, &viewer);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+}
+
+// ============================================================================
+// verify_history_consistency
+// ============================================================================
+
+#[test]
+fn test_history_consistency_holds_through_normal_use() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    assert!(client.verify_history_consistency(&account));
+
+    client.enable_privacy(&account, &1, &None);
+    client.enable_privacy(&account, &3, &None);
+    assert!(client.verify_history_consistency(&account));
+
+    client.revoke_privacy(&account, &None);
+    assert!(client.verify_history_consistency(&account));
+}
+
+#[test]
+fn test_history_consistency_detects_desynced_level() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    client.enable_privacy(&account, &1, &None);
+
+    env.as_contract(&client.address, || {
+        storage::set_privacy_level(&env, &account, 2);
+    });
+
+    assert!(!client.verify_history_consistency(&account));
+}
+
+// ============================================================================
+// import_privacy
+// ============================================================================
+
+#[test]
+fn test_import_privacy_sets_full_state() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let account = Address::generate(&env);
+    let history = vec![&env, 3, u32::MAX, 1];
+
+    client.import_privacy(&admin, &account, &3, &history, &4_321);
+
+    assert_eq!(client.privacy_status(&account), Some(3));
+    assert_eq!(client.privacy_history(&account), history);
+    assert_eq!(client.last_privacy_change(&account), Some(4_321));
+    assert_eq!(client.configured_account_count(), 1);
+    assert!(client.verify_history_consistency(&account));
+    assert!(client.verify_privacy_chain(&account, &vec![&env, 1, u32::MAX, 3]));
+}
+
+#[test]
+fn test_import_privacy_validates_input() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let account = Address::generate(&env);
+
+    let result = client.try_import_privacy(&account, &account, &1, &vec![&env, 1], &1);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+    let result = client.try_import_privacy(&admin, &account, &u32::MAX, &vec![&env], &1);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidPrivacyLevel)));
+    let result = client.try_import_privacy(&admin, &account, &2, &vec![&env, 1], &1);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidPrivacyLevel)));
+
+    let mut too_long = vec![&env];
+    for _ in 0..33 {
+        too_long.push_back(2);
+    }
+    let result = client.try_import_privacy(&admin, &account, &2, &too_long, &1);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidHistoryLimit)));
+    assert_eq!(client.privacy_status(&account), None);
+}
+
+#[test]
+fn test_redact_history_entry_replaces_only_that_slot() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let account = Address::generate(&env);
+    client.enable_privacy(&account, &1, &None);
+    client.enable_privacy(&account, &2, &None);
+    client.enable_privacy(&account, &3, &None);
+    let head = client.privacy_history_head(&account);
+
+    client.redact_history_entry(&admin, &account, &1);
+
+    assert_eq!(
+        client.privacy_history(&account),
+        vec![&env, 3, REDACTED_PRIVACY_LEVEL, 1]
+    );
+    assert_eq!(client.privacy_history_head(&account), head);
+    assert!(client.verify_history_consistency(&account));
+}
+
+#[test]
+fn test_redact_history_entry_validates_input() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let account = Address::generate(&env);
+    client.enable_privacy(&account, &1, &None);
+
+    let result = client.try_redact_history_entry(&account, &account, &0);
+    assert_eq!(result, Err(Ok(QuickexError::Unauthorized)));
+    let result = client.try_redact_history_entry(&admin, &account, &1);
+    assert_eq!(result, Err(Ok(QuickexError::InvalidRange)));
+    assert_eq!(client.privacy_history(&account), vec![&env, 1]);
+}
+
+#[test]
+fn test_last_privacy_change_tracks_ledger() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    assert_eq!(client.last_privacy_change(&account), None);
+
+    env.ledger().set_sequence_number(77);
+    client.enable_privacy(&account, &1, &None);
+    assert_eq!(client.last_privacy_change(&account), Some(77));
+}
+
+// ============================================================================
+// Hash-linked history
+// ============================================================================
+
+#[test]
+fn test_privacy_chain_verifies_recorded_sequence() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    assert_eq!(
+        client.privacy_history_head(&account),
+        BytesN::from_array(&env, &[0u8; 32])
+    );
+    assert!(client.verify_privacy_chain(&account, &vec![&env]));
+
+    client.enable_privacy(&account, &1, &None);
+    client.enable_privacy(&account, &3, &None);
+    client.revoke_privacy(&account, &None);
+
+    let mut preimage = Bytes::from_array(&env, &[0u8; 32]);
+    preimage.append(&Bytes::from_array(&env, &1u32.to_be_bytes()));
+    let first: BytesN<32> = env.crypto().sha256(&preimage).into();
+    let mut preimage = Bytes::from_array(&env, &first.to_array());
+    preimage.append(&Bytes::from_array(&env, &3u32.to_be_bytes()));
+    let second: BytesN<32> = env.crypto().sha256(&preimage).into();
+    let mut preimage = Bytes::from_array(&env, &second.to_array());
+    preimage.append(&Bytes::from_array(&env, &u32::MAX.to_be_bytes()));
+    let head: BytesN<32> = env.crypto().sha256(&preimage).into();
+
+    assert_eq!(client.privacy_history_head(&account), head);
+    assert!(client.verify_privacy_chain(&account, &vec![&env, 1, 3, u32::MAX]));
+}
+
+#[test]
+fn test_privacy_chain_rejects_reordered_sequence() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    client.enable_privacy(&account, &1, &None);
+    client.enable_privacy(&account, &3, &None);
+
+    assert!(client.verify_privacy_chain(&account, &vec![&env, 1, 3]));
+    assert!(!client.verify_privacy_chain(&account, &vec![&env, 3, 1]));
+    assert!(!client.verify_privacy_chain(&account, &vec![&env, 1]));
+}
+
+#[test]
+fn test_privacy_chain_survives_history_trimming() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    client.set_history_limit(&account, &1);
+    client.enable_privacy(&account, &1, &None);
+    client.enable_privacy(&account, &2, &None);
+
+    assert_eq!(client.privacy_history(&account), vec![&env, 2]);
+    assert!(client.verify_privacy_chain(&account, &vec![&env, 1, 2]));
+}
+
+#[test]
+fn test_checkpoint_chain_captures_head_at_call_time() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    assert_eq!(client.latest_checkpoint(&account), None);
+
+    client.enable_privacy(&account, &1, &None);
+    let checkpoint = client.checkpoint_chain(&account);
+    assert_eq!(checkpoint, client.privacy_history_head(&account));
+
+    client.enable_privacy(&account, &2, &None);
+    assert_ne!(client.privacy_history_head(&account), checkpoint);
+    assert_eq!(client.latest_checkpoint(&account), Some(checkpoint.clone()));
+
+    let newer = client.checkpoint_chain(&account);
+    assert_eq!(client.latest_checkpoint(&account), Some(newer));
+}
+
+// ============================================================================
+// Frozen accounts and summaries
+// ============================================================================
+
+#[test]
+fn test_frozen_account_rejects_changes() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let account = Address::generate(&env);
+    client.initialize(&admin);
+    client.enable_privacy(&account, &1, &None);
+
+    client.set_privacy_frozen(&admin, &account, &true);
+    let result = client.try_enable_privacy(&account, &2, &None);
+    assert_eq!(result, Err(Ok(QuickexError::PrivacyFrozen)));
+    let result = client.try_revoke_privacy(&account, &None);
+    assert_eq!(result, Err(Ok(QuickexError::PrivacyFrozen)));
+    assert_eq!(client.privacy_status(&account), Some(1));
+
+    client.set_privacy_frozen(&admin, &account, &false);
+    client.enable_privacy(&account, &2, &None);
+    assert_eq!(client.privacy_status(&account), Some(2));
+}
+
+#[test]
+fn test_sealed_account_rejects_changes() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let account = Address::generate(&env);
+    client.initialize(&admin);
+    client.enable_privacy(&account, &1, &None);
+    assert!(!client.is_privacy_sealed(&account));
+
+    client.seal_privacy(&account);
+    assert!(client.is_privacy_sealed(&account));
+    let result = client.try_enable_privacy(&account, &2, &None);
+    assert_eq!(result, Err(Ok(QuickexError::PrivacySealed)));
+    let result = client.try_revoke_privacy(&account, &None);
+    assert_eq!(result, Err(Ok(QuickexError::PrivacySealed)));
+    let result = client.try_import_privacy(&admin, &account, &2, &vec![&env, 2], &1);
+    assert_eq!(result, Err(Ok(QuickexError::PrivacySealed)));
+    assert_eq!(client.privacy_status(&account), Some(1));
+    assert_eq!(client.privacy_history(&account), vec![&env, 1]);
+}
+
+#[test]
+fn test_sealed_account_has_no_unseal_path() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let account = Address::generate(&env);
+    client.initialize(&admin);
+    client.seal_privacy(&account);
+
+    // Neither sealing again nor the admin's freeze toggle lifts the seal.
+    client.seal_privacy(&account);
+    client.set_privacy_frozen(&admin, &account, &true);
+    client.set_privacy_frozen(&admin, &account, &false);
+    assert!(client.is_privacy_sealed(&account));
+    let result = client.try_enable_privacy(&account, &1, &None);
+    assert_eq!(result, Err(Ok(QuickexError::PrivacySealed)));
+    assert_eq!(
+        client.revoke_privacy_bulk(&admin, &vec![&env, account.clone()]),
+        0
+    );
+    assert_eq!(client.privacy_status(&account), None);
+}
+
+#[test]
+fn test_account_summary_many_mixed_states() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let configured = Address::generate(&env);
+    let revoked = Address::generate(&env);
+    let frozen = Address::generate(&env);
+    let fresh = Address::generate(&env);
+    client.initialize(&admin);
+
+    client.enable_privacy(&configured, &1, &None);
+    client.enable_privacy(&configured, &3, &None);
+    client.enable_privacy(&revoked, &2, &None);
+    client.revoke_privacy(&revoked, &None);
+    client.enable_privacy(&frozen, &1, &None);
+    client.set_privacy_frozen(&admin, &frozen, &true);
+
+    let summaries = client.account_summary_many(&vec![
+        &env,
+        configured.clone(),
+        revoked.clone(),
+        frozen.clone(),
+        fresh.clone(),
+    ]);
+
+    assert_eq!(
+        summaries,
+        vec![
+            &env,
+            AccountPrivacy {
+                account: configured,
+                level: Some(3),
+                history_len: 2,
+                frozen: false,
+                configured: true,
+            },
+            AccountPrivacy {
+                account: revoked,
+                level: None,
+                history_len: 2,
+                frozen: false,
+                configured: false,
+            },
+            AccountPrivacy {
+                account: frozen,
+                level: Some(1),
+                history_len: 1,
+                frozen: true,
+                configured: true,
+            },
+            AccountPrivacy {
+                account: fresh,
+                level: None,
+                history_len: 0,
+                frozen: false,
+                configured: false,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_enable_privacy_emits_redacted_event_by_default() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    assert!(!client.event_verbosity(&account));
+
+    client.enable_privacy(&account, &2, &None);
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    let expected: soroban_sdk::Vec<Val> = (
+        Symbol::new(&env, "PrivacyLevelSetRedacted"),
+        account.clone(),
+    )
+        .into_val(&env);
+    assert_eq!(topics, expected);
+    let data: Map<Symbol, Val> = data.into_val(&env);
+    assert!(!data.contains_key(Symbol::new(&env, "level")));
+}
+
+#[test]
+fn test_enable_privacy_emits_level_when_verbose() {
+    let (env, client) = setup();
+    let account = Address::generate(&env);
+    client.set_event_verbosity(&account, &true);
+    assert!(client.event_verbosity(&account));
+
+    client.enable_privacy(&account, &2, &None);
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    let expected: soroban_sdk::Vec<Val> =
+        (Symbol::new(&env, "PrivacyLevelSet"), account.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+    let data: Map<Symbol, Val> = data.into_val(&env);
+    let level: u32 = data.get(Symbol::new(&env, "level")).unwrap().into_val(&env);
+    assert_eq!(level, 2);
+}
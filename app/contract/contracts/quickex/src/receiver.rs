@@ -0,0 +1,27 @@
+//! Client side of the optional callback notifying contract recipients of a release.
+//!
+//! A receiver is any contract exposing `on_escrow_received(id: u64, amount: u64)`.
+//! QuickEx calls it after paying out an escrow whose sender opted in, and ignores
+//! recipients that are accounts, lack the entry point, or fail.
+
+use soroban_sdk::{address_payload::AddressPayload, contractclient, Address, Env};
+
+/// Interface QuickEx expects from a recipient contract that wants release callbacks.
+///
+/// Only the generated [`EscrowReceiverClient`] is used; the trait itself is never
+/// implemented here.
+#[allow(dead_code)]
+#[contractclient(name = "EscrowReceiverClient")]
+pub trait EscrowReceiver {
+    /// Called after escrow `id` paid `amount` to this contract.
+    fn on_escrow_received(env: Env, id: u64, amount: u64);
+}
+
+/// Notify `recipient` that escrow `id` paid it `amount`, if it is a contract.
+///
+/// Any failure of the callback is swallowed so it can never block a release.
+pub fn notify(env: &Env, recipient: &Address, id: u64, amount: u64) {
+    if let Some(AddressPayload::ContractIdHash(_)) = recipient.to_payload() {
+        let _ = EscrowReceiverClient::new(env, recipient).try_on_escrow_received(&id, &amount);
+    }
+}
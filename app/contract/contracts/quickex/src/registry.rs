@@ -0,0 +1,30 @@
+//! Client side of the external name registry used to resolve escrow recipients.
+//!
+//! A registry is any contract exposing `resolve(name: Symbol) -> Option<Address>`.
+//! QuickEx only reads from it, once, when an escrow is created.
+
+use soroban_sdk::{contractclient, Address, Env, Symbol};
+
+use crate::errors::QuickexError;
+
+/// Interface QuickEx expects from a name registry contract.
+///
+/// Only the generated [`RegistryClient`] is used; the trait itself is never implemented here.
+#[allow(dead_code)]
+#[contractclient(name = "RegistryClient")]
+pub trait NameRegistry {
+    /// Return the address registered under `name`, or `None` if unregistered.
+    fn resolve(env: Env, name: Symbol) -> Option<Address>;
+}
+
+/// Resolve `name` through the registry contract at `registry`.
+///
+/// # Errors
+/// - [`QuickexError::NameNotResolved`] – the name is unregistered or the registry call
+///   failed.
+pub fn resolve(env: &Env, registry: &Address, name: &Symbol) -> Result<Address, QuickexError> {
+    match RegistryClient::new(env, registry).try_resolve(name) {
+        Ok(Ok(Some(address))) => Ok(address),
+        _ => Err(QuickexError::NameNotResolved),
+    }
+}
@@ -10,6 +10,65 @@
 //! |------------------------|----------------|-------------|
 //! | [`Escrow`](DataKey::Escrow) | `EscrowEntry`  | Escrow entry keyed by commitment hash (32 bytes). One entry per unique deposit. |
 //! | [`EscrowCounter`](DataKey::EscrowCounter) | `u64`       | Global monotonic counter for escrow creation. |
+//! | [`EscrowData`](DataKey::EscrowData) | `EscrowData` | Id-based escrow record keyed by the id returned from `create_escrow`. |
+//! | [`DomainTag`](DataKey::DomainTag) | `Bytes` | Admin-configured commitment domain separator. Defaults to the built-in tag when unset. |
+//! | [`ExpiryBucket`](DataKey::ExpiryBucket) | `Vec<u64>` | Ids of the `Pending` id-based escrows expiring at a ledger, keyed by that ledger. |
+//! | [`ConfiguredAccountCount`](DataKey::ConfiguredAccountCount) | `u32` | Number of accounts that currently have a privacy level set. |
+//! | [`LockedBalance`](DataKey::LockedBalance) | `i128` | Per-token sum of amounts held by open (`Pending`) escrows. |
+//! | [`Campaign`](DataKey::Campaign) | `Campaign` | Escrow campaign keyed by campaign id. |
+//! | [`CampaignCounter`](DataKey::CampaignCounter) | `u64` | Global monotonic counter for campaign creation. |
+//! | [`HistoryLimit`](DataKey::HistoryLimit) | `u32` | Per-account privacy history depth. Defaults to the global maximum when unset. |
+//! | [`SaltSeed`](DataKey::SaltSeed) | `(u32, BytesN<32>)` | Per-account `(version, seed_hash)` of the current salt-derivation seed. |
+//! | [`EscrowApprovals`](DataKey::EscrowApprovals) | `EscrowApprovals` | Approver set and collected approvals of an M-of-N id-based escrow. |
+//! | [`TotalOpenValue`](DataKey::TotalOpenValue) | `i128` | Running sum of the amounts of `Pending` id-based escrows. |
+//! | [`PrivacySigner`](DataKey::PrivacySigner) | `BytesN<32>` | Ed25519 key allowed to change an account's privacy level by signature. |
+//! | [`PrivacyNonce`](DataKey::PrivacyNonce) | `u64` | Per-account nonce of signed privacy changes; bumps on each use. |
+//! | [`TrackedCommitment`](DataKey::TrackedCommitment) | `u32` | Ledger sequence at which a commitment was recorded via `create_tracked_commitment`. |
+//! | [`ViewerGrant`](DataKey::ViewerGrant) | `bool` | Present while `owner` lets `viewer` see its private data. Keyed by `(owner, viewer)`. |
+//! | [`BlockedViewer`](DataKey::BlockedViewer) | `bool` | Present while the admin blocks a viewer contract-wide. |
+//! | [`PrivacyFrozen`](DataKey::PrivacyFrozen) | `bool` | Present while the admin freezes an account's privacy settings. |
+//! | [`Nullifier`](DataKey::Nullifier) | `bool` | Present once a nullifier has been spent. Keyed by the 32-byte nullifier. |
+//! | [`LevelHistogram`](DataKey::LevelHistogram) | `Map<u32, u32>` | Number of accounts currently at each privacy level. Levels with no accounts are absent. |
+//! | [`CommitmentOpening`](DataKey::CommitmentOpening) | `OpeningRecord` | Who revealed a commitment's opening and at which ledger. |
+//! | [`PrivacyHistoryHead`](DataKey::PrivacyHistoryHead) | `BytesN<32>` | Head of the hash chain over every privacy level an account recorded. Absent until the first change. |
+//! | [`PrivacyCheckpoint`](DataKey::PrivacyCheckpoint) | `BytesN<32>` | Latest privacy history chain head an account checkpointed. |
+//! | [`PrivacyIdemKey`](DataKey::PrivacyIdemKey) | `bool` | Result of the `enable_privacy_idempotent` call made with an idempotency key. Keyed by `(account, key)`. |
+//! | [`EscrowFeeBps`](DataKey::EscrowFeeBps) | `u32` | Admin-configured escrow fee in basis points, withheld on release. Defaults to 0. |
+//! | [`FeeDiscount`](DataKey::FeeDiscount) | `u32` | Fee discount in basis points for senders at a given privacy level. Defaults to 0. |
+//! | [`MaxEscrowAmount`](DataKey::MaxEscrowAmount) | `u64` | Admin-configured ceiling on id-based escrow amounts. No ceiling when unset. |
+//! | [`SenderEscrowCount`](DataKey::SenderEscrowCount) | `u32` | Number of slots used in an address's paged sender index. |
+//! | [`RecipientEscrowCount`](DataKey::RecipientEscrowCount) | `u32` | Number of slots used in an address's paged recipient index. |
+//! | [`TrackedRate`](DataKey::TrackedRate) | `(u32, u32)` | Ledger sequence and count of an owner's most recent tracked commitment creations. |
+//! | [`DisputeDeadline`](DataKey::DisputeDeadline) | `u32` | Admin-configured ledgers a dispute may stay unresolved before either party can time it out. |
+//! | [`DisputedAt`](DataKey::DisputedAt) | `u32` | Ledger sequence at which an id-based escrow was disputed. |
+//! | [`DefaultPrivacyLevel`](DataKey::DefaultPrivacyLevel) | `u32` | Admin-configured privacy level reported for accounts without their own level. |
+//! | [`LastPrivacyChange`](DataKey::LastPrivacyChange) | `u32` | Ledger sequence of an account's most recent privacy level change. |
+//! | [`CancelRebate`](DataKey::CancelRebate) | `(u64, u32)` | Admin-configured flat rebate and grace window in ledgers for quick escrow cancellations. |
+//! | [`RebatePool`](DataKey::RebatePool) | `i128` | Per-token funds available to pay quick-cancellation rebates. |
+//! | [`EventVerbose`](DataKey::EventVerbose) | `bool` | Whether an account's privacy events include its level. Redacted when unset. |
+//! | [`OpenEscrowCount`](DataKey::OpenEscrowCount) | `u32` | Cached number of non-terminal id-based escrows an address sends. |
+//! | [`EscrowHashlock`](DataKey::EscrowHashlock) | `BytesN<32>` | SHA-256 hash whose preimage releases a hashlock id-based escrow. |
+//! | [`TrackedAtLedger`](DataKey::TrackedAtLedger) | `Vec<BytesN<32>>` | Commitments tracked in a ledger, in creation order, keyed by that ledger. |
+//! | [`Operator`](DataKey::Operator) | `Address` | Operator an account lets authorize privacy changes on its behalf. |
+//! | [`EscrowMemo`](DataKey::EscrowMemo) | `Bytes` | Opaque memo encrypted to the recipient of an id-based escrow. |
+//! | [`EscrowArbiter`](DataKey::EscrowArbiter) | `Address` | Arbiter the parties of an id-based escrow appointed. The admin arbitrates when unset. |
+//! | [`PrivacySealed`](DataKey::PrivacySealed) | `bool` | Present once an account permanently sealed its privacy settings. Never removed. |
+//! | [`VoteCommitment`](ExtKey::VoteCommitment) | `BytesN<32>` | Commitment to a voter's governance weight. |
+//! | [`VoteWeight`](ExtKey::VoteWeight) | `i128` | Voter's governance weight once revealed against its commitment. |
+//! | [`ReleaseCallback`](ExtKey::ReleaseCallback) | `bool` | Whether releasing an id-based escrow calls back into its recipient contract. |
+//! | [`MerkleFrontier`](ExtKey::MerkleFrontier) | `Vec<BytesN<32>>` | Pending left-hand node per level of the commitment Merkle tree. |
+//! | [`MerkleRoot`](ExtKey::MerkleRoot) | `BytesN<32>` | Current root of the commitment Merkle tree. |
+//! | [`MerkleSize`](ExtKey::MerkleSize) | `u32` | Number of leaves in the commitment Merkle tree. |
+//! | [`EscrowArbiterFee`](ExtKey::EscrowArbiterFee) | `u64` | Fee an id-based escrow pays its arbiter when a dispute is resolved. |
+//! | [`ExpirySweepCursor`](ExtKey::ExpirySweepCursor) | `u32` | First expiry ledger `sweep_expired` has not processed yet. |
+//! | [`SenderEscrowPage`](ExtKey::SenderEscrowPage) | `Vec<u64>` | One page of the ids of the id-based escrows an address sends, in creation order. `0` marks a migrated-away slot. |
+//! | [`RecipientEscrowPage`](ExtKey::RecipientEscrowPage) | `Vec<u64>` | One page of the ids of the id-based escrows an address receives, in creation order. `0` marks a migrated-away slot. |
+//! | [`EscrowIndexSlots`](ExtKey::EscrowIndexSlots) | `(u32, u32)` | Slots an id-based escrow occupies in its sender and recipient indexes. |
+//! | [`CollectedFees`](ExtKey::CollectedFees) | `i128` | Per-token escrow fees withheld on release and not yet collected by the admin. |
+//! | [`UsedReceipt`](ExtKey::UsedReceipt) | `bool` | Marks a sender's delivery receipt as used by `release_with_receipt`. |
+//! | [`DomainTagLocked`](ExtKey::DomainTagLocked) | `bool` | Set by the first deposit or `set_domain_tag`; the domain tag is fixed from then on. |
+//! | [`OpenCountRepair`](ExtKey::OpenCountRepair) | `(u32, u32)` | Next index page and count so far of a sender's open-count repair in progress. |
+//! | [`SaltPolicy`](DataKey::SaltPolicy) | `(u32, u32)` | Admin-configured `(min, max)` commitment salt length. Defaults to `(0, 1024)` when unset. |
 //! | [`Admin`](DataKey::Admin) | `Address`     | Contract admin address. Set during initialisation, transferable by admin. |
 //! | [`Paused`](DataKey::Paused) | `bool`       | Global pause flag. When true, critical operations may be blocked. |
 //! | [`PrivacyLevel`](DataKey::PrivacyLevel) | `u32`  | Numeric privacy level per account (0 = off). Used by `enable_privacy`. |
@@ -38,9 +97,9 @@
 //! - **Value layout**: Changing `EscrowEntry` fields may require migration logic; adding optional
 //!   fields can be done carefully with defaults.
 
-use soroban_sdk::{contracttype, Address, Bytes, Env, Vec};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Map, Vec};
 
-use crate::types::EscrowEntry;
+use crate::types::{Campaign, EscrowApprovals, EscrowData, EscrowEntry, OpeningRecord};
 
 // -----------------------------------------------------------------------------
 // Key constants (for keys not using DataKey)
@@ -75,6 +134,134 @@ pub enum DataKey {
     PrivacyLevel(Address),
     /// Privacy level change history per account.
     PrivacyHistory(Address),
+    /// Id-based escrow record keyed by escrow id.
+    EscrowData(u64),
+    /// Commitment domain separator (singleton).
+    DomainTag,
+    /// Ids of the escrows expiring at a ledger, keyed by ledger sequence.
+    ExpiryBucket(u32),
+    /// Number of accounts with a privacy level set (singleton).
+    ConfiguredAccountCount,
+    /// Sum of open escrow amounts per token.
+    LockedBalance(Address),
+    /// Escrow campaign keyed by campaign id.
+    Campaign(u64),
+    /// Global campaign counter (singleton).
+    CampaignCounter,
+    /// Commitment salt length policy (singleton).
+    SaltPolicy,
+    /// Privacy history depth per account.
+    HistoryLimit(Address),
+    /// Versioned salt-derivation seed commitment per account.
+    SaltSeed(Address),
+    /// M-of-N approval state keyed by escrow id.
+    EscrowApprovals(u64),
+    /// Running total of open id-based escrow amounts (singleton).
+    TotalOpenValue,
+    /// Registered privacy signing key per account.
+    PrivacySigner(Address),
+    /// Signed privacy change nonce per account.
+    PrivacyNonce(Address),
+    /// Creation ledger of a tracked commitment, keyed by commitment hash.
+    TrackedCommitment(BytesN<32>),
+    /// Viewer grant keyed by `(owner, viewer)`.
+    ViewerGrant(Address, Address),
+    /// Admin blocklist entry per viewer.
+    BlockedViewer(Address),
+    /// Admin freeze flag per account.
+    PrivacyFrozen(Address),
+    /// Spent nullifier marker.
+    Nullifier(BytesN<32>),
+    /// Per-level account counters (singleton).
+    LevelHistogram,
+    /// Recorded opening of a commitment, keyed by commitment hash.
+    CommitmentOpening(BytesN<32>),
+    /// Hash-chain head of an account's privacy history.
+    PrivacyHistoryHead(Address),
+    /// Latest checkpointed privacy history chain head per account.
+    PrivacyCheckpoint(Address),
+    /// Recorded result of an idempotent privacy change, keyed by `(account, idem_key)`.
+    PrivacyIdemKey(Address, BytesN<32>),
+    /// Escrow fee in basis points (singleton).
+    EscrowFeeBps,
+    /// Fee discount in basis points, keyed by sender privacy level.
+    FeeDiscount(u32),
+    /// Ceiling on id-based escrow amounts (singleton).
+    MaxEscrowAmount,
+    /// Slot count of the paged index of id-based escrows sent by an address.
+    SenderEscrowCount(Address),
+    /// Slot count of the paged index of id-based escrows received by an address.
+    RecipientEscrowCount(Address),
+    /// Per-ledger tracked commitment counter for an owner.
+    TrackedRate(Address),
+    /// Dispute timeout window in ledgers (singleton).
+    DisputeDeadline,
+    /// Dispute ledger of an id-based escrow, keyed by escrow id.
+    DisputedAt(u64),
+    /// Baseline privacy level for unconfigured accounts (singleton).
+    DefaultPrivacyLevel,
+    /// Ledger of an account's latest privacy level change.
+    LastPrivacyChange(Address),
+    /// Quick-cancellation rebate and grace window (singleton).
+    CancelRebate,
+    /// Funded quick-cancellation rebate pool, keyed by token.
+    RebatePool(Address),
+    /// Per-account event verbosity flag.
+    EventVerbose(Address),
+    /// Cached per-sender count of non-terminal id-based escrows.
+    OpenEscrowCount(Address),
+    /// Hash lock keyed by escrow id.
+    EscrowHashlock(u64),
+    /// Tracked commitments created in a ledger, keyed by ledger sequence.
+    TrackedAtLedger(u32),
+    /// Delegated operator per account.
+    Operator(Address),
+    /// Encrypted memo keyed by escrow id.
+    EscrowMemo(u64),
+    /// Appointed arbiter keyed by escrow id.
+    EscrowArbiter(u64),
+    /// Permanent seal flag per account.
+    PrivacySealed(Address),
+}
+
+/// Overflow storage keys.
+///
+/// `#[contracttype]` enums are capped at 50 variants and [`DataKey`] is full, so
+/// newer namespaces live here. Keys from the two enums can never collide because
+/// the enum type is part of the serialised key.
+#[contracttype]
+#[derive(Clone)]
+pub enum ExtKey {
+    /// Registered vote weight commitment per voter.
+    VoteCommitment(Address),
+    /// Revealed vote weight per voter.
+    VoteWeight(Address),
+    /// Release callback opt-in keyed by escrow id.
+    ReleaseCallback(u64),
+    /// Frontier of the commitment Merkle tree.
+    MerkleFrontier,
+    /// Root of the commitment Merkle tree.
+    MerkleRoot,
+    /// Leaf count of the commitment Merkle tree.
+    MerkleSize,
+    /// Arbiter fee keyed by escrow id.
+    EscrowArbiterFee(u64),
+    /// Resume point of the expiry sweep (singleton).
+    ExpirySweepCursor,
+    /// Page of the sender index, keyed by `(sender, page)`.
+    SenderEscrowPage(Address, u32),
+    /// Page of the recipient index, keyed by `(recipient, page)`.
+    RecipientEscrowPage(Address, u32),
+    /// Index slots of an id-based escrow, keyed by escrow id.
+    EscrowIndexSlots(u64),
+    /// Uncollected escrow fees keyed by token.
+    CollectedFees(Address),
+    /// Delivery receipt already used by `release_with_receipt`, keyed by `(sender, receipt_hash)`.
+    UsedReceipt(Address, BytesN<32>),
+    /// Set once the domain tag can no longer change (singleton).
+    DomainTagLocked,
+    /// Progress of an open-count repair, keyed by sender.
+    OpenCountRepair(Address),
 }
 
 // -----------------------------------------------------------------------------
@@ -105,6 +292,241 @@ pub fn has_escrow(env: &Env, commitment: &Bytes) -> bool {
     env.storage().persistent().has(&key)
 }
 
+/// Put a campaign into storage.
+pub fn put_campaign(env: &Env, campaign: &Campaign) {
+    let key = DataKey::Campaign(campaign.id);
+    env.storage().persistent().set(&key, campaign);
+}
+
+/// Get a campaign from storage.
+///
+/// **Contract**: Returns `None` if no campaign exists for the id.
+pub fn get_campaign(env: &Env, id: u64) -> Option<Campaign> {
+    let key = DataKey::Campaign(id);
+    env.storage().persistent().get(&key)
+}
+
+/// Put the M-of-N approval state of an escrow into storage.
+pub fn put_escrow_approvals(env: &Env, id: u64, approvals: &EscrowApprovals) {
+    let key = DataKey::EscrowApprovals(id);
+    env.storage().persistent().set(&key, approvals);
+}
+
+/// Get the M-of-N approval state of an escrow.
+///
+/// **Contract**: Returns `None` for escrows without an approver set.
+pub fn get_escrow_approvals(env: &Env, id: u64) -> Option<EscrowApprovals> {
+    let key = DataKey::EscrowApprovals(id);
+    env.storage().persistent().get(&key)
+}
+
+/// Put the hash lock of an escrow into storage.
+pub fn put_escrow_hashlock(env: &Env, id: u64, hashlock: &BytesN<32>) {
+    let key = DataKey::EscrowHashlock(id);
+    env.storage().persistent().set(&key, hashlock);
+}
+
+/// Get the hash lock of an escrow.
+///
+/// **Contract**: Returns `None` for escrows without a hash lock.
+pub fn get_escrow_hashlock(env: &Env, id: u64) -> Option<BytesN<32>> {
+    let key = DataKey::EscrowHashlock(id);
+    env.storage().persistent().get(&key)
+}
+
+/// Put the encrypted memo of an escrow into storage.
+pub fn put_escrow_memo(env: &Env, id: u64, memo: &Bytes) {
+    let key = DataKey::EscrowMemo(id);
+    env.storage().persistent().set(&key, memo);
+}
+
+/// Get the encrypted memo of an escrow.
+///
+/// **Contract**: Returns `None` for escrows created without a memo.
+pub fn get_escrow_memo(env: &Env, id: u64) -> Option<Bytes> {
+    let key = DataKey::EscrowMemo(id);
+    env.storage().persistent().get(&key)
+}
+
+/// Put the appointed arbiter of an escrow into storage.
+pub fn put_escrow_arbiter(env: &Env, id: u64, arbiter: &Address) {
+    let key = DataKey::EscrowArbiter(id);
+    env.storage().persistent().set(&key, arbiter);
+}
+
+/// Get the appointed arbiter of an escrow.
+///
+/// **Contract**: Returns `None` if the parties never appointed one.
+pub fn get_escrow_arbiter(env: &Env, id: u64) -> Option<Address> {
+    let key = DataKey::EscrowArbiter(id);
+    env.storage().persistent().get(&key)
+}
+
+/// Increment and return the campaign counter.
+///
+/// **Contract**: Atomic increment. Initial value treated as 0.
+pub fn increment_campaign_counter(env: &Env) -> u64 {
+    let key = DataKey::CampaignCounter;
+    let mut count: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+    count += 1;
+    env.storage().persistent().set(&key, &count);
+    count
+}
+
+/// Get the running total of open id-based escrow amounts.
+///
+/// **Contract**: Returns 0 if never set.
+pub fn get_total_open_value(env: &Env) -> i128 {
+    let key = DataKey::TotalOpenValue;
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the running total of open id-based escrow amounts.
+pub fn set_total_open_value(env: &Env, total: i128) {
+    let key = DataKey::TotalOpenValue;
+    env.storage().persistent().set(&key, &total);
+}
+
+/// Get the escrow fee in basis points.
+///
+/// **Contract**: Returns 0 if never set.
+pub fn get_escrow_fee_bps(env: &Env) -> u32 {
+    let key = DataKey::EscrowFeeBps;
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the escrow fee in basis points.
+pub fn set_escrow_fee_bps(env: &Env, fee_bps: u32) {
+    let key = DataKey::EscrowFeeBps;
+    env.storage().persistent().set(&key, &fee_bps);
+}
+
+/// Get the fee discount in basis points for senders at `level`.
+///
+/// **Contract**: Returns 0 if never set.
+pub fn get_fee_discount(env: &Env, level: u32) -> u32 {
+    let key = DataKey::FeeDiscount(level);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the fee discount in basis points for senders at `level`.
+pub fn set_fee_discount(env: &Env, level: u32, discount_bps: u32) {
+    let key = DataKey::FeeDiscount(level);
+    env.storage().persistent().set(&key, &discount_bps);
+}
+
+/// Get the ceiling on id-based escrow amounts.
+///
+/// **Contract**: Returns `u64::MAX` (no ceiling) if never set.
+pub fn get_max_escrow_amount(env: &Env) -> u64 {
+    let key = DataKey::MaxEscrowAmount;
+    env.storage().persistent().get(&key).unwrap_or(u64::MAX)
+}
+
+/// Set the ceiling on id-based escrow amounts.
+pub fn set_max_escrow_amount(env: &Env, max: u64) {
+    let key = DataKey::MaxEscrowAmount;
+    env.storage().persistent().set(&key, &max);
+}
+
+/// Get the configured dispute timeout window in ledgers.
+///
+/// **Contract**: Returns `None` if the admin never set one.
+pub fn get_dispute_deadline_ledgers(env: &Env) -> Option<u32> {
+    let key = DataKey::DisputeDeadline;
+    env.storage().persistent().get(&key)
+}
+
+/// Set the dispute timeout window in ledgers.
+pub fn set_dispute_deadline_ledgers(env: &Env, ledgers: u32) {
+    let key = DataKey::DisputeDeadline;
+    env.storage().persistent().set(&key, &ledgers);
+}
+
+/// Get the baseline privacy level for unconfigured accounts.
+///
+/// **Contract**: Returns `None` if the admin never set one.
+pub fn get_default_privacy_level(env: &Env) -> Option<u32> {
+    let key = DataKey::DefaultPrivacyLevel;
+    env.storage().persistent().get(&key)
+}
+
+/// Set the baseline privacy level for unconfigured accounts.
+pub fn set_default_privacy_level(env: &Env, level: u32) {
+    let key = DataKey::DefaultPrivacyLevel;
+    env.storage().persistent().set(&key, &level);
+}
+
+/// Get the ledger sequence at which escrow `id` was disputed.
+pub fn get_disputed_at(env: &Env, id: u64) -> Option<u32> {
+    let key = DataKey::DisputedAt(id);
+    env.storage().persistent().get(&key)
+}
+
+/// Record the ledger sequence at which escrow `id` was disputed.
+pub fn set_disputed_at(env: &Env, id: u64, ledger: u32) {
+    let key = DataKey::DisputedAt(id);
+    env.storage().persistent().set(&key, &ledger);
+}
+
+/// Get the quick-cancellation rebate as `(amount, grace_ledgers)`.
+///
+/// **Contract**: Returns `(0, 0)` (no rebate) if never set.
+pub fn get_cancel_rebate(env: &Env) -> (u64, u32) {
+    let key = DataKey::CancelRebate;
+    env.storage().persistent().get(&key).unwrap_or((0, 0))
+}
+
+/// Set the quick-cancellation rebate amount and grace window.
+pub fn set_cancel_rebate(env: &Env, amount: u64, grace_ledgers: u32) {
+    let key = DataKey::CancelRebate;
+    env.storage()
+        .persistent()
+        .set(&key, &(amount, grace_ledgers));
+}
+
+/// Get the funds left in the rebate pool of `token`.
+///
+/// **Contract**: Returns 0 if the pool was never funded.
+pub fn get_rebate_pool(env: &Env, token: &Address) -> i128 {
+    let key = DataKey::RebatePool(token.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the funds left in the rebate pool of `token`.
+pub fn set_rebate_pool(env: &Env, token: &Address, amount: i128) {
+    let key = DataKey::RebatePool(token.clone());
+    env.storage().persistent().set(&key, &amount);
+}
+
+/// Get the cached number of non-terminal escrows `sender` sends.
+///
+/// **Contract**: Returns 0 if never set.
+pub fn get_open_escrow_count(env: &Env, sender: &Address) -> u32 {
+    let key = DataKey::OpenEscrowCount(sender.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the cached number of non-terminal escrows `sender` sends.
+pub fn set_open_escrow_count(env: &Env, sender: &Address, count: u32) {
+    let key = DataKey::OpenEscrowCount(sender.clone());
+    env.storage().persistent().set(&key, &count);
+}
+
+/// Get the sum of amounts held by open escrows of `token`.
+///
+/// **Contract**: Returns 0 if never set.
+pub fn get_locked_balance(env: &Env, token: &Address) -> i128 {
+    let key = DataKey::LockedBalance(token.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the sum of amounts held by open escrows of `token`.
+pub fn set_locked_balance(env: &Env, token: &Address, amount: i128) {
+    let key = DataKey::LockedBalance(token.clone());
+    env.storage().persistent().set(&key, &amount);
+}
+
 /// Get the next escrow counter value.
 ///
 /// **Contract**: Returns 0 if never set. Counter is used for `create_escrow`.
@@ -125,6 +547,22 @@ pub fn increment_escrow_counter(env: &Env) -> u64 {
     count
 }
 
+/// Put an id-based escrow record into storage.
+///
+/// **Contract**: Overwrites any existing record for the same id.
+pub fn put_escrow_data(env: &Env, escrow: &EscrowData) {
+    let key = DataKey::EscrowData(escrow.id);
+    env.storage().persistent().set(&key, escrow);
+}
+
+/// Get an id-based escrow record from storage.
+///
+/// **Contract**: Returns `None` if no escrow exists for the id.
+pub fn get_escrow_data(env: &Env, id: u64) -> Option<EscrowData> {
+    let key = DataKey::EscrowData(id);
+    env.storage().persistent().get(&key)
+}
+
 // -----------------------------------------------------------------------------
 // Admin helpers
 // -----------------------------------------------------------------------------
@@ -153,6 +591,252 @@ pub fn is_paused(env: &Env) -> bool {
     env.storage().persistent().get(&key).unwrap_or(false)
 }
 
+/// Get the ids of the escrows expiring at `expiry_ledger`, in creation order.
+///
+/// **Contract**: Returns empty vec if none.
+pub fn get_expiry_bucket(env: &Env, expiry_ledger: u32) -> Vec<u64> {
+    let key = DataKey::ExpiryBucket(expiry_ledger);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Append an escrow to the bucket of its expiry ledger.
+pub fn add_to_expiry_bucket(env: &Env, expiry_ledger: u32, id: u64) {
+    let mut bucket = get_expiry_bucket(env, expiry_ledger);
+    bucket.push_back(id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ExpiryBucket(expiry_ledger), &bucket);
+}
+
+/// Get the number of slots used in `sender`'s index.
+///
+/// **Contract**: Returns `0` if `sender` has never sent an escrow.
+pub fn get_sender_escrow_count(env: &Env, sender: &Address) -> u32 {
+    let key = DataKey::SenderEscrowCount(sender.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the number of slots used in `sender`'s index.
+pub fn set_sender_escrow_count(env: &Env, sender: &Address, count: u32) {
+    let key = DataKey::SenderEscrowCount(sender.clone());
+    env.storage().persistent().set(&key, &count);
+}
+
+/// Get one page of `sender`'s index.
+///
+/// **Contract**: Returns an empty list if the page was never written.
+pub fn get_sender_escrow_page(env: &Env, sender: &Address, page: u32) -> Vec<u64> {
+    let key = ExtKey::SenderEscrowPage(sender.clone(), page);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set one page of `sender`'s index.
+pub fn set_sender_escrow_page(env: &Env, sender: &Address, page: u32, ids: &Vec<u64>) {
+    let key = ExtKey::SenderEscrowPage(sender.clone(), page);
+    env.storage().persistent().set(&key, ids);
+}
+
+/// Get the number of slots used in `recipient`'s index.
+///
+/// **Contract**: Returns `0` if `recipient` has never received an escrow.
+pub fn get_recipient_escrow_count(env: &Env, recipient: &Address) -> u32 {
+    let key = DataKey::RecipientEscrowCount(recipient.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the number of slots used in `recipient`'s index.
+pub fn set_recipient_escrow_count(env: &Env, recipient: &Address, count: u32) {
+    let key = DataKey::RecipientEscrowCount(recipient.clone());
+    env.storage().persistent().set(&key, &count);
+}
+
+/// Get one page of `recipient`'s index.
+///
+/// **Contract**: Returns an empty list if the page was never written.
+pub fn get_recipient_escrow_page(env: &Env, recipient: &Address, page: u32) -> Vec<u64> {
+    let key = ExtKey::RecipientEscrowPage(recipient.clone(), page);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set one page of `recipient`'s index.
+pub fn set_recipient_escrow_page(env: &Env, recipient: &Address, page: u32, ids: &Vec<u64>) {
+    let key = ExtKey::RecipientEscrowPage(recipient.clone(), page);
+    env.storage().persistent().set(&key, ids);
+}
+
+/// Get the `(sender, recipient)` index slots of an escrow.
+pub fn get_escrow_index_slots(env: &Env, id: u64) -> Option<(u32, u32)> {
+    let key = ExtKey::EscrowIndexSlots(id);
+    env.storage().persistent().get(&key)
+}
+
+/// Set the `(sender, recipient)` index slots of an escrow.
+pub fn put_escrow_index_slots(env: &Env, id: u64, slots: (u32, u32)) {
+    let key = ExtKey::EscrowIndexSlots(id);
+    env.storage().persistent().set(&key, &slots);
+}
+
+/// Remove an escrow from the bucket of its expiry ledger, dropping the bucket once empty.
+///
+/// **Contract**: No-op if the escrow is not in the bucket.
+pub fn remove_from_expiry_bucket(env: &Env, expiry_ledger: u32, id: u64) {
+    let mut bucket = get_expiry_bucket(env, expiry_ledger);
+    if let Some(pos) = bucket.first_index_of(id) {
+        bucket.remove(pos);
+        let key = DataKey::ExpiryBucket(expiry_ledger);
+        if bucket.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &bucket);
+        }
+    }
+}
+
+/// Get the first expiry ledger the expiry sweep has not processed yet.
+///
+/// **Contract**: Returns `None` before any expiring escrow is created.
+pub fn get_expiry_sweep_cursor(env: &Env) -> Option<u32> {
+    let key = ExtKey::ExpirySweepCursor;
+    env.storage().persistent().get(&key)
+}
+
+/// Set the first expiry ledger the expiry sweep has not processed yet.
+pub fn set_expiry_sweep_cursor(env: &Env, ledger: u32) {
+    let key = ExtKey::ExpirySweepCursor;
+    env.storage().persistent().set(&key, &ledger);
+}
+
+/// Get the `(next_page, count)` progress of `sender`'s open-count repair.
+///
+/// **Contract**: Returns `(0, 0)` when no repair is in progress.
+pub fn get_open_count_repair(env: &Env, sender: &Address) -> (u32, u32) {
+    let key = ExtKey::OpenCountRepair(sender.clone());
+    env.storage().persistent().get(&key).unwrap_or((0, 0))
+}
+
+/// Set the `(next_page, count)` progress of `sender`'s open-count repair.
+pub fn set_open_count_repair(env: &Env, sender: &Address, progress: (u32, u32)) {
+    let key = ExtKey::OpenCountRepair(sender.clone());
+    env.storage().persistent().set(&key, &progress);
+}
+
+/// Clear `sender`'s open-count repair progress.
+pub fn remove_open_count_repair(env: &Env, sender: &Address) {
+    let key = ExtKey::OpenCountRepair(sender.clone());
+    env.storage().persistent().remove(&key);
+}
+
+/// Set the commitment domain tag.
+pub fn set_domain_tag(env: &Env, tag: &Bytes) {
+    let key = DataKey::DomainTag;
+    env.storage().persistent().set(&key, tag);
+}
+
+/// Fix the commitment domain tag for the lifetime of the contract.
+pub fn lock_domain_tag(env: &Env) {
+    let key = ExtKey::DomainTagLocked;
+    env.storage().persistent().set(&key, &true);
+}
+
+/// Check whether the commitment domain tag can no longer change.
+pub fn is_domain_tag_locked(env: &Env) -> bool {
+    let key = ExtKey::DomainTagLocked;
+    env.storage().persistent().has(&key)
+}
+
+/// Record the ledger sequence at which a commitment was tracked.
+pub fn set_tracked_commitment(env: &Env, commitment: &BytesN<32>, ledger: u32) {
+    let key = DataKey::TrackedCommitment(commitment.clone());
+    env.storage().persistent().set(&key, &ledger);
+}
+
+/// Get the commitments tracked in `ledger`, in creation order.
+///
+/// **Contract**: Returns an empty list if nothing was tracked in that ledger.
+pub fn get_tracked_at_ledger(env: &Env, ledger: u32) -> Vec<BytesN<32>> {
+    let key = DataKey::TrackedAtLedger(ledger);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Append a commitment to the commitments tracked in `ledger`.
+pub fn push_tracked_at_ledger(env: &Env, ledger: u32, commitment: &BytesN<32>) {
+    let mut bucket = get_tracked_at_ledger(env, ledger);
+    bucket.push_back(commitment.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::TrackedAtLedger(ledger), &bucket);
+}
+
+/// Get an owner's tracked commitment counter as `(ledger, count)`.
+///
+/// **Contract**: Returns `(0, 0)` if the owner never tracked a commitment.
+pub fn get_tracked_rate(env: &Env, owner: &Address) -> (u32, u32) {
+    let key = DataKey::TrackedRate(owner.clone());
+    env.storage().persistent().get(&key).unwrap_or((0, 0))
+}
+
+/// Set an owner's tracked commitment counter.
+pub fn set_tracked_rate(env: &Env, owner: &Address, ledger: u32, count: u32) {
+    let key = DataKey::TrackedRate(owner.clone());
+    env.storage().persistent().set(&key, &(ledger, count));
+}
+
+/// Get the ledger sequence at which a commitment was tracked.
+///
+/// **Contract**: Returns `None` for commitments never tracked.
+pub fn get_tracked_commitment(env: &Env, commitment: &BytesN<32>) -> Option<u32> {
+    let key = DataKey::TrackedCommitment(commitment.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Record who revealed a commitment's opening.
+pub fn set_commitment_opening(env: &Env, commitment: &BytesN<32>, record: &OpeningRecord) {
+    let key = DataKey::CommitmentOpening(commitment.clone());
+    env.storage().persistent().set(&key, record);
+}
+
+/// Get the recorded opening of a commitment.
+///
+/// **Contract**: Returns `None` if no opening was recorded.
+pub fn get_commitment_opening(env: &Env, commitment: &BytesN<32>) -> Option<OpeningRecord> {
+    let key = DataKey::CommitmentOpening(commitment.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Get the configured commitment domain tag.
+///
+/// **Contract**: Returns `None` if the admin never set one.
+pub fn get_domain_tag(env: &Env) -> Option<Bytes> {
+    let key = DataKey::DomainTag;
+    env.storage().persistent().get(&key)
+}
+
+/// Set the `(min, max)` commitment salt length policy.
+pub fn set_salt_policy(env: &Env, min_len: u32, max_len: u32) {
+    let key = DataKey::SaltPolicy;
+    env.storage().persistent().set(&key, &(min_len, max_len));
+}
+
+/// Get the configured `(min, max)` commitment salt length policy.
+///
+/// **Contract**: Returns `None` if the admin never set one.
+pub fn get_salt_policy(env: &Env) -> Option<(u32, u32)> {
+    let key = DataKey::SaltPolicy;
+    env.storage().persistent().get(&key)
+}
+
 // -----------------------------------------------------------------------------
 // Privacy helpers (level-based API)
 // -----------------------------------------------------------------------------
@@ -169,10 +853,30 @@ pub fn get_privacy_level(env: &Env, account: &Address) -> Option<u32> {
     env.storage().persistent().get(&key)
 }
 
+/// Remove the privacy level for an account.
+pub fn remove_privacy_level(env: &Env, account: &Address) {
+    let key = DataKey::PrivacyLevel(account.clone());
+    env.storage().persistent().remove(&key);
+}
+
+/// Get the number of accounts with a privacy level set.
+///
+/// **Contract**: Returns 0 if never set.
+pub fn get_configured_account_count(env: &Env) -> u32 {
+    let key = DataKey::ConfiguredAccountCount;
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the number of accounts with a privacy level set.
+pub fn set_configured_account_count(env: &Env, count: u32) {
+    let key = DataKey::ConfiguredAccountCount;
+    env.storage().persistent().set(&key, &count);
+}
+
 /// Add to privacy history for an account.
 ///
 /// **Contract**: Pushes `level` to the front of the history (newest first).
-/// History is unbounded; consider capping in future if needed.
+/// Does not trim; [`crate::privacy`] enforces the retention limit.
 pub fn add_privacy_history(env: &Env, account: &Address, level: u32) {
     let key = DataKey::PrivacyHistory(account.clone());
     let mut history: Vec<u32> = env
@@ -194,3 +898,374 @@ pub fn get_privacy_history(env: &Env, account: &Address) -> Vec<u32> {
         .get(&key)
         .unwrap_or(Vec::new(env))
 }
+
+/// Replace the privacy history for an account.
+pub fn set_privacy_history(env: &Env, account: &Address, history: &Vec<u32>) {
+    let key = DataKey::PrivacyHistory(account.clone());
+    env.storage().persistent().set(&key, history);
+}
+
+/// Get the ledger sequence of an account's latest privacy level change.
+///
+/// **Contract**: Returns `None` if the account's level never changed.
+pub fn get_last_privacy_change(env: &Env, account: &Address) -> Option<u32> {
+    let key = DataKey::LastPrivacyChange(account.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Set the ledger sequence of an account's latest privacy level change.
+pub fn set_last_privacy_change(env: &Env, account: &Address, ledger: u32) {
+    let key = DataKey::LastPrivacyChange(account.clone());
+    env.storage().persistent().set(&key, &ledger);
+}
+
+/// Get the operator registered for an account.
+///
+/// **Contract**: Returns `None` if none is registered.
+pub fn get_operator(env: &Env, account: &Address) -> Option<Address> {
+    let key = DataKey::Operator(account.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Register `operator` for an account, or clear it with `None`.
+pub fn set_operator(env: &Env, account: &Address, operator: Option<&Address>) {
+    let key = DataKey::Operator(account.clone());
+    match operator {
+        Some(operator) => env.storage().persistent().set(&key, operator),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Check whether an account's privacy events include its level.
+///
+/// **Contract**: Returns `false` if never set.
+pub fn is_event_verbose(env: &Env, account: &Address) -> bool {
+    let key = DataKey::EventVerbose(account.clone());
+    env.storage().persistent().get(&key).unwrap_or(false)
+}
+
+/// Set whether an account's privacy events include its level.
+pub fn set_event_verbose(env: &Env, account: &Address, verbose: bool) {
+    let key = DataKey::EventVerbose(account.clone());
+    env.storage().persistent().set(&key, &verbose);
+}
+
+/// Get the hash-chain head of an account's privacy history.
+///
+/// **Contract**: Returns `None` if the account never recorded a level.
+pub fn get_privacy_history_head(env: &Env, account: &Address) -> Option<BytesN<32>> {
+    let key = DataKey::PrivacyHistoryHead(account.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Set the hash-chain head of an account's privacy history.
+pub fn set_privacy_history_head(env: &Env, account: &Address, head: &BytesN<32>) {
+    let key = DataKey::PrivacyHistoryHead(account.clone());
+    env.storage().persistent().set(&key, head);
+}
+
+/// Get the latest checkpointed privacy history chain head of an account.
+///
+/// **Contract**: Returns `None` if the account never checkpointed.
+pub fn get_privacy_checkpoint(env: &Env, account: &Address) -> Option<BytesN<32>> {
+    let key = DataKey::PrivacyCheckpoint(account.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Record a checkpoint of an account's privacy history chain head.
+pub fn set_privacy_checkpoint(env: &Env, account: &Address, head: &BytesN<32>) {
+    let key = DataKey::PrivacyCheckpoint(account.clone());
+    env.storage().persistent().set(&key, head);
+}
+
+/// Get the recorded result of an idempotent privacy change.
+///
+/// **Contract**: Returns `None` if `idem_key` was never used for `account`.
+pub fn get_privacy_idem_result(
+    env: &Env,
+    account: &Address,
+    idem_key: &BytesN<32>,
+) -> Option<bool> {
+    let key = DataKey::PrivacyIdemKey(account.clone(), idem_key.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Record the result of an idempotent privacy change.
+pub fn set_privacy_idem_result(env: &Env, account: &Address, idem_key: &BytesN<32>, result: bool) {
+    let key = DataKey::PrivacyIdemKey(account.clone(), idem_key.clone());
+    env.storage().persistent().set(&key, &result);
+}
+
+/// Set the privacy history depth for an account.
+pub fn set_history_limit(env: &Env, account: &Address, limit: u32) {
+    let key = DataKey::HistoryLimit(account.clone());
+    env.storage().persistent().set(&key, &limit);
+}
+
+/// Set the current `(version, seed_hash)` salt seed for an account.
+pub fn set_salt_seed(env: &Env, account: &Address, version: u32, seed_hash: &BytesN<32>) {
+    let key = DataKey::SaltSeed(account.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &(version, seed_hash.clone()));
+}
+
+/// Get the current `(version, seed_hash)` salt seed for an account.
+///
+/// **Contract**: Returns `None` if the account never rotated a seed.
+pub fn get_salt_seed(env: &Env, account: &Address) -> Option<(u32, BytesN<32>)> {
+    let key = DataKey::SaltSeed(account.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Set the ed25519 key allowed to sign privacy changes for an account.
+pub fn set_privacy_signer(env: &Env, account: &Address, pubkey: &BytesN<32>) {
+    let key = DataKey::PrivacySigner(account.clone());
+    env.storage().persistent().set(&key, pubkey);
+}
+
+/// Get the ed25519 key allowed to sign privacy changes for an account.
+///
+/// **Contract**: Returns `None` if the account never registered one.
+pub fn get_privacy_signer(env: &Env, account: &Address) -> Option<BytesN<32>> {
+    let key = DataKey::PrivacySigner(account.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Set the signed privacy change nonce for an account.
+pub fn set_privacy_nonce(env: &Env, account: &Address, nonce: u64) {
+    let key = DataKey::PrivacyNonce(account.clone());
+    env.storage().persistent().set(&key, &nonce);
+}
+
+/// Get the signed privacy change nonce for an account.
+///
+/// **Contract**: Returns 0 if never set.
+pub fn get_privacy_nonce(env: &Env, account: &Address) -> u64 {
+    let key = DataKey::PrivacyNonce(account.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Grant or revoke `viewer`'s access to `owner`'s private data.
+pub fn set_viewer_grant(env: &Env, owner: &Address, viewer: &Address, granted: bool) {
+    let key = DataKey::ViewerGrant(owner.clone(), viewer.clone());
+    if granted {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Check whether `owner` granted `viewer` access.
+pub fn has_viewer_grant(env: &Env, owner: &Address, viewer: &Address) -> bool {
+    let key = DataKey::ViewerGrant(owner.clone(), viewer.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Add or remove `viewer` from the global blocklist.
+pub fn set_viewer_blocked(env: &Env, viewer: &Address, blocked: bool) {
+    let key = DataKey::BlockedViewer(viewer.clone());
+    if blocked {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Check whether `viewer` is on the global blocklist.
+pub fn is_viewer_blocked(env: &Env, viewer: &Address) -> bool {
+    let key = DataKey::BlockedViewer(viewer.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Freeze or unfreeze an account's privacy settings.
+pub fn set_privacy_frozen(env: &Env, account: &Address, frozen: bool) {
+    let key = DataKey::PrivacyFrozen(account.clone());
+    if frozen {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Check whether an account's privacy settings are frozen.
+pub fn is_privacy_frozen(env: &Env, account: &Address) -> bool {
+    let key = DataKey::PrivacyFrozen(account.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Permanently seal an account's privacy settings.
+pub fn set_privacy_sealed(env: &Env, account: &Address) {
+    let key = DataKey::PrivacySealed(account.clone());
+    env.storage().persistent().set(&key, &true);
+}
+
+/// Check whether an account sealed its privacy settings.
+pub fn is_privacy_sealed(env: &Env, account: &Address) -> bool {
+    let key = DataKey::PrivacySealed(account.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Get the vote weight commitment a voter registered.
+///
+/// **Contract**: Returns `None` if the voter never registered.
+pub fn get_vote_commitment(env: &Env, voter: &Address) -> Option<BytesN<32>> {
+    let key = ExtKey::VoteCommitment(voter.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Set the vote weight commitment of a voter.
+pub fn set_vote_commitment(env: &Env, voter: &Address, commitment: &BytesN<32>) {
+    let key = ExtKey::VoteCommitment(voter.clone());
+    env.storage().persistent().set(&key, commitment);
+}
+
+/// Get a voter's revealed vote weight.
+///
+/// **Contract**: Returns `None` until the weight is revealed.
+pub fn get_vote_weight(env: &Env, voter: &Address) -> Option<i128> {
+    let key = ExtKey::VoteWeight(voter.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Set a voter's revealed vote weight.
+pub fn set_vote_weight(env: &Env, voter: &Address, weight: i128) {
+    let key = ExtKey::VoteWeight(voter.clone());
+    env.storage().persistent().set(&key, &weight);
+}
+
+/// Set whether releasing an escrow calls back into its recipient.
+pub fn set_release_callback(env: &Env, id: u64, enabled: bool) {
+    let key = ExtKey::ReleaseCallback(id);
+    env.storage().persistent().set(&key, &enabled);
+}
+
+/// Returns `true` when releasing the escrow calls back into its recipient.
+///
+/// **Contract**: Returns `false` if never set.
+pub fn has_release_callback(env: &Env, id: u64) -> bool {
+    let key = ExtKey::ReleaseCallback(id);
+    env.storage().persistent().get(&key).unwrap_or(false)
+}
+
+/// Get the frontier of the commitment Merkle tree.
+///
+/// **Contract**: Returns an empty vec before the first insertion.
+pub fn get_merkle_frontier(env: &Env) -> Vec<BytesN<32>> {
+    let key = ExtKey::MerkleFrontier;
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the frontier of the commitment Merkle tree.
+pub fn set_merkle_frontier(env: &Env, frontier: &Vec<BytesN<32>>) {
+    let key = ExtKey::MerkleFrontier;
+    env.storage().persistent().set(&key, frontier);
+}
+
+/// Get the root of the commitment Merkle tree.
+///
+/// **Contract**: Returns `None` before the first insertion.
+pub fn get_merkle_root(env: &Env) -> Option<BytesN<32>> {
+    let key = ExtKey::MerkleRoot;
+    env.storage().persistent().get(&key)
+}
+
+/// Set the root of the commitment Merkle tree.
+pub fn set_merkle_root(env: &Env, root: &BytesN<32>) {
+    let key = ExtKey::MerkleRoot;
+    env.storage().persistent().set(&key, root);
+}
+
+/// Get the number of leaves in the commitment Merkle tree.
+///
+/// **Contract**: Returns `0` before the first insertion.
+pub fn get_merkle_size(env: &Env) -> u32 {
+    let key = ExtKey::MerkleSize;
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the number of leaves in the commitment Merkle tree.
+pub fn set_merkle_size(env: &Env, size: u32) {
+    let key = ExtKey::MerkleSize;
+    env.storage().persistent().set(&key, &size);
+}
+
+/// Set the fee an escrow pays its arbiter on dispute resolution.
+pub fn put_escrow_arbiter_fee(env: &Env, id: u64, fee: u64) {
+    let key = ExtKey::EscrowArbiterFee(id);
+    env.storage().persistent().set(&key, &fee);
+}
+
+/// Get the fee an escrow pays its arbiter on dispute resolution.
+///
+/// **Contract**: Returns `0` if no fee was set.
+pub fn get_escrow_arbiter_fee(env: &Env, id: u64) -> u64 {
+    let key = ExtKey::EscrowArbiterFee(id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Get the escrow fees withheld in `token` that the admin has not collected.
+///
+/// **Contract**: Returns `0` if none.
+pub fn get_collected_fees(env: &Env, token: &Address) -> i128 {
+    let key = ExtKey::CollectedFees(token.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the escrow fees withheld in `token` that the admin has not collected.
+pub fn set_collected_fees(env: &Env, token: &Address, amount: i128) {
+    let key = ExtKey::CollectedFees(token.clone());
+    env.storage().persistent().set(&key, &amount);
+}
+
+/// Mark a sender's delivery receipt as used.
+pub fn set_receipt_used(env: &Env, sender: &Address, receipt_hash: &BytesN<32>) {
+    let key = ExtKey::UsedReceipt(sender.clone(), receipt_hash.clone());
+    env.storage().persistent().set(&key, &true);
+}
+
+/// Check whether a sender's delivery receipt was already used.
+pub fn is_receipt_used(env: &Env, sender: &Address, receipt_hash: &BytesN<32>) -> bool {
+    let key = ExtKey::UsedReceipt(sender.clone(), receipt_hash.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Mark a nullifier as spent.
+pub fn set_nullifier_spent(env: &Env, nullifier: &BytesN<32>) {
+    let key = DataKey::Nullifier(nullifier.clone());
+    env.storage().persistent().set(&key, &true);
+}
+
+/// Check whether a nullifier has been spent.
+pub fn is_nullifier_spent(env: &Env, nullifier: &BytesN<32>) -> bool {
+    let key = DataKey::Nullifier(nullifier.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Get the per-level account counters.
+///
+/// **Contract**: Returns an empty map if never set.
+pub fn get_level_histogram(env: &Env) -> Map<u32, u32> {
+    let key = DataKey::LevelHistogram;
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Map::new(env))
+}
+
+/// Set the per-level account counters.
+pub fn set_level_histogram(env: &Env, histogram: &Map<u32, u32>) {
+    let key = DataKey::LevelHistogram;
+    env.storage().persistent().set(&key, histogram);
+}
+
+/// Get the privacy history depth for an account.
+///
+/// **Contract**: Returns `None` if the account never set one.
+pub fn get_history_limit(env: &Env, account: &Address) -> Option<u32> {
+    let key = DataKey::HistoryLimit(account.clone());
+    env.storage().persistent().get(&key)
+}
@@ -1,6 +1,6 @@
 use crate::{
-    errors::QuickexError, storage::put_escrow, EscrowEntry, EscrowStatus, QuickexContract,
-    QuickexContractClient,
+    commitment::DEFAULT_DOMAIN_TAG, errors::QuickexError, storage::put_escrow,
+    types::ContractConfig, EscrowEntry, EscrowStatus, QuickexContract, QuickexContractClient,
 };
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
@@ -82,7 +82,7 @@ fn test_get_escrow_details_privacy_enabled_hides_sensitive_fields() {
     let amount: i128 = 5000;
     let salt = Bytes::from_slice(&env, b"priv_hide_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     data.append(&owner.clone().to_xdr(&env));
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
     data.append(&salt);
@@ -118,7 +118,7 @@ fn test_get_escrow_details_privacy_enabled_owner_sees_full_details() {
     let amount: i128 = 5000;
     let salt = Bytes::from_slice(&env, b"priv_owner_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     data.append(&owner.clone().to_xdr(&env));
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
     data.append(&salt);
@@ -155,7 +155,7 @@ fn test_get_escrow_details_privacy_disabled_shows_full_details() {
     let amount: i128 = 2500;
     let salt = Bytes::from_slice(&env, b"priv_off_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     data.append(&owner.clone().to_xdr(&env));
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
     data.append(&salt);
@@ -231,7 +231,7 @@ fn test_successful_withdrawal() {
     let amount: i128 = 1000;
     let salt = Bytes::from_slice(&env, b"test_salt_123");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
 
     let address_bytes: Bytes = to.clone().to_xdr(&env);
 
@@ -259,7 +259,7 @@ fn test_double_withdrawal_fails() {
     let amount: i128 = 1000;
     let salt = Bytes::from_slice(&env, b"test_salt_456");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = to.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -280,6 +280,42 @@ fn test_double_withdrawal_fails() {
     assert_contract_error(second_result, QuickexError::AlreadySpent);
 }
 
+#[test]
+fn test_settle_at_least_meets_minimum() {
+    let (env, client) = setup();
+    let token = create_test_token(&env);
+    let to = Address::generate(&env);
+    let amount: i128 = 1000;
+    let salt = Bytes::from_slice(&env, b"at_least");
+    let commitment = client.create_amount_commitment(&to, &amount, &salt);
+    setup_escrow(&env, &client.address, &token, amount, commitment.clone(), 0);
+    token::StellarAssetClient::new(&env, &token).mint(&client.address, &amount);
+
+    client.settle_at_least(&commitment, &to, &amount, &salt, &600);
+
+    assert_eq!(token::Client::new(&env, &token).balance(&to), amount);
+    let result = client.try_settle_at_least(&commitment, &to, &amount, &salt, &600);
+    assert_contract_error(result, QuickexError::AlreadySpent);
+}
+
+#[test]
+fn test_settle_at_least_below_minimum_reverts() {
+    let (env, client) = setup();
+    let token = create_test_token(&env);
+    let to = Address::generate(&env);
+    let amount: i128 = 500;
+    let salt = Bytes::from_slice(&env, b"at_least");
+    let commitment = client.create_amount_commitment(&to, &amount, &salt);
+    setup_escrow(&env, &client.address, &token, amount, commitment.clone(), 0);
+    token::StellarAssetClient::new(&env, &token).mint(&client.address, &amount);
+
+    let result = client.try_settle_at_least(&commitment, &to, &amount, &salt, &501);
+    assert_contract_error(result, QuickexError::AmountBelowMinimum);
+    let result = client.try_settle_at_least(&commitment, &to, &499, &salt, &0);
+    assert_contract_error(result, QuickexError::CommitmentNotFound);
+    assert_eq!(token::Client::new(&env, &token).balance(&to), 0);
+}
+
 #[test]
 fn test_invalid_salt_fails() {
     let (env, client) = setup();
@@ -289,7 +325,7 @@ fn test_invalid_salt_fails() {
     let correct_salt = Bytes::from_slice(&env, b"correct_salt");
     let wrong_salt = Bytes::from_slice(&env, b"wrong_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = to.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -312,7 +348,7 @@ fn test_invalid_amount_fails() {
     let wrong_amount: i128 = 500;
     let salt = Bytes::from_slice(&env, b"test_salt_789");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = to.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &correct_amount.to_be_bytes()));
@@ -342,7 +378,7 @@ fn test_zero_amount_fails() {
     let amount: i128 = 0;
     let salt = Bytes::from_slice(&env, b"test_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = to.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -363,7 +399,7 @@ fn test_negative_amount_fails() {
     let amount: i128 = -100;
     let salt = Bytes::from_slice(&env, b"test_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = to.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -384,7 +420,7 @@ fn test_nonexistent_commitment_fails() {
     let amount: i128 = 1000;
     let salt = Bytes::from_slice(&env, b"nonexistent");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = to.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -396,6 +432,56 @@ fn test_nonexistent_commitment_fails() {
     assert_contract_error(result, QuickexError::CommitmentNotFound);
 }
 
+#[test]
+fn test_set_domain_tag_locked_after_first_deposit() {
+    let (env, client) = setup();
+    let token = create_test_token(&env);
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    client.initialize(&admin);
+    token::StellarAssetClient::new(&env, &token).mint(&owner, &1_000);
+
+    let salt = Bytes::from_slice(&env, b"tag_lock");
+    client.deposit(&token, &1_000, &owner, &salt, &0);
+
+    let result = client.try_set_domain_tag(&admin, &Bytes::from_slice(&env, b"late-tag"));
+    assert_contract_error(result, QuickexError::DomainTagLocked);
+
+    // The deposit is still keyed under the tag it was made with.
+    let commitment = client.create_amount_commitment(&owner, &1_000, &salt);
+    client.withdraw(&token, &1_000, &commitment, &owner, &salt);
+    assert_eq!(token::Client::new(&env, &token).balance(&owner), 1_000);
+}
+
+#[test]
+fn test_withdraw_deposit_made_before_domain_tag() {
+    let (env, client) = setup();
+    let token = create_test_token(&env);
+    let admin = Address::generate(&env);
+    let to = Address::generate(&env);
+    let amount: i128 = 1000;
+    let salt = Bytes::from_slice(&env, b"pre_tag_salt");
+    client.initialize(&admin);
+
+    // A deposit made before domain tags existed is keyed by the untagged preimage.
+    let mut data = to.clone().to_xdr(&env);
+    data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
+    data.append(&salt);
+    let commitment: BytesN<32> = env.crypto().sha256(&data).into();
+    setup_escrow(&env, &client.address, &token, amount, commitment.clone(), 0);
+    token::StellarAssetClient::new(&env, &token).mint(&client.address, &amount);
+
+    client.set_domain_tag(&admin, &Bytes::from_slice(&env, b"deployment-a"));
+
+    assert!(client.verify_proof_view(&amount, &salt, &to));
+    client.withdraw(&token, &amount, &commitment, &to, &salt);
+    assert_eq!(token::Client::new(&env, &token).balance(&to), amount);
+    assert_eq!(
+        client.get_commitment_state(&commitment),
+        Some(EscrowStatus::Spent)
+    );
+}
+
 #[test]
 fn test_set_and_get_privacy() {
     let (env, client) = setup();
@@ -447,8 +533,14 @@ fn test_create_escrow() {
     let from = Address::generate(&env);
     let to = Address::generate(&env);
     let amount = 1_000;
-    let escrow_id = client.create_escrow(&from, &to, &amount);
+    let token = create_test_token(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&from, &(amount as i128));
+    let escrow_id = client.create_escrow(&from, &to, &amount, &token);
     assert!(escrow_id > 0);
+    assert_eq!(
+        token::Client::new(&env, &token).balance(&client.address),
+        1_000
+    );
 }
 
 #[test]
@@ -463,6 +555,20 @@ fn test_canonical_error_code_ranges() {
     assert_eq!(QuickexError::InvalidAmount as u32, 100);
     assert_eq!(QuickexError::InvalidSalt as u32, 101);
     assert_eq!(QuickexError::InvalidPrivacyLevel as u32, 102);
+    assert_eq!(QuickexError::InvalidSignature as u32, 103);
+    assert_eq!(QuickexError::InvalidDomainTag as u32, 104);
+    assert_eq!(QuickexError::InvalidExpiry as u32, 105);
+    assert_eq!(QuickexError::InvalidSaltPolicy as u32, 106);
+    assert_eq!(QuickexError::InvalidHistoryLimit as u32, 107);
+    assert_eq!(QuickexError::InvalidRange as u32, 108);
+    assert_eq!(QuickexError::InvalidThreshold as u32, 109);
+    assert_eq!(QuickexError::InvalidHex as u32, 110);
+    assert_eq!(QuickexError::SelfEscrow as u32, 111);
+    assert_eq!(QuickexError::InvalidFeeBps as u32, 112);
+    assert_eq!(QuickexError::AmountTooLarge as u32, 113);
+    assert_eq!(QuickexError::LengthMismatch as u32, 114);
+    assert_eq!(QuickexError::AmountBelowMinimum as u32, 115);
+    assert_eq!(QuickexError::MemoTooLong as u32, 116);
 
     // Auth/admin failures (200-299)
     assert_eq!(QuickexError::Unauthorized as u32, 200);
@@ -479,6 +585,22 @@ fn test_canonical_error_code_ranges() {
     assert_eq!(QuickexError::EscrowExpired as u32, 307);
     assert_eq!(QuickexError::EscrowNotExpired as u32, 308);
     assert_eq!(QuickexError::InvalidOwner as u32, 309);
+    assert_eq!(QuickexError::EscrowNotFound as u32, 310);
+    assert_eq!(QuickexError::PrivacyNotConfigured as u32, 311);
+    assert_eq!(QuickexError::CampaignNotFound as u32, 312);
+    assert_eq!(QuickexError::AlreadyInCampaign as u32, 313);
+    assert_eq!(QuickexError::InsufficientApprovals as u32, 314);
+    assert_eq!(QuickexError::PrivacyFrozen as u32, 315);
+    assert_eq!(QuickexError::LockNotElapsed as u32, 316);
+    assert_eq!(QuickexError::NameNotResolved as u32, 317);
+    assert_eq!(QuickexError::RateLimited as u32, 318);
+    assert_eq!(QuickexError::EscrowNotDisputed as u32, 319);
+    assert_eq!(QuickexError::DisputeWindowOpen as u32, 320);
+    assert_eq!(QuickexError::HashlockMismatch as u32, 321);
+    assert_eq!(QuickexError::PrivacySealed as u32, 322);
+    assert_eq!(QuickexError::MerkleTreeFull as u32, 323);
+    assert_eq!(QuickexError::ExpiryLedgerFull as u32, 324);
+    assert_eq!(QuickexError::DomainTagLocked as u32, 325);
 
     // Internal/unexpected conditions (900-999)
     assert_eq!(QuickexError::InternalError as u32, 900);
@@ -525,6 +647,56 @@ fn test_initialize_admin() {
     assert!(!client.is_paused());
 }
 
+#[test]
+fn test_is_initialized() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+
+    assert!(!client.is_initialized());
+
+    client.initialize(&admin);
+
+    assert!(client.is_initialized());
+}
+
+#[test]
+fn test_get_config_reports_defaults_and_updates() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    assert_eq!(
+        client.get_config(),
+        ContractConfig {
+            fee_bps: 0,
+            max_escrow_amount: u64::MAX,
+            max_salt_len: 1024,
+            paused: false,
+            default_privacy_level: None,
+            dispute_deadline_ledgers: 120_960,
+        }
+    );
+
+    client.set_escrow_fee_bps(&admin, &250);
+    client.set_max_escrow_amount(&admin, &5_000);
+    client.set_salt_policy(&admin, &8, &64);
+    client.set_paused(&admin, &true);
+    client.set_default_privacy_level(&admin, &1);
+    client.set_dispute_deadline_ledgers(&admin, &500);
+
+    assert_eq!(
+        client.get_config(),
+        ContractConfig {
+            fee_bps: 250,
+            max_escrow_amount: 5_000,
+            max_salt_len: 64,
+            paused: true,
+            default_privacy_level: Some(1),
+            dispute_deadline_ledgers: 500,
+        }
+    );
+}
+
 #[test]
 fn test_initialize_twice_fails() {
     let (env, client) = setup();
@@ -576,7 +748,7 @@ fn test_withdraw_fails_when_paused() {
     let amount: i128 = 1000;
     let salt = Bytes::from_slice(&env, b"paused_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = to.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -682,7 +854,7 @@ fn test_get_commitment_state_pending() {
     let amount: i128 = 1000;
     let salt = Bytes::from_slice(&env, b"test_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = owner.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -703,7 +875,7 @@ fn test_get_commitment_state_spent() {
     let amount: i128 = 1000;
     let salt = Bytes::from_slice(&env, b"test_salt_spent");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = owner.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -736,7 +908,7 @@ fn test_get_commitment_state_not_found() {
     let amount: i128 = 1000;
     let salt = Bytes::from_slice(&env, b"nonexistent_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = owner.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -755,7 +927,7 @@ fn test_verify_proof_view_valid() {
     let amount: i128 = 1000;
     let salt = Bytes::from_slice(&env, b"valid_proof_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = owner.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -777,7 +949,7 @@ fn test_verify_proof_view_wrong_amount() {
     let wrong_amount: i128 = 500;
     let salt = Bytes::from_slice(&env, b"amount_test_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = owner.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &correct_amount.to_be_bytes()));
@@ -806,7 +978,7 @@ fn test_verify_proof_view_wrong_salt() {
     let correct_salt = Bytes::from_slice(&env, b"correct_salt");
     let wrong_salt = Bytes::from_slice(&env, b"wrong_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = owner.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -828,7 +1000,7 @@ fn test_verify_proof_view_wrong_owner() {
     let amount: i128 = 1000;
     let salt = Bytes::from_slice(&env, b"owner_test_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = correct_owner.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -849,7 +1021,7 @@ fn test_verify_proof_view_spent_commitment() {
     let amount: i128 = 1000;
     let salt = Bytes::from_slice(&env, b"spent_commitment_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = owner.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -896,7 +1068,7 @@ fn test_get_escrow_details_found() {
     let amount: i128 = 1000;
     let salt = Bytes::from_slice(&env, b"details_test_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = owner.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -923,7 +1095,7 @@ fn test_get_escrow_details_not_found() {
     let amount: i128 = 1000;
     let salt = Bytes::from_slice(&env, b"not_found_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = owner.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -943,7 +1115,7 @@ fn test_get_escrow_details_spent_status() {
     let amount: i128 = 1000;
     let salt = Bytes::from_slice(&env, b"spent_details_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = owner.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -1056,7 +1228,7 @@ fn test_withdrawal_fails_after_expiry() {
     let amount: i128 = 1000;
     let salt = Bytes::from_slice(&env, b"expiry_salt");
 
-    let mut data = Bytes::new(&env);
+    let mut data = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     let address_bytes: Bytes = to.clone().to_xdr(&env);
     data.append(&address_bytes);
     data.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
@@ -1086,7 +1258,7 @@ fn test_withdrawal_fails_after_expiry() {
 
     // Setup another one for the expiry test
     let salt2 = Bytes::from_slice(&env, b"expiry_salt_2");
-    let mut data2 = Bytes::new(&env);
+    let mut data2 = Bytes::from_slice(&env, DEFAULT_DOMAIN_TAG);
     data2.append(&to.clone().to_xdr(&env));
     data2.append(&Bytes::from_slice(&env, &amount.to_be_bytes()));
     data2.append(&salt2);
@@ -1188,3 +1360,116 @@ fn test_double_refund_fails() {
     let res = client.try_refund(&commitment, &owner);
     assert_eq!(res, Err(Ok(crate::errors::QuickexError::AlreadySpent)));
 }
+
+// ============================================================================
+// Reconciliation Tests
+// ============================================================================
+
+#[test]
+fn test_reconcile_zero_when_healthy() {
+    let (env, client) = setup();
+    let token = create_test_token(&env);
+    let token_admin = token::StellarAssetClient::new(&env, &token);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    token_admin.mint(&alice, &1_000);
+    token_admin.mint(&bob, &1_000);
+
+    assert_eq!(client.reconcile(&token), 0);
+
+    let salt = Bytes::from_slice(&env, b"reconcile_a");
+    client.deposit(&token, &400, &alice, &salt, &0);
+    client.deposit_with_commitment(&bob, &token, &250, &BytesN::from_array(&env, &[7; 32]), &0);
+    assert_eq!(client.reconcile(&token), 0);
+
+    client.withdraw(
+        &token,
+        &400,
+        &BytesN::from_array(&env, &[0; 32]),
+        &alice,
+        &salt,
+    );
+    assert_eq!(client.reconcile(&token), 0);
+}
+
+#[test]
+fn test_locked_balance_tracked_per_token() {
+    let (env, client) = setup();
+    let token_a = create_test_token(&env);
+    let token_b = create_test_token(&env);
+    let owner = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_a).mint(&owner, &1_000);
+    token::StellarAssetClient::new(&env, &token_b).mint(&owner, &1_000);
+    assert_eq!(client.locked_balance(&token_a), 0);
+
+    let salt_a1 = Bytes::from_slice(&env, b"locked_a1");
+    client.deposit(&token_a, &300, &owner, &salt_a1, &0);
+    client.deposit(
+        &token_a,
+        &200,
+        &owner,
+        &Bytes::from_slice(&env, b"locked_a2"),
+        &0,
+    );
+    client.deposit(
+        &token_b,
+        &450,
+        &owner,
+        &Bytes::from_slice(&env, b"locked_b"),
+        &0,
+    );
+    assert_eq!(client.locked_balance(&token_a), 500);
+    assert_eq!(client.locked_balance(&token_b), 450);
+
+    client.withdraw(
+        &token_a,
+        &300,
+        &BytesN::from_array(&env, &[0; 32]),
+        &owner,
+        &salt_a1,
+    );
+    assert_eq!(client.locked_balance(&token_a), 200);
+    assert_eq!(client.locked_balance(&token_b), 450);
+
+    // Funds not backed by an escrow are not counted.
+    token::StellarAssetClient::new(&env, &token_b).mint(&client.address, &25);
+    assert_eq!(client.locked_balance(&token_b), 450);
+}
+
+#[test]
+fn test_reconcile_reports_untracked_surplus() {
+    let (env, client) = setup();
+    let token = create_test_token(&env);
+    let token_admin = token::StellarAssetClient::new(&env, &token);
+    let owner = Address::generate(&env);
+    token_admin.mint(&owner, &1_000);
+
+    client.deposit(
+        &token,
+        &500,
+        &owner,
+        &Bytes::from_slice(&env, b"surplus"),
+        &0,
+    );
+
+    // A direct transfer to the contract is not backed by any escrow.
+    token_admin.mint(&client.address, &30);
+    assert_eq!(client.reconcile(&token), 30);
+}
+
+#[test]
+fn test_solvency_ratio_bps_solvent() {
+    let (env, client) = setup();
+    let token = create_test_token(&env);
+    let token_admin = token::StellarAssetClient::new(&env, &token);
+    let owner = Address::generate(&env);
+    token_admin.mint(&owner, &1_000);
+    assert_eq!(client.solvency_ratio_bps(&token), u32::MAX);
+
+    let salt = Bytes::from_slice(&env, b"solvent");
+    client.deposit(&token, &500, &owner, &salt, &0);
+    assert_eq!(client.solvency_ratio_bps(&token), 10_000);
+
+    token_admin.mint(&client.address, &30);
+    assert_eq!(client.solvency_ratio_bps(&token), 10_600);
+}
@@ -2,7 +2,7 @@
 //!
 //! See [`crate::storage`] for the storage schema and key layout.
 
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, Bytes, Symbol, Vec};
 
 /// Escrow entry status.
 ///
@@ -17,6 +17,11 @@ use soroban_sdk::{contracttype, Address};
 /// - `Pending`:  Funds are escrowed, awaiting withdrawal or refund.
 /// - `Spent`:    Withdrawal completed successfully. Terminal state.
 /// - `Refunded`: Owner reclaimed funds after timeout. Terminal state.
+///
+/// Id-based escrows (see [`EscrowData`]) share this enum: `Released` once the funds
+/// have been paid out to the recipient, `Refunded` once an expired escrow was returned
+/// to the sender, and `Cancelled` once a party called off the escrow. A `Disputed`
+/// escrow still holds its funds until the admin resolves it.
 #[contracttype]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum EscrowStatus {
@@ -26,6 +31,12 @@ pub enum EscrowStatus {
     /// equivalent to an escrow that has passed expiry but not yet been refunded.
     Expired,
     Refunded,
+    /// Id-based escrow paid out to its recipient. Terminal state.
+    Released,
+    /// Id-based escrow called off by one of its parties. Terminal state.
+    Cancelled,
+    /// Id-based escrow frozen by a party's dispute until the admin resolves it.
+    Disputed,
 }
 
 /// Escrow entry structure.
@@ -83,3 +94,143 @@ pub struct PrivacyAwareEscrowView {
     /// Expiry timestamp; `0` means no expiry (always visible).
     pub expires_at: u64,
 }
+
+/// Id-based escrow record.
+///
+/// Stored under [`DataKey::EscrowData`](crate::storage::DataKey::EscrowData)(id) in persistent
+/// storage. Created by [`QuickexContract::create_escrow`](crate::QuickexContract::create_escrow);
+/// unlike [`EscrowEntry`], parties and amount are recorded in the clear.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowData {
+    /// Sequential id returned by `create_escrow`.
+    pub id: u64,
+    /// Sender who created the escrow.
+    pub from: Address,
+    /// Recipient who receives the funds on release.
+    pub to: Address,
+    /// Amount in token base units.
+    pub amount: u64,
+    /// Current status (Pending, Released, Refunded, Cancelled).
+    pub status: EscrowStatus,
+    /// Ledger sequence when the escrow was created.
+    pub created_ledger: u32,
+    /// Ledger sequence from which the escrow can be refunded to the sender.
+    /// A value of `0` means the escrow never expires.
+    pub expiry_ledger: u32,
+    /// Campaign this escrow is grouped under. A value of `0` means no campaign.
+    pub campaign_id: u64,
+    /// Simple interest accrued per elapsed ledger, in basis points of `amount`.
+    /// A value of `0` means the escrow accrues no interest.
+    pub interest_bps_per_ledger: u32,
+    /// Principal plus accrued interest, minus `fee_amount`, paid to the recipient.
+    /// `0` until released.
+    pub released_amount: u64,
+    /// Ledgers after `created_ledger` before the escrow can be released.
+    pub min_lock_ledgers: u32,
+    /// Escrow fee withheld from the payout at release. `0` until released.
+    pub fee_amount: u64,
+    /// Token the contract holds for this escrow. `None` for escrows that only
+    /// record the amount and move no funds.
+    pub token: Option<Address>,
+}
+
+/// Group of id-based escrows managed together, e.g. a crowdfunding batch.
+///
+/// Stored under [`DataKey::Campaign`](crate::storage::DataKey::Campaign)(id).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Campaign {
+    /// Sequential id returned by `create_campaign`, starting at 1.
+    pub id: u64,
+    /// Owner who created the campaign.
+    pub owner: Address,
+    /// Ids of the escrows attached to the campaign, in attachment order.
+    pub escrow_ids: Vec<u64>,
+}
+
+/// Approver set of an M-of-N id-based escrow.
+///
+/// Stored under [`DataKey::EscrowApprovals`](crate::storage::DataKey::EscrowApprovals)(id)
+/// for escrows created with `create_mofn_escrow`; other escrows have no entry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowApprovals {
+    /// Addresses allowed to approve the release.
+    pub approvers: Vec<Address>,
+    /// Number of distinct approvals required before release.
+    pub threshold: u32,
+    /// Approvers who have approved so far, in approval order.
+    pub approved: Vec<Address>,
+}
+
+/// Combined privacy state of one account.
+///
+/// Returned by [`QuickexContract::account_summary_many`](crate::QuickexContract::account_summary_many).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountPrivacy {
+    /// Account the summary describes.
+    pub account: Address,
+    /// Current numeric privacy level, `None` if unconfigured.
+    pub level: Option<u32>,
+    /// Number of retained privacy history entries.
+    pub history_len: u32,
+    /// Whether the admin froze the account's privacy settings.
+    pub frozen: bool,
+    /// Whether the account currently has a privacy level set.
+    pub configured: bool,
+}
+
+/// Accountability record of a revealed commitment opening.
+///
+/// Written by [`QuickexContract::record_opening_by`](crate::QuickexContract::record_opening_by).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OpeningRecord {
+    /// Address that revealed the opening.
+    pub revealer: Address,
+    /// Ledger sequence at which the opening was recorded.
+    pub ledger: u32,
+}
+
+/// Admin-configurable contract settings.
+///
+/// Returned by [`QuickexContract::get_config`](crate::QuickexContract::get_config) so admin
+/// tooling can display the whole configuration from a single call. Unset settings
+/// report their defaults.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractConfig {
+    /// Base escrow fee in basis points, before privacy-level discounts.
+    pub fee_bps: u32,
+    /// Ceiling on id-based escrow amounts.
+    pub max_escrow_amount: u64,
+    /// Maximum commitment salt length in bytes under the current policy.
+    pub max_salt_len: u32,
+    /// Global pause flag.
+    pub paused: bool,
+    /// Privacy level reported for unconfigured accounts, if any.
+    pub default_privacy_level: Option<u32>,
+    /// Ledgers a dispute may stay unresolved before either party can time it out.
+    pub dispute_deadline_ledgers: u32,
+}
+
+/// Parameters of the commitment scheme in use.
+///
+/// Returned by [`QuickexContract::commitment_params`](crate::QuickexContract::commitment_params)
+/// so off-chain provers can configure themselves from a single call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentParams {
+    /// Version of the preimage layout.
+    pub version: u32,
+    /// Hash algorithm applied to the preimage.
+    pub hash_alg: Symbol,
+    /// Minimum salt length in bytes under the current policy.
+    pub min_salt: u32,
+    /// Maximum salt length in bytes under the current policy.
+    pub max_salt: u32,
+    /// Domain tag prefixed to every preimage.
+    pub domain_tag: Bytes,
+}
@@ -0,0 +1,63 @@
+//! Commitment-based vote weights for private governance.
+//!
+//! A voter first registers a commitment to their weight, keeping the weight hidden
+//! while voting is open, and reveals it at tally time. The commitment is an amount
+//! commitment owned by the voter, see
+//! [`create_amount_commitment`](crate::commitment::create_amount_commitment).
+
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+use crate::{
+    commitment,
+    errors::QuickexError,
+    storage::{get_vote_commitment, get_vote_weight, set_vote_commitment, set_vote_weight},
+};
+
+/// Register `voter`'s commitment to their vote weight. The voter must authorize.
+///
+/// # Errors
+/// - [`QuickexError::CommitmentAlreadyExists`] – the voter already registered a weight.
+pub fn register_vote_weight(
+    env: &Env,
+    voter: &Address,
+    weight_commitment: BytesN<32>,
+) -> Result<(), QuickexError> {
+    voter.require_auth();
+
+    if get_vote_commitment(env, voter).is_some() {
+        return Err(QuickexError::CommitmentAlreadyExists);
+    }
+    set_vote_commitment(env, voter, &weight_commitment);
+    Ok(())
+}
+
+/// Open `voter`'s registered commitment and record the plaintext weight.
+/// The voter must authorize.
+///
+/// # Errors
+/// - [`QuickexError::CommitmentNotFound`] – the voter never registered a weight.
+/// - [`QuickexError::AlreadySpent`] – the weight was already revealed.
+/// - [`QuickexError::CommitmentMismatch`] – `weight` and `salt` do not open the commitment.
+pub fn reveal_vote_weight(
+    env: &Env,
+    voter: &Address,
+    weight: i128,
+    salt: Bytes,
+) -> Result<(), QuickexError> {
+    voter.require_auth();
+
+    let registered = get_vote_commitment(env, voter).ok_or(QuickexError::CommitmentNotFound)?;
+    if get_vote_weight(env, voter).is_some() {
+        return Err(QuickexError::AlreadySpent);
+    }
+    if !commitment::verify_amount_commitment(env, registered, voter.clone(), weight, salt) {
+        return Err(QuickexError::CommitmentMismatch);
+    }
+    set_vote_weight(env, voter, weight);
+    Ok(())
+}
+
+/// Return `voter`'s revealed weight, or `None` until it is revealed.
+pub fn vote_weight(env: &Env, voter: &Address) -> Option<i128> {
+    get_vote_weight(env, voter)
+}
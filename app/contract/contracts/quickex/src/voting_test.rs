@@ -0,0 +1,50 @@
+//! Vote weight tests.
+//!
+//! Covers registering weight commitments and revealing them at tally time.
+
+use crate::{errors::QuickexError, QuickexContract, QuickexContractClient};
+use soroban_sdk::{testutils::Address as _, Address, Bytes, Env};
+
+fn setup<'a>() -> (Env, QuickexContractClient<'a>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(QuickexContract, ());
+    let client = QuickexContractClient::new(&env, &contract_id);
+    (env, client)
+}
+
+#[test]
+fn test_reveal_vote_weight_records_committed_weight() {
+    let (env, client) = setup();
+    let voter = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"ballot");
+    let commitment = client.create_amount_commitment(&voter, &42i128, &salt);
+
+    client.register_vote_weight(&voter, &commitment);
+    assert_eq!(client.vote_weight(&voter), None);
+    let result = client.try_register_vote_weight(&voter, &commitment);
+    assert_eq!(result, Err(Ok(QuickexError::CommitmentAlreadyExists)));
+
+    client.reveal_vote_weight(&voter, &42i128, &salt);
+    assert_eq!(client.vote_weight(&voter), Some(42));
+    let result = client.try_reveal_vote_weight(&voter, &42i128, &salt);
+    assert_eq!(result, Err(Ok(QuickexError::AlreadySpent)));
+}
+
+#[test]
+fn test_reveal_vote_weight_wrong_salt_reverts() {
+    let (env, client) = setup();
+    let voter = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"ballot");
+    let commitment = client.create_amount_commitment(&voter, &42i128, &salt);
+    client.register_vote_weight(&voter, &commitment);
+
+    let result =
+        client.try_reveal_vote_weight(&voter, &42i128, &Bytes::from_slice(&env, b"ballop"));
+    assert_eq!(result, Err(Ok(QuickexError::CommitmentMismatch)));
+    assert_eq!(client.vote_weight(&voter), None);
+
+    let stranger = Address::generate(&env);
+    let result = client.try_reveal_vote_weight(&stranger, &42i128, &salt);
+    assert_eq!(result, Err(Ok(QuickexError::CommitmentNotFound)));
+}